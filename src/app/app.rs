@@ -1,13 +1,16 @@
 use std::env;
 
+use crate::error::LazuliError;
 use crate::asset_manager::{AssetManager, GlAssetManager};
 use crate::event::EventSystem;
-use crate::graphics::scene::Scene;
+use crate::graphics::scene::{Scene, SceneManager};
 use crate::graphics::ui::Interface;
 use crate::graphics::window::{Window, WindowBuilder};
 use crate::graphics::renderer::Renderer;
 use crate::input::Input;
 
+use super::HeadlessApp;
+
 pub struct App {
     pub event_system: EventSystem,
     window: Box<dyn Window>,
@@ -22,15 +25,23 @@ impl App {
         env::set_var("RUST_BACKTRACE", "1");
 
         let mut event_system = EventSystem::new();
-        let window = window_builder.build(&mut event_system);
+        let window = window_builder.build(&mut event_system).expect("App failed to create window");
         let input = Input::new();
-        let asset_manager = Box::new(GlAssetManager::new());
+        let asset_manager = Box::new(GlAssetManager::new().with_shader_hot_reload(window_builder.shader_hot_reload));
         let interface = Interface::new(&mut event_system, window.get_size(), window.get_pixel_density() as f32);
 
         let app = Self { event_system, window, input, asset_manager, interface };
         app.run::<T>();
     }
 
+    /// Run a scene for a number of frames without opening a window. The returned app can be used to
+    /// inspect the scene or to keep on stepping through frames.
+    pub fn run_headless<T: Scene>(window_builder: &WindowBuilder, frames: u64) -> Result<HeadlessApp, LazuliError> {
+        let mut app = HeadlessApp::from_window_builder::<T>(window_builder)?;
+        app.run_frames(frames);
+        Ok(app)
+    }
+
     fn run<T: Scene + 'static>(mut self) {
         let scene = T::new(
             &mut self.event_system, 
@@ -40,7 +51,8 @@ impl App {
             &mut self.interface
         ).expect("App failed to create initial scene");
         
-        let renderer = Renderer::new(Box::new(scene)).expect("App failed to create renderer");
+        let scene_manager = SceneManager::new(Box::new(scene), &mut self.event_system, &mut *self.asset_manager, &mut self.interface);
+        let renderer = Renderer::new(scene_manager).expect("App failed to create renderer");
         self.window.run(renderer, self.event_system, self.input, self.asset_manager, self.interface);
     }
 }
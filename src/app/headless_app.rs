@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use glam::Vec2;
+
+use crate::error::LazuliError;
+use crate::asset_manager::{AssetManager, asset_manager_mock::MockAssetManager};
+use crate::event::{EventSystem, WindowResizeEvent};
+use crate::graphics::scene::{Scene, SceneManager};
+use crate::graphics::ui::Interface;
+use crate::graphics::window::{WindowBuilder, WindowSize};
+use crate::input::{Input, InputRecording, InputReplay};
+use crate::time::{FixedTimestep, VirtualClock};
+
+/// Size that is used when the window builder asks for a size that depends on a monitor
+const DEFAULT_HEADLESS_SIZE: Vec2 = Vec2 { x: 800.0, y: 600.0 };
+
+/// Runs a scene without a window and without a graphics context. Every call to `step` simulates a single
+/// frame by moving the virtual clock forward by one frame and running the fixed rate updates of the interface
+/// and the scene that fit in that frame, just like a windowed app would.
+///
+/// Because there is no graphics context, `Scene::draw` is never called and assets get loaded by a
+/// `MockAssetManager`. Scenes that create ui elements or shapes need a graphics context and can not be
+/// run headless.
+pub struct HeadlessApp {
+    pub event_system: EventSystem,
+    pub input: Input,
+    pub asset_manager: Box<dyn AssetManager>,
+    pub interface: Interface,
+    scene_manager: SceneManager,
+    clock: VirtualClock,
+    fixed_timestep: FixedTimestep,
+    frame_time: Duration,
+    update_count: u64,
+    input_replay: Option<InputReplay>,
+}
+
+impl HeadlessApp {
+    pub fn new<T: Scene>(window_size: Vec2, pixel_density: f32) -> Result<Self, LazuliError> {
+        let mut event_system = EventSystem::new();
+        let input = Input::new();
+        let mut asset_manager: Box<dyn AssetManager> = Box::new(MockAssetManager::new());
+        let mut interface = Interface::new(&mut event_system, window_size, pixel_density);
+
+        let scene = T::new(&mut event_system, window_size, pixel_density, &mut *asset_manager, &mut interface)?;
+        let scene_manager = SceneManager::new(Box::new(scene), &mut event_system, &mut *asset_manager, &mut interface);
+
+        // Mimic the initial resize event that a real window sends
+        event_system.send(WindowResizeEvent {
+            width: window_size.x as u32,
+            height: window_size.y as u32,
+        });
+
+        Ok(Self {
+            event_system,
+            input,
+            asset_manager,
+            interface,
+            scene_manager,
+            clock: VirtualClock::new(),
+            fixed_timestep: FixedTimestep::default(),
+            frame_time: FixedTimestep::default().step_duration(),
+            update_count: 0,
+            input_replay: None,
+        })
+    }
+
+    pub fn from_window_builder<T: Scene>(window_builder: &WindowBuilder) -> Result<Self, LazuliError> {
+        let window_size = match window_builder.size {
+            WindowSize::Pixels(width, height) => Vec2::new(width as f32, height as f32),
+            WindowSize::FullScreen | WindowSize::Maximized => DEFAULT_HEADLESS_SIZE,
+        };
+
+        Self::new::<T>(window_size, 1.0)
+    }
+
+    /// Simulate a single frame
+    pub fn step(&mut self) {
+        self.clock.advance(self.frame_time);
+
+        for _ in 0..self.fixed_timestep.advance(self.frame_time) {
+            if let Some(input_replay) = &mut self.input_replay {
+                input_replay.apply_next_frame(&mut self.input);
+            }
+            self.input.poll_gamepads();
+
+            self.interface.update(&mut self.event_system, &mut *self.asset_manager, &mut self.input);
+            self.scene_manager.update(&mut self.event_system, &self.input, &mut *self.asset_manager, &mut self.interface);
+
+            self.input.reset();
+            self.update_count += 1;
+        }
+
+        self.event_system.dispatch_queued();
+    }
+
+    /// Simulate `frames` frames
+    pub fn run_frames(&mut self, frames: u64) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Feed the frames of `recording` into the input, one frame per update, starting at the next update
+    pub fn replay_input(&mut self, recording: InputRecording) {
+        self.input_replay = Some(InputReplay::new(recording));
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.input_replay.as_ref().is_some_and(|input_replay| !input_replay.is_finished())
+    }
+
+    /// Simulate frames until every frame of the replay has been used
+    pub fn run_replay(&mut self) {
+        while self.is_replaying() {
+            self.step();
+        }
+    }
+
+    /// Set the number of frames that are simulated per second of virtual time. By default this is the same
+    /// as the tick rate, so that every frame runs exactly one update.
+    ///
+    /// Panics if `target_fps` is 0.
+    pub fn set_target_fps(&mut self, target_fps: u64) {
+        assert!(target_fps > 0, "HeadlessApp target fps must be at least 1");
+        self.frame_time = Duration::from_nanos(1_000_000_000 / target_fps);
+    }
+
+    pub fn scene_manager(&self) -> &SceneManager { &self.scene_manager }
+    /// Get the active scene, if it is of type `T`
+    pub fn scene<T: Scene>(&self) -> Option<&T> { self.scene_manager.active_scene_as::<T>() }
+    /// Get the active scene, if it is of type `T`
+    pub fn mut_scene<T: Scene>(&mut self) -> Option<&mut T> { self.scene_manager.mut_active_scene_as::<T>() }
+    pub fn clock(&self) -> &VirtualClock { &self.clock }
+    pub fn frame_count(&self) -> u64 { self.clock.frame_count() }
+    pub fn update_count(&self) -> u64 { self.update_count }
+    /// See `Scene::draw`
+    pub fn interpolation(&self) -> f32 { self.fixed_timestep.alpha() }
+}
@@ -0,0 +1,116 @@
+use glam::Vec2;
+
+use crate::{error::LazuliError, asset_manager::AssetManager, event::{EventReader, EventSystem, WindowResizeEvent}, graphics::{scene::Scene, ui::Interface, window::WindowBuilder}, input::{ButtonState, Input, InputEvent, Key}};
+
+use super::{App, HeadlessApp};
+
+struct CountingScene {
+    nr_updates: u32,
+    nr_space_presses: u32,
+    last_window_size: Option<Vec2>,
+    window_resize_listener: EventReader<WindowResizeEvent>,
+}
+
+impl Scene for CountingScene {
+    fn new(event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) -> Result<Self, LazuliError> {
+        Ok(Self {
+            nr_updates: 0,
+            nr_space_presses: 0,
+            last_window_size: None,
+            window_resize_listener: event_system.register(),
+        })
+    }
+
+    unsafe fn draw(&self, _asset_manager: &mut dyn AssetManager, _interpolation: f32) {}
+
+    fn update(&mut self, _event_system: &mut EventSystem, input: &Input, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {
+        self.nr_updates += 1;
+
+        if input.keyboard.is_key_down(Key::Space) {
+            self.nr_space_presses += 1;
+        }
+
+        if let Some(event) = self.window_resize_listener.read().last() {
+            self.last_window_size = Some(Vec2::new(event.width as f32, event.height as f32));
+        }
+    }
+}
+
+#[test]
+fn test_run_frames() -> Result<(), LazuliError> {
+    let mut app = HeadlessApp::new::<CountingScene>(Vec2::new(400.0, 300.0), 1.0)?;
+    app.set_target_fps(50);
+
+    app.run_frames(10);
+
+    // 200ms of virtual time at a tick rate of 60
+    assert_eq!(12, app.scene::<CountingScene>().unwrap().nr_updates);
+    assert_eq!(12, app.update_count());
+    assert_eq!(10, app.frame_count());
+    assert_eq!(200, app.clock().now_millis());
+    assert_eq!(Some(Vec2::new(400.0, 300.0)), app.scene::<CountingScene>().unwrap().last_window_size);
+
+    Ok(())
+}
+
+#[test]
+fn test_input_is_reset_after_step() -> Result<(), LazuliError> {
+    let mut app = HeadlessApp::new::<CountingScene>(Vec2::new(400.0, 300.0), 1.0)?;
+
+    app.input.keyboard.register_key_event(Key::Space, ButtonState::Down);
+    app.run_frames(3);
+
+    assert_eq!(1, app.scene::<CountingScene>().unwrap().nr_space_presses);
+    assert!(app.input.keyboard.is_key_held(Key::Space));
+
+    Ok(())
+}
+
+#[test]
+fn test_run_headless() -> Result<(), LazuliError> {
+    let app = App::run_headless::<CountingScene>(&WindowBuilder::new().with_size(640, 480), 5)?;
+
+    assert_eq!(5, app.scene::<CountingScene>().unwrap().nr_updates);
+    assert_eq!(Some(Vec2::new(640.0, 480.0)), app.scene::<CountingScene>().unwrap().last_window_size);
+
+    Ok(())
+}
+
+#[test]
+fn test_replay_input() -> Result<(), LazuliError> {
+    let mut recording_app = HeadlessApp::new::<CountingScene>(Vec2::new(400.0, 300.0), 1.0)?;
+    recording_app.input.start_recording();
+
+    for frame in 0..6 {
+        if frame % 2 == 0 {
+            recording_app.input.register_event(InputEvent::Key { key: Key::Space, state: ButtonState::Down });
+        } else {
+            recording_app.input.register_event(InputEvent::Key { key: Key::Space, state: ButtonState::Up });
+        }
+        recording_app.step();
+    }
+
+    let recording = recording_app.input.stop_recording().unwrap();
+    assert_eq!(6, recording.number_of_frames());
+
+    let mut replay_app = HeadlessApp::new::<CountingScene>(Vec2::new(400.0, 300.0), 1.0)?;
+    replay_app.replay_input(recording);
+    replay_app.run_replay();
+
+    assert!(!replay_app.is_replaying());
+    assert_eq!(6, replay_app.update_count());
+    assert_eq!(3, replay_app.scene::<CountingScene>().unwrap().nr_space_presses);
+    assert_eq!(
+        recording_app.scene::<CountingScene>().unwrap().nr_space_presses,
+        replay_app.scene::<CountingScene>().unwrap().nr_space_presses,
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "target fps must be at least 1")]
+fn test_zero_target_fps() {
+    let mut app = HeadlessApp::new::<CountingScene>(Vec2::new(400.0, 300.0), 1.0).unwrap();
+    app.set_target_fps(0);
+}
@@ -1,2 +1,13 @@
 mod app;
 pub use app::App;
+
+mod headless_app;
+pub use headless_app::HeadlessApp;
+
+mod offscreen_app;
+pub use offscreen_app::OffscreenApp;
+
+#[cfg(test)]
+mod headless_app_test;
+#[cfg(test)]
+mod offscreen_app_test;
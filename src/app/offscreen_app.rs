@@ -0,0 +1,148 @@
+use glam::Vec2;
+use glutin::{dpi::PhysicalSize, event_loop::EventLoop, Api, ContextBuilder, GlProfile, GlRequest, PossiblyCurrent};
+use image::RgbaImage;
+
+use crate::error::{LazuliError, WindowError};
+use crate::asset_manager::{AssetManager, GlAssetManager};
+use crate::event::{EventSystem, WindowResizeEvent};
+use crate::graphics::capture::Framebuffer;
+use crate::graphics::renderer::Renderer;
+use crate::graphics::scene::{Scene, SceneManager};
+use crate::graphics::ui::Interface;
+use crate::input::Input;
+use crate::time::FixedTimestep;
+
+/// Runs a scene with a graphics context but without a window, drawing every frame into an offscreen
+/// framebuffer. Unlike `HeadlessApp`, it draws the scene and the interface, so frames can be captured and
+/// compared with reference images using `capture::GoldenImage`.
+///
+/// On Linux it first tries an OSMesa context, which needs no display at all. If that is not available it
+/// falls back to a headless context of the display server, which also works with a virtual display like Xvfb.
+/// Both work with a software implementation like Mesa llvmpipe, so no GPU is needed.
+pub struct OffscreenApp {
+    pub event_system: EventSystem,
+    pub input: Input,
+    pub interface: Interface,
+    // The order of the fields matters: everything that owns OpenGL objects has to be dropped before the context
+    renderer: Renderer,
+    framebuffer: Framebuffer,
+    pub asset_manager: Box<dyn AssetManager>,
+    fixed_timestep: FixedTimestep,
+    update_count: u64,
+    _context: glutin::Context<PossiblyCurrent>,
+    _event_loop: Option<EventLoop<()>>,
+}
+
+impl OffscreenApp {
+    pub fn new<T: Scene>(width: u32, height: u32) -> Result<Self, LazuliError> {
+        let (context, event_loop) = Self::create_context(PhysicalSize::new(width, height))?;
+
+        let window_size = Vec2::new(width as f32, height as f32);
+        let mut event_system = EventSystem::new();
+        let input = Input::new();
+        let mut asset_manager: Box<dyn AssetManager> = Box::new(GlAssetManager::new());
+        let mut interface = Interface::new(&mut event_system, window_size, 1.0);
+
+        let scene = T::new(&mut event_system, window_size, 1.0, &mut *asset_manager, &mut interface)?;
+        let scene_manager = SceneManager::new(Box::new(scene), &mut event_system, &mut *asset_manager, &mut interface);
+        let renderer = Renderer::new(scene_manager)?;
+        let framebuffer = Framebuffer::new(width, height)?;
+
+        // Mimic the initial resize event that a real window sends
+        event_system.send(WindowResizeEvent { width, height });
+
+        Ok(Self {
+            event_system,
+            input,
+            interface,
+            renderer,
+            framebuffer,
+            asset_manager,
+            fixed_timestep: FixedTimestep::default(),
+            update_count: 0,
+            _context: context,
+            _event_loop: event_loop,
+        })
+    }
+
+    /// Simulate a single frame, which runs exactly one update
+    pub fn step(&mut self) {
+        let step_duration = self.fixed_timestep.step_duration();
+
+        for _ in 0..self.fixed_timestep.advance(step_duration) {
+            self.input.poll_gamepads();
+            self.interface.update(&mut self.event_system, &mut *self.asset_manager, &mut self.input);
+            self.renderer.scene_manager.update(&mut self.event_system, &self.input, &mut *self.asset_manager, &mut self.interface);
+
+            self.input.reset();
+            self.update_count += 1;
+        }
+
+        self.event_system.dispatch_queued();
+    }
+
+    /// Simulate `frames` frames
+    pub fn run_frames(&mut self, frames: u64) {
+        for _ in 0..frames {
+            self.step();
+        }
+    }
+
+    /// Draw the current frame into the offscreen framebuffer and read it back
+    pub fn capture(&mut self) -> RgbaImage {
+        self.framebuffer.bind();
+        self.renderer.draw(&mut *self.asset_manager, &self.interface, self.fixed_timestep.alpha());
+        self.framebuffer.unbind();
+
+        self.framebuffer.read_pixels()
+    }
+
+    pub fn scene_manager(&self) -> &SceneManager { &self.renderer.scene_manager }
+    /// Get the active scene, if it is of type `T`
+    pub fn scene<T: Scene>(&self) -> Option<&T> { self.renderer.scene_manager.active_scene_as::<T>() }
+    /// Get the active scene, if it is of type `T`
+    pub fn mut_scene<T: Scene>(&mut self) -> Option<&mut T> { self.renderer.scene_manager.mut_active_scene_as::<T>() }
+    pub fn update_count(&self) -> u64 { self.update_count }
+
+    fn create_context(size: PhysicalSize<u32>) -> Result<(glutin::Context<PossiblyCurrent>, Option<EventLoop<()>>), LazuliError> {
+        let context_builder = ContextBuilder::new()
+            .with_gl(GlRequest::Specific(Api::OpenGl, (3, 3)))
+            .with_gl_profile(GlProfile::Core);
+
+        #[cfg(target_os = "linux")]
+        {
+            use glutin::platform::unix::{EventLoopBuilderExtUnix, HeadlessContextExt};
+
+            let osmesa_error = match context_builder.clone().build_osmesa(size) {
+                Ok(context) => return Ok((Self::make_current(context)?, None)),
+                Err(err) => err,
+            };
+
+            // Creating an event loop panics when there is no display server to connect to
+            if std::env::var_os("DISPLAY").is_none() && std::env::var_os("WAYLAND_DISPLAY").is_none() {
+                return Err(WindowError::Creation(osmesa_error).into());
+            }
+
+            let event_loop = glutin::event_loop::EventLoopBuilder::new().with_any_thread(true).build();
+            let context = context_builder.build_headless(&event_loop, size).map_err(WindowError::Creation)?;
+            Ok((Self::make_current(context)?, Some(event_loop)))
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let event_loop = EventLoop::new();
+            let context = context_builder.build_headless(&event_loop, size).map_err(WindowError::Creation)?;
+            Ok((Self::make_current(context)?, Some(event_loop)))
+        }
+    }
+
+    fn make_current(context: glutin::Context<glutin::NotCurrent>) -> Result<glutin::Context<PossiblyCurrent>, LazuliError> {
+        let context = unsafe {
+            context.make_current().map_err(|(_, err)| WindowError::Context(err))?
+        };
+
+        gl::load_with(|ptr| context.get_proc_address(ptr) as *const _);
+
+        Ok(context)
+    }
+}
@@ -0,0 +1,237 @@
+use std::{cell::RefCell, rc::Rc};
+
+use glam::Vec2;
+
+use crate::{error::LazuliError, asset_manager::AssetManager, event::{ButtonClicked, EventPropagation, EventSystem, WidgetHoverEnter, WidgetHoverLeave}, graphics::{capture::GoldenImage, scene::Scene, ui::{shapes::RectangleBuilder, widget::{ButtonBuilder, DropdownBuilder, DropdownOption}, AnchorPoint, Interface, Position, UiWidgetId}, Color}, input::{ButtonState, Input, MouseButton}, ResourceId};
+
+use super::OffscreenApp;
+
+struct RectanglesScene {}
+
+impl Scene for RectanglesScene {
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Self, LazuliError> {
+        let rectangle_id = interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
+            .with_color(Color::Rgb(255, 0, 0))
+            .with_size(Vec2::new(80.0, 40.0))
+            .with_position(Position::ScreenAnchor(AnchorPoint::TopLeftInside(10.0, 10.0)))
+        , asset_manager)?;
+
+        interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
+            .with_color(Color::Rgba(0, 0, 255, 0.5))
+            .with_size(Vec2::new(40.0, 80.0))
+            .with_position(Position::ElementAnchor(AnchorPoint::RightOutside(5.0), rectangle_id))
+        , asset_manager)?;
+
+        Ok(Self {})
+    }
+
+    unsafe fn draw(&self, _asset_manager: &mut dyn AssetManager, _interpolation: f32) {}
+
+    fn update(&mut self, _event_system: &mut EventSystem, _input: &Input, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {}
+}
+
+/// Needs OpenGL, which is not available everywhere, so it only runs with the `gl-tests` feature. `make test-gl`
+/// runs it on Mesa llvmpipe, which is what CI uses. Set `LAZULI_UPDATE_GOLDEN=1` to accept a changed rendering.
+#[test]
+#[cfg_attr(not(feature = "gl-tests"), ignore = "needs an OpenGL implementation, run with `make test-gl`")]
+fn test_ui_rectangles_match_golden_image() -> Result<(), LazuliError> {
+    let mut app = OffscreenApp::new::<RectanglesScene>(200, 120)?;
+    app.run_frames(2);
+
+    let image = app.capture();
+
+    assert_eq!((200, 120), image.dimensions());
+    GoldenImage::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden/ui_rectangles.png"))
+        .with_channel_tolerance(4)
+        .check(&image)
+}
+
+/// A rectangle at the top left, a rectangle that does not handle input at the top right and a focusable button
+/// below them, remembering what the interface consumed
+struct ClickScene {
+    button_id: ResourceId<UiWidgetId>,
+    is_mouse_consumed: bool,
+    is_keyboard_consumed: bool,
+}
+
+impl Scene for ClickScene {
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Self, LazuliError> {
+        interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
+            .with_size(Vec2::new(80.0, 40.0))
+            .with_position(Position::ScreenAnchor(AnchorPoint::TopLeftInside(10.0, 10.0)))
+        , asset_manager)?;
+
+        interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
+            .with_size(Vec2::new(80.0, 40.0))
+            .with_position(Position::ScreenAnchor(AnchorPoint::TopRightInside(10.0, 10.0)))
+            .with_handle_input_events(false)
+        , asset_manager)?;
+
+        let button_id = interface.create_button("Focus", &ButtonBuilder::new()
+            .with_width(80.0)
+            .with_height(30.0)
+            .with_position(Position::ScreenAnchor(AnchorPoint::TopLeftInside(10.0, 70.0)))
+        , asset_manager)?;
+        interface.set_widget_focusable(&button_id, true);
+
+        Ok(Self { button_id, is_mouse_consumed: false, is_keyboard_consumed: false })
+    }
+
+    unsafe fn draw(&self, _asset_manager: &mut dyn AssetManager, _interpolation: f32) {}
+
+    fn update(&mut self, _event_system: &mut EventSystem, input: &Input, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {
+        self.is_mouse_consumed = input.mouse.is_consumed_by_ui();
+        self.is_keyboard_consumed = input.keyboard.is_consumed_by_ui();
+    }
+}
+
+fn click(app: &mut OffscreenApp, x: f64, y: f64) {
+    app.input.mouse.register_reposition_event(x, y);
+    app.input.mouse.register_button_event(MouseButton::Left, ButtonState::Down);
+    app.step();
+    app.input.mouse.register_button_event(MouseButton::Left, ButtonState::Up);
+}
+
+fn consumed_input(app: &OffscreenApp) -> (bool, bool) {
+    let scene = app.scene::<ClickScene>().unwrap();
+    (scene.is_mouse_consumed, scene.is_keyboard_consumed)
+}
+
+#[test]
+#[cfg_attr(not(feature = "gl-tests"), ignore = "needs an OpenGL implementation, run with `make test-gl`")]
+fn test_clicks_consume_input() -> Result<(), LazuliError> {
+    let mut app = OffscreenApp::new::<ClickScene>(200, 120)?;
+    app.run_frames(2);
+
+    click(&mut app, 50.0, 30.0);
+    assert_eq!((true, false), consumed_input(&app), "a click on the rectangle consumes the mouse");
+
+    click(&mut app, 170.0, 110.0);
+    assert_eq!((false, false), consumed_input(&app), "a click on empty space consumes nothing");
+
+    click(&mut app, 50.0, 85.0);
+    assert_eq!((true, true), consumed_input(&app), "a click on the focusable button takes keyboard focus");
+    app.step();
+    let button_id = app.scene::<ClickScene>().unwrap().button_id;
+    assert!(app.interface.keyboard_focus().is_some_and(|widget_id| widget_id.equals(&button_id)), "the focus stays after releasing the mouse");
+
+    click(&mut app, 50.0, 30.0);
+    assert_eq!((true, false), consumed_input(&app), "a click on anything else takes the focus away");
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(not(feature = "gl-tests"), ignore = "needs an OpenGL implementation, run with `make test-gl`")]
+fn test_non_interactive_rectangle_does_not_consume_input() -> Result<(), LazuliError> {
+    let mut app = OffscreenApp::new::<ClickScene>(200, 120)?;
+    app.run_frames(2);
+
+    app.input.mouse.register_reposition_event(50.0, 30.0);
+    app.step();
+    assert_eq!((true, false), consumed_input(&app), "hovering the interactive rectangle consumes the mouse");
+
+    app.input.mouse.register_reposition_event(150.0, 30.0);
+    app.step();
+    assert_eq!((false, false), consumed_input(&app), "hovering the non-interactive rectangle consumes nothing");
+
+    click(&mut app, 150.0, 30.0);
+    assert_eq!((false, false), consumed_input(&app), "clicking the non-interactive rectangle consumes nothing");
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(not(feature = "gl-tests"), ignore = "needs an OpenGL implementation, run with `make test-gl`")]
+fn test_hidden_widget_loses_focus() -> Result<(), LazuliError> {
+    let mut app = OffscreenApp::new::<ClickScene>(200, 120)?;
+    app.run_frames(2);
+
+    click(&mut app, 50.0, 85.0);
+    assert!(app.interface.keyboard_focus().is_some());
+
+    let button_id = app.scene::<ClickScene>().unwrap().button_id;
+    app.interface.set_widget_visibility(&button_id, false);
+    app.step();
+    assert_eq!((false, false), consumed_input(&app));
+    assert!(app.interface.keyboard_focus().is_none());
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(not(feature = "gl-tests"), ignore = "needs an OpenGL implementation, run with `make test-gl`")]
+fn test_widget_events_reach_subscribers() -> Result<(), LazuliError> {
+    let mut app = OffscreenApp::new::<ClickScene>(200, 120)?;
+    app.run_frames(2);
+    let button_id = app.scene::<ClickScene>().unwrap().button_id;
+
+    let events = Rc::new(RefCell::new(vec![]));
+    let clicked_events = events.clone();
+    app.event_system.subscribe(move |event: &ButtonClicked| {
+        clicked_events.borrow_mut().push(("clicked", *event.widget_id.id()));
+        EventPropagation::Continue
+    });
+    let hover_enter_events = events.clone();
+    app.event_system.subscribe(move |event: &WidgetHoverEnter| {
+        hover_enter_events.borrow_mut().push(("hover enter", *event.widget_id.id()));
+        EventPropagation::Continue
+    });
+    let hover_leave_events = events.clone();
+    app.event_system.subscribe(move |event: &WidgetHoverLeave| {
+        hover_leave_events.borrow_mut().push(("hover leave", *event.widget_id.id()));
+        EventPropagation::Continue
+    });
+
+    click(&mut app, 50.0, 85.0);
+    app.input.mouse.register_reposition_event(170.0, 110.0);
+    // Widgets see the element state of the previous update, so their events arrive one update later
+    app.run_frames(2);
+
+    assert_eq!(vec![
+        ("hover enter", *button_id.id()),
+        ("clicked", *button_id.id()),
+        ("hover leave", *button_id.id()),
+    ], *events.borrow());
+
+    Ok(())
+}
+
+struct DropdownScene {
+    dropdown_id: ResourceId<UiWidgetId>,
+}
+
+impl Scene for DropdownScene {
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Self, LazuliError> {
+        let dropdown_id = interface.create_dropdown(&DropdownBuilder::new()
+            .with_options(vec![DropdownOption::new("One", 1), DropdownOption::new("Two", 2)])
+            .with_position(Position::ScreenAnchor(AnchorPoint::TopLeftInside(10.0, 10.0)))
+        , asset_manager)?;
+
+        Ok(Self { dropdown_id })
+    }
+
+    unsafe fn draw(&self, _asset_manager: &mut dyn AssetManager, _interpolation: f32) {}
+
+    fn update(&mut self, _event_system: &mut EventSystem, _input: &Input, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {}
+}
+
+#[test]
+#[cfg_attr(not(feature = "gl-tests"), ignore = "needs an OpenGL implementation, run with `make test-gl`")]
+fn test_dropdown_sends_hover_events() -> Result<(), LazuliError> {
+    let mut app = OffscreenApp::new::<DropdownScene>(300, 200)?;
+    app.run_frames(2);
+    let dropdown_id = app.scene::<DropdownScene>().unwrap().dropdown_id;
+    let mut hover_enter_listener = app.event_system.register::<WidgetHoverEnter>();
+    let mut hover_leave_listener = app.event_system.register::<WidgetHoverLeave>();
+
+    app.input.mouse.register_reposition_event(20.0, 20.0);
+    app.run_frames(2);
+    assert!(hover_enter_listener.read().iter().any(|event| event.widget_id.equals(&dropdown_id)));
+
+    app.input.mouse.register_reposition_event(290.0, 190.0);
+    app.run_frames(2);
+    assert!(hover_leave_listener.read().iter().any(|event| event.widget_id.equals(&dropdown_id)));
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::error::{AssetError, LazuliError};
 use crate::ResourceId;
 
 pub struct AssetEntry<T, U> {
@@ -33,15 +34,12 @@ impl<T, U: std::cmp::PartialEq> AssetCollection<T, U> {
         None
     }
 
-    pub fn add(&mut self, asset: T, builder_hash: U) -> Result<ResourceId<T>, String> {
+    pub fn add(&mut self, asset: T, builder_hash: U) -> Result<ResourceId<T>, LazuliError> {
         self.id_counter += 1;
 
         match self.entries.entry(self.id_counter) {
             std::collections::hash_map::Entry::Occupied(_) => {
-                return Err(format!(
-                    "Encountered duplicate id {} while adding asset to asset collection"
-                    , self.id_counter
-                ));
+                return Err(AssetError::DuplicateId(self.id_counter).into());
             },
             std::collections::hash_map::Entry::Vacant(entry) => {
                 entry.insert(AssetEntry { asset, builder_hash });
@@ -1,3 +1,4 @@
+use crate::error::LazuliError;
 use crate::ResourceId;
 
 use super::AssetCollection;
@@ -11,7 +12,7 @@ fn test_add() {
 }
 
 #[test]
-fn test_get_by_builder_hash() -> Result<(), String> {
+fn test_get_by_builder_hash() -> Result<(), LazuliError> {
     let mut asset_collection: AssetCollection<&str, usize> = AssetCollection::new();
     
     let builder_hash = 1;
@@ -26,7 +27,7 @@ fn test_get_by_builder_hash() -> Result<(), String> {
 }
 
 #[test]
-fn test_get_asset_by_id() -> Result<(), String> {
+fn test_get_asset_by_id() -> Result<(), LazuliError> {
     let mut asset_collection: AssetCollection<&str, usize> = AssetCollection::new();
 
     let asset_id = asset_collection.add("", 0)?;
@@ -1,21 +1,26 @@
-use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+use std::{collections::{hash_map::DefaultHasher, HashMap}, hash::{Hash, Hasher}};
 
-use crate::{graphics::{font::{BitmapBuilder, Font, GlFont}, material::Material, shader::{ShaderBuilder, ShaderProgram, UniformValue}, texture::{GlTexture, Texture, TextureImage}}, ResourceId};
+use crate::{error::{AssetError, LazuliError}, graphics::{font::{BitmapBuilder, Font, GlFont}, material::Material, shader::{ShaderBuilder, ShaderProgram, ShaderWatcher, UniformValue}, texture::{GlTexture, Texture, TextureImage}}, log, ResourceId};
 
 use super::asset_collection::AssetCollection;
 
 pub trait AssetManager {
-    fn load_texture(&mut self, path: &String) -> Result<ResourceId<Box<dyn Texture>>, String>;
-    fn load_texture_from_image(&mut self, texture_image: &dyn TextureImage) -> Result<ResourceId<Box<dyn Texture>>, String>;
+    fn load_texture(&mut self, path: &String) -> Result<ResourceId<Box<dyn Texture>>, LazuliError>;
+    fn load_texture_from_image(&mut self, texture_image: &dyn TextureImage) -> Result<ResourceId<Box<dyn Texture>>, LazuliError>;
     fn get_texture_by_id(&mut self, id: &ResourceId<Box<dyn Texture>>) -> Option<&Box<dyn Texture>>;
-    fn load_font(&mut self, bitmap_builder: &dyn BitmapBuilder, shader_builder: Option<Box<dyn ShaderBuilder>>) -> Result<ResourceId<Box<dyn Font>>, String>;
+    fn load_font(&mut self, bitmap_builder: &dyn BitmapBuilder, shader_builder: Option<Box<dyn ShaderBuilder>>) -> Result<ResourceId<Box<dyn Font>>, LazuliError>;
     fn get_font_by_id(&mut self, id: &ResourceId<Box<dyn Font>>) -> Option<&Box<dyn Font>>;
-    fn load_shader(&mut self, shader_builder: Box<dyn ShaderBuilder>) -> Result<ResourceId<Box<dyn ShaderProgram>>, String>;
+    fn load_shader(&mut self, shader_builder: Box<dyn ShaderBuilder>) -> Result<ResourceId<Box<dyn ShaderProgram>>, LazuliError>;
     fn get_shader_by_id(&mut self, id: &ResourceId<Box<dyn ShaderProgram>>) -> Option<&Box<dyn ShaderProgram>>;
-    fn load_material(&mut self, shader_id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<ResourceId<Material>, String>;
+    /// Rebuild a shader from its source files, keeping its id. When shader hot reload is enabled, the uniforms that
+    /// were set on the old program get set on the new one. If building fails, the old program stays in use.
+    fn reload_shader(&mut self, id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<(), LazuliError>;
+    /// Rebuild all shaders of which a source file changed on disk. Returns the ids of the reloaded shaders.
+    fn reload_changed_shaders(&mut self) -> Vec<ResourceId<Box<dyn ShaderProgram>>>;
+    fn load_material(&mut self, shader_id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<ResourceId<Material>, LazuliError>;
     fn get_material_by_id(&mut self, id: &ResourceId<Material>) -> Option<&mut Material>;
-    fn add_material_texture(&mut self, material_id: &ResourceId<Material>, texture_id: &ResourceId<Box<dyn Texture>>) -> Result<(), String>;
-    fn activate_material(&mut self, material_id: &ResourceId<Material>) -> Result<(), String>;
+    fn add_material_texture(&mut self, material_id: &ResourceId<Material>, texture_id: &ResourceId<Box<dyn Texture>>) -> Result<(), LazuliError>;
+    fn activate_material(&mut self, material_id: &ResourceId<Material>) -> Result<(), LazuliError>;
     fn get_material_shader(&mut self, material_id: &ResourceId<Material>) -> Option<&Box<dyn ShaderProgram>>;
 }
 
@@ -24,6 +29,9 @@ pub struct GlAssetManager {
     fonts: AssetCollection<Box<dyn Font>, u64>,
     shaders: AssetCollection<Box< dyn ShaderProgram>, u64>,
     materials: AssetCollection<Material, bool>,
+    shader_builders: HashMap<u32, Box<dyn ShaderBuilder>>,
+    shader_watcher: ShaderWatcher,
+    shader_hot_reload: bool,
 }
 
 impl GlAssetManager {
@@ -33,12 +41,22 @@ impl GlAssetManager {
             fonts: AssetCollection::new(),
             shaders: AssetCollection::new(),
             materials: AssetCollection::new(),
+            shader_builders: HashMap::new(),
+            shader_watcher: ShaderWatcher::new(),
+            shader_hot_reload: false,
         }
     }
+
+    /// Watch the source files of shaders, so that `reload_changed_shaders` can rebuild them. Should be set before
+    /// loading any shaders.
+    pub fn with_shader_hot_reload(mut self, shader_hot_reload: bool) -> Self {
+        self.shader_hot_reload = shader_hot_reload;
+        self
+    }
 }
 
 impl AssetManager for GlAssetManager {
-    fn load_texture(&mut self, path: &String) -> Result<ResourceId<Box<dyn Texture>>, String> {
+    fn load_texture(&mut self, path: &String) -> Result<ResourceId<Box<dyn Texture>>, LazuliError> {
         let some_path = Some(path.clone());
 
         match self.textures.get_by_builder_hash(&some_path) {
@@ -54,7 +72,7 @@ impl AssetManager for GlAssetManager {
         }
     }
 
-    fn load_texture_from_image(&mut self, texture_image: &dyn TextureImage) -> Result<ResourceId<Box<dyn Texture>>, String> {
+    fn load_texture_from_image(&mut self, texture_image: &dyn TextureImage) -> Result<ResourceId<Box<dyn Texture>>, LazuliError> {
         match GlTexture::new_from_image(texture_image) {
             Ok(texture) => self.textures.add(Box::new(texture), None),
             Err(err) => Err(err),
@@ -65,14 +83,14 @@ impl AssetManager for GlAssetManager {
        self.textures.get_asset_by_id(id)
     }
 
-    fn load_font(&mut self, bitmap_builder: &dyn BitmapBuilder, shader_builder: Option<Box<dyn ShaderBuilder>>) -> Result<ResourceId<Box<dyn Font>>, String> {
+    fn load_font(&mut self, bitmap_builder: &dyn BitmapBuilder, shader_builder: Option<Box<dyn ShaderBuilder>>) -> Result<ResourceId<Box<dyn Font>>, LazuliError> {
         let shader_builder_to_use = shader_builder.unwrap_or(
             bitmap_builder.default_shader_builder()
         );
 
         let mut hasher = DefaultHasher::new();
-        shader_builder_to_use.hash().hash(&mut hasher);
-        bitmap_builder.get_hash().hash(&mut hasher);
+        shader_builder_to_use.hash()?.hash(&mut hasher);
+        bitmap_builder.get_hash()?.hash(&mut hasher);
         let hash = hasher.finish();
 
         match self.fonts.get_by_builder_hash(&hash) {
@@ -90,7 +108,7 @@ impl AssetManager for GlAssetManager {
         self.fonts.get_asset_by_id(id)
     }
 
-    fn load_shader(&mut self, shader_builder: Box<dyn ShaderBuilder>) -> Result<ResourceId<Box<dyn ShaderProgram>>, String> {
+    fn load_shader(&mut self, shader_builder: Box<dyn ShaderBuilder>) -> Result<ResourceId<Box<dyn ShaderProgram>>, LazuliError> {
         let hash = shader_builder.hash()?;
 
         match self.shaders.get_by_builder_hash(&hash) {
@@ -99,20 +117,64 @@ impl AssetManager for GlAssetManager {
         }
 
         let shader = shader_builder.build()?;
-        
-        self.shaders.add(shader, hash)
+        shader.set_record_uniforms(self.shader_hot_reload);
+        let shader_id = self.shaders.add(shader, hash)?;
+
+        if self.shader_hot_reload {
+            self.shader_watcher.watch(shader_id.duplicate(), shader_builder.source_paths());
+        }
+        self.shader_builders.insert(*shader_id.id(), shader_builder);
+
+        Ok(shader_id)
     }
 
     fn get_shader_by_id(&mut self, id: &ResourceId<Box<dyn ShaderProgram>>) -> Option<&Box<dyn ShaderProgram>> {
         self.shaders.get_asset_by_id(id)
     }
 
+    fn reload_shader(&mut self, id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<(), LazuliError> {
+        let new_shader = match self.shader_builders.get(id.id()) {
+            Some(shader_builder) => shader_builder.build()?,
+            None => return Err(AssetError::NotFound { asset_type: "shader builder", id: *id.id() }.into()),
+        };
+
+        let shader = match self.shaders.get_mut_asset_by_id(id) {
+            Some(shader) => shader,
+            None => return Err(AssetError::NotFound { asset_type: "shader", id: *id.id() }.into()),
+        };
+
+        new_shader.set_record_uniforms(self.shader_hot_reload);
+        for (name, value) in shader.known_uniforms() {
+            new_shader.set_uniform(&name, &value);
+        }
+
+        *shader = new_shader;
+
+        Ok(())
+    }
+
+    fn reload_changed_shaders(&mut self) -> Vec<ResourceId<Box<dyn ShaderProgram>>> {
+        let mut reloaded = vec![];
+
+        for shader_id in self.shader_watcher.poll() {
+            match self.reload_shader(&shader_id) {
+                Ok(()) => {
+                    log::engine_info(format!("Reloaded shader {}", shader_id.id()));
+                    reloaded.push(shader_id);
+                },
+                Err(err) => log::engine_err(format!("Failed to reload shader {}, keeping the old one: {}", shader_id.id(), err)),
+            }
+        }
+
+        reloaded
+    }
+
     /// Create a new material. We do not check for existing materials with the same hash because each
     /// objet in the world will need a separate material.
     /// 
     /// If we want to have a builder hash in the feature, we probably want to add all parameters to it, which
     /// is only the shader_id at the time of writing.
-    fn load_material(&mut self, shader_id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<ResourceId<Material>, String> {
+    fn load_material(&mut self, shader_id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<ResourceId<Material>, LazuliError> {
         let material = Material::new(shader_id.duplicate());
         self.materials.add(material, false)
     }
@@ -121,7 +183,7 @@ impl AssetManager for GlAssetManager {
         self.materials.get_mut_asset_by_id(id)
     }
 
-    fn add_material_texture(&mut self, material_id: &ResourceId<Material>, texture_id: &ResourceId<Box<dyn Texture>>) -> Result<(), String> {
+    fn add_material_texture(&mut self, material_id: &ResourceId<Material>, texture_id: &ResourceId<Box<dyn Texture>>) -> Result<(), LazuliError> {
         let textures_length: usize;
         let shader_id;
         match self.get_material_by_id(material_id) {
@@ -131,7 +193,7 @@ impl AssetManager for GlAssetManager {
                 material.push_texture_id(texture_id.duplicate());
 
             },
-            None => return Err(format!("Material {} not found", material_id.id())),
+            None => return Err(AssetError::NotFound { asset_type: "material", id: *material_id.id() }.into()),
         }
         
         match self.get_shader_by_id(&shader_id) {
@@ -141,19 +203,19 @@ impl AssetManager for GlAssetManager {
                     &UniformValue::from(textures_length as i32)
                 );
             },
-            None => return Err(format!("Shader {} from material {} was not found", shader_id.id(), material_id.id())),
+            None => return Err(AssetError::NotFound { asset_type: "shader", id: *shader_id.id() }.into()),
         }
 
         Ok(())
     }
 
-    fn activate_material(&mut self, material_id: &ResourceId<Material>) -> Result<(), String> {
+    fn activate_material(&mut self, material_id: &ResourceId<Material>) -> Result<(), LazuliError> {
         // apply shader
         match self.get_material_shader(material_id) {
             Some(material) => {
                 material.apply();
             },
-            None => return Err(format!("Shader for material {} not found", material_id.id())),
+            None => return Err(AssetError::NotFound { asset_type: "material shader", id: *material_id.id() }.into()),
         }
 
         // activate textures
@@ -162,7 +224,7 @@ impl AssetManager for GlAssetManager {
             Some(material) => {
                 texture_ids = material.texture_ids_copy();
             },
-            None => return Err(format!("Material {} not found", material_id.id())),
+            None => return Err(AssetError::NotFound { asset_type: "material", id: *material_id.id() }.into()),
         }
 
         for (unit, texture_id) in texture_ids.iter().enumerate() {
@@ -170,7 +232,7 @@ impl AssetManager for GlAssetManager {
                 Some(texture) => {
                     texture.activate(unit)
                 },
-                None => return Err(format!("Texture {} from material {} was not found", texture_id.id(), material_id.id())),
+                None => return Err(AssetError::NotFound { asset_type: "texture", id: *texture_id.id() }.into()),
             }
         }
     
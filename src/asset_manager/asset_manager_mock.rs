@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use glam::Vec2;
 
-use crate::{asset_manager::{AssetCollection, AssetManager}, graphics::{font::{self, bitmap_mock::MockBitmap, font_mock::MockFont, Font}, material::Material, shader::{shader_builder_mock::MockShaderBuilder, ShaderBuilder, ShaderProgram}, texture::{texture_mock::MockTexture, Texture, TextureImage}}, ResourceId};
+use crate::{error::{AssetError, LazuliError}, asset_manager::{AssetCollection, AssetManager}, graphics::{font::{self, bitmap_mock::MockBitmap, font_mock::MockFont, Font}, material::Material, shader::{shader_builder_mock::MockShaderBuilder, ShaderBuilder, ShaderProgram}, texture::{texture_mock::MockTexture, Texture, TextureImage}}, ResourceId};
 
 pub struct MockAssetManager {
     textures: AssetCollection<Box<dyn Texture>, u32>,
@@ -25,7 +25,7 @@ impl MockAssetManager {
 }
 
 impl AssetManager for MockAssetManager {
-    fn load_texture(&mut self, _path: &String) -> Result<ResourceId<Box<dyn Texture>>, String> {
+    fn load_texture(&mut self, _path: &String) -> Result<ResourceId<Box<dyn Texture>>, LazuliError> {
         let texture = MockTexture {
             size: Vec2{x: 720.0, y: 1280.0}
         };
@@ -34,7 +34,7 @@ impl AssetManager for MockAssetManager {
         self.textures.add(Box::new(texture), builder_hash)
     }
 
-    fn load_texture_from_image(&mut self, _texture_image: &dyn TextureImage) -> Result<ResourceId<Box<dyn Texture>>, String> {
+    fn load_texture_from_image(&mut self, _texture_image: &dyn TextureImage) -> Result<ResourceId<Box<dyn Texture>>, LazuliError> {
         let texture = MockTexture {
             size: Vec2{x: 720.0, y: 1280.0}
         };
@@ -47,7 +47,7 @@ impl AssetManager for MockAssetManager {
         self.textures.get_asset_by_id(id)
     }
 
-    fn load_font(&mut self, _bitmap_builder: &dyn font::BitmapBuilder, _shader_builder: Option<Box<dyn ShaderBuilder>>) -> Result<ResourceId<Box<dyn Font>>, String> {
+    fn load_font(&mut self, _bitmap_builder: &dyn font::BitmapBuilder, _shader_builder: Option<Box<dyn ShaderBuilder>>) -> Result<ResourceId<Box<dyn Font>>, LazuliError> {
         let atlas = MockBitmap {
             characters: HashMap::new(),
             line_height: 5.0,
@@ -77,7 +77,7 @@ impl AssetManager for MockAssetManager {
         self.fonts.get_asset_by_id(id)
     }
 
-    fn load_shader(&mut self, shader_builder: Box<dyn ShaderBuilder>) -> Result<ResourceId<Box<dyn ShaderProgram>>, String> {
+    fn load_shader(&mut self, shader_builder: Box<dyn ShaderBuilder>) -> Result<ResourceId<Box<dyn ShaderProgram>>, LazuliError> {
         let shader = shader_builder.build()?;
         let hash = self.new_hash();
         self.shaders.add(shader, hash)
@@ -87,7 +87,18 @@ impl AssetManager for MockAssetManager {
         self.shaders.get_asset_by_id(id)
     }
 
-    fn load_material(&mut self, shader_id: &ResourceId<Box< dyn ShaderProgram>>) -> Result<ResourceId<Material>, String> {
+    fn reload_shader(&mut self, id: &ResourceId<Box<dyn ShaderProgram>>) -> Result<(), LazuliError> {
+        match self.shaders.get_asset_by_id(id) {
+            Some(_) => Ok(()),
+            None => Err(AssetError::NotFound { asset_type: "shader", id: *id.id() }.into()),
+        }
+    }
+
+    fn reload_changed_shaders(&mut self) -> Vec<ResourceId<Box<dyn ShaderProgram>>> {
+        vec![]
+    }
+
+    fn load_material(&mut self, shader_id: &ResourceId<Box< dyn ShaderProgram>>) -> Result<ResourceId<Material>, LazuliError> {
         let material = Material::new(shader_id.duplicate());
         self.materials.add(material, false)
     }
@@ -96,21 +107,21 @@ impl AssetManager for MockAssetManager {
         self.materials.get_mut_asset_by_id(id)
     }
 
-    fn add_material_texture(&mut self, material_id: &ResourceId<Material>, texture_id: &ResourceId<Box<dyn Texture>>) -> Result<(), String> {
+    fn add_material_texture(&mut self, material_id: &ResourceId<Material>, texture_id: &ResourceId<Box<dyn Texture>>) -> Result<(), LazuliError> {
         match self.get_material_by_id(material_id) {
             Some(material) => {
                 material.push_texture_id(texture_id.duplicate());
                 Ok(())
             },
-            None => Err(format!("Material not found")),
+            None => Err(AssetError::NotFound { asset_type: "material", id: *material_id.id() }.into()),
             
         }
     }
 
-    fn activate_material(&mut self, material_id: &ResourceId<Material>) -> Result<(), String> {
+    fn activate_material(&mut self, material_id: &ResourceId<Material>) -> Result<(), LazuliError> {
         match self.get_material_by_id(material_id) {
             Some(_material) => Ok(()),
-            None => Err(format!("Material not found")),
+            None => Err(AssetError::NotFound { asset_type: "material", id: *material_id.id() }.into()),
         }
     }
 
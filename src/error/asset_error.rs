@@ -0,0 +1,48 @@
+use std::{error::Error, fmt, io};
+
+#[derive(Debug)]
+pub enum AssetError {
+    /// A file could not be read or written
+    Io { path: String, source: io::Error },
+    /// An image could not be decoded or encoded
+    Image { path: String, source: image::ImageError },
+    /// The file could be read, but it does not contain a font
+    InvalidFont { path: String },
+    /// A font bitmap could not be created with the settings of its builder
+    InvalidBitmap(String),
+    /// Cached asset data could not be converted to or from json
+    Serialization(serde_json::Error),
+    /// Data other than an asset cache, described by `what`, could not be converted to or from json
+    Json { what: &'static str, source: serde_json::Error },
+    /// There is no asset of the given type with the given id
+    NotFound { asset_type: &'static str, id: u32 },
+    /// An asset collection tried to hand out an id that is already in use
+    DuplicateId(u32),
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::Io { path, .. } => write!(f, "failed to access file {:?}", path),
+            AssetError::Image { path, .. } => write!(f, "failed to process image {:?}", path),
+            AssetError::InvalidFont { path } => write!(f, "file {:?} does not contain a valid font", path),
+            AssetError::InvalidBitmap(reason) => write!(f, "failed to create font bitmap: {}", reason),
+            AssetError::Serialization(_) => write!(f, "failed to serialize asset cache"),
+            AssetError::Json { what, source } => write!(f, "failed to convert {} to or from json: {}", what, source),
+            AssetError::NotFound { asset_type, id } => write!(f, "{} with id {} was not found", asset_type, id),
+            AssetError::DuplicateId(id) => write!(f, "encountered duplicate id {} while adding asset to asset collection", id),
+        }
+    }
+}
+
+impl Error for AssetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AssetError::Io { source, .. } => Some(source),
+            AssetError::Image { source, .. } => Some(source),
+            AssetError::Serialization(source) => Some(source),
+            AssetError::Json { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
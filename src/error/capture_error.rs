@@ -0,0 +1,32 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub enum CaptureError {
+    /// An offscreen framebuffer could not be completed. Holds the status that OpenGL reported.
+    IncompleteFramebuffer(u32),
+    /// A captured image does not match its reference image within the tolerance
+    GoldenMismatch { path: String, differing_pixels: u64, max_channel_difference: u8 },
+    /// A captured image has a different size than its reference image
+    GoldenSizeMismatch { path: String, expected: (u32, u32), actual: (u32, u32) },
+    /// There is no reference image to compare a captured image with
+    GoldenMissing { path: String },
+}
+
+impl fmt::Display for CaptureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaptureError::IncompleteFramebuffer(status) => write!(f, "offscreen framebuffer is incomplete (status 0x{:x})", status),
+            CaptureError::GoldenMismatch { path, differing_pixels, max_channel_difference } => write!(
+                f, "image does not match reference {:?}: {} pixels differ, by at most {}", path, differing_pixels, max_channel_difference,
+            ),
+            CaptureError::GoldenSizeMismatch { path, expected, actual } => write!(
+                f, "image of {}x{} does not match the size of reference {:?}, which is {}x{}", actual.0, actual.1, path, expected.0, expected.1,
+            ),
+            CaptureError::GoldenMissing { path } => write!(
+                f, "reference image {:?} does not exist, set LAZULI_UPDATE_GOLDEN=1 to create it", path,
+            ),
+        }
+    }
+}
+
+impl Error for CaptureError {}
@@ -0,0 +1,23 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub enum InputError {
+    /// A shortcut contains a name that is neither a key nor a modifier
+    UnknownKey { shortcut: String, key: String },
+    /// A shortcut consists of modifiers only
+    MissingKey(String),
+    /// A shortcut contains more than one key that is not a modifier
+    MultipleKeys(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::UnknownKey { shortcut, key } => write!(f, "unknown key {:?} in shortcut {:?}", key, shortcut),
+            InputError::MissingKey(shortcut) => write!(f, "shortcut {:?} has no key besides modifiers", shortcut),
+            InputError::MultipleKeys(shortcut) => write!(f, "shortcut {:?} has more than one key besides modifiers", shortcut),
+        }
+    }
+}
+
+impl Error for InputError {}
@@ -0,0 +1,79 @@
+use std::{error::Error, fmt};
+
+use super::{AssetError, CaptureError, InputError, ShaderError, UiError, WindowError};
+
+/// Error type of all fallible engine functions. Match on the variant (and the error it holds) to find out
+/// what caused the failure.
+#[derive(Debug)]
+pub enum LazuliError {
+    Asset(AssetError),
+    Shader(ShaderError),
+    Ui(UiError),
+    Window(WindowError),
+    Capture(CaptureError),
+    Input(InputError),
+    /// An error that does not come from one of the engine systems, for example from a `Scene` of a project
+    Other(String),
+}
+
+impl fmt::Display for LazuliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LazuliError::Asset(err) => err.fmt(f),
+            LazuliError::Shader(err) => err.fmt(f),
+            LazuliError::Ui(err) => err.fmt(f),
+            LazuliError::Window(err) => err.fmt(f),
+            LazuliError::Capture(err) => err.fmt(f),
+            LazuliError::Input(err) => err.fmt(f),
+            LazuliError::Other(message) => f.write_str(message),
+        }
+    }
+}
+
+/// `LazuliError` only wraps the error of an engine system, so it shows the message of that error and passes
+/// on its source.
+impl Error for LazuliError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LazuliError::Asset(err) => err.source(),
+            LazuliError::Shader(err) => err.source(),
+            LazuliError::Ui(err) => err.source(),
+            LazuliError::Window(err) => err.source(),
+            LazuliError::Capture(err) => err.source(),
+            LazuliError::Input(err) => err.source(),
+            LazuliError::Other(_) => None,
+        }
+    }
+}
+
+impl From<AssetError> for LazuliError {
+    fn from(err: AssetError) -> Self { LazuliError::Asset(err) }
+}
+
+impl From<ShaderError> for LazuliError {
+    fn from(err: ShaderError) -> Self { LazuliError::Shader(err) }
+}
+
+impl From<UiError> for LazuliError {
+    fn from(err: UiError) -> Self { LazuliError::Ui(err) }
+}
+
+impl From<WindowError> for LazuliError {
+    fn from(err: WindowError) -> Self { LazuliError::Window(err) }
+}
+
+impl From<CaptureError> for LazuliError {
+    fn from(err: CaptureError) -> Self { LazuliError::Capture(err) }
+}
+
+impl From<InputError> for LazuliError {
+    fn from(err: InputError) -> Self { LazuliError::Input(err) }
+}
+
+impl From<String> for LazuliError {
+    fn from(message: String) -> Self { LazuliError::Other(message) }
+}
+
+impl From<&str> for LazuliError {
+    fn from(message: &str) -> Self { LazuliError::Other(message.to_string()) }
+}
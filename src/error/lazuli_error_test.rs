@@ -0,0 +1,42 @@
+use std::{error::Error, io};
+
+use crate::{graphics::ui::UiElementId, ResourceId};
+
+use super::{AssetError, LazuliError, ShaderError, UiError};
+
+#[test]
+fn test_display_uses_message_of_wrapped_error() {
+    let err: LazuliError = UiError::ElementNotFound(ResourceId::<UiElementId>::new(3)).into();
+    assert_eq!(err.to_string(), "element with id 3 was not found");
+
+    let err: LazuliError = ShaderError::AttributeNotFound("position".to_string()).into();
+    assert_eq!(err.to_string(), "could not find attribute location of position");
+
+    let err: LazuliError = "something went wrong".into();
+    assert_eq!(err.to_string(), "something went wrong");
+}
+
+#[test]
+fn test_source_chaining() {
+    let io_err = io::Error::new(io::ErrorKind::NotFound, "no such file");
+    let err: LazuliError = AssetError::Io { path: "./missing.png".to_string(), source: io_err }.into();
+
+    assert_eq!(err.to_string(), "failed to access file \"./missing.png\"");
+    assert_eq!(err.source().unwrap().to_string(), "no such file");
+
+    let err: LazuliError = UiError::InvalidBuilder("invalid".to_string()).into();
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn test_match_on_cause() {
+    let err: LazuliError = AssetError::NotFound { asset_type: "font", id: 7 }.into();
+
+    match err {
+        LazuliError::Asset(AssetError::NotFound { asset_type, id }) => {
+            assert_eq!(asset_type, "font");
+            assert_eq!(id, 7);
+        },
+        _ => panic!("unexpected error: {}", err),
+    }
+}
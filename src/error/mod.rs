@@ -1,3 +1,27 @@
 pub mod opengl;
 
 pub use opengl::gl_check_errors;
+
+mod lazuli_error;
+pub use lazuli_error::LazuliError;
+
+mod asset_error;
+pub use asset_error::AssetError;
+
+mod shader_error;
+pub use shader_error::ShaderError;
+
+mod ui_error;
+pub use ui_error::UiError;
+
+mod window_error;
+pub use window_error::WindowError;
+
+mod capture_error;
+pub use capture_error::CaptureError;
+
+mod input_error;
+pub use input_error::InputError;
+
+#[cfg(test)]
+mod lazuli_error_test;
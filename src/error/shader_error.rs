@@ -0,0 +1,36 @@
+use std::{error::Error, ffi::NulError, fmt, io};
+
+#[derive(Debug)]
+pub enum ShaderError {
+    /// The shader source file could not be read
+    Io { path: String, source: io::Error },
+    /// A string that gets passed to OpenGL contains a nul byte
+    InvalidString { value: String, source: NulError },
+    /// `log` holds the compile errors reported by the driver
+    Compile { path: String, shader_type: &'static str, log: String },
+    /// `log` holds the link errors reported by the driver
+    Link { log: String },
+    AttributeNotFound(String),
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaderError::Io { path, .. } => write!(f, "failed to read shader file with path {:?}", path),
+            ShaderError::InvalidString { value, .. } => write!(f, "failed to create CString from {:?}", value),
+            ShaderError::Compile { path, shader_type, log } => write!(f, "failed to compile {} shader {}: [{}]", shader_type, path, log),
+            ShaderError::Link { log } => write!(f, "failed to link shader program: [{}]", log),
+            ShaderError::AttributeNotFound(attribute) => write!(f, "could not find attribute location of {}", attribute),
+        }
+    }
+}
+
+impl Error for ShaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ShaderError::Io { source, .. } => Some(source),
+            ShaderError::InvalidString { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use std::{any::TypeId, error::Error, fmt};
+
+use crate::{graphics::ui::{UiElementId, UiLayoutId, UiWidgetId}, ResourceId};
+
+#[derive(Debug)]
+pub enum UiError {
+    /// There is no element with the given id, or it is not of the type that the operation needs (for example
+    /// setting the text of a rectangle)
+    ElementNotFound(ResourceId<UiElementId>),
+    WidgetNotFound(ResourceId<UiWidgetId>),
+    LayoutNotFound(ResourceId<UiLayoutId>),
+    /// The element that another element is anchored to could not be found
+    AnchorNotFound(ResourceId<UiElementId>),
+    UnhandledElementType(TypeId),
+    /// A ui builder contains settings that can not be combined
+    InvalidBuilder(String),
+}
+
+impl fmt::Display for UiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UiError::ElementNotFound(id) => write!(f, "element with id {} was not found", id.id()),
+            UiError::WidgetNotFound(id) => write!(f, "widget with id {} was not found", id.id()),
+            UiError::LayoutNotFound(id) => write!(f, "layout with id {} was not found", id.id()),
+            UiError::AnchorNotFound(id) => write!(f, "anchor element with id {} was not found", id.id()),
+            UiError::UnhandledElementType(type_id) => write!(f, "unhandled element type {:?}", type_id),
+            UiError::InvalidBuilder(reason) => write!(f, "invalid builder: {}", reason),
+        }
+    }
+}
+
+impl Error for UiError {}
@@ -0,0 +1,27 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub enum WindowError {
+    /// The window or its graphics context could not be created
+    Creation(glutin::CreationError),
+    /// The graphics context could not be made current
+    Context(glutin::ContextError),
+}
+
+impl fmt::Display for WindowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowError::Creation(_) => write!(f, "failed to create window"),
+            WindowError::Context(_) => write!(f, "failed to make graphics context current"),
+        }
+    }
+}
+
+impl Error for WindowError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WindowError::Creation(source) => Some(source),
+            WindowError::Context(source) => Some(source),
+        }
+    }
+}
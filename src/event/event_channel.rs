@@ -0,0 +1,246 @@
+use std::{any::Any, cell::RefCell, collections::{HashMap, VecDeque}, marker::PhantomData, rc::Rc};
+
+use crate::log;
+
+/// Number of events that a reader can fall behind before the overflow policy kicks in
+pub const DEFAULT_EVENT_CAPACITY: usize = 256;
+
+/// What to do when an event gets sent to a reader that already has `capacity` unread events
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OverflowPolicy {
+    /// Make room by removing the oldest unread event
+    #[default]
+    DropOldest,
+    /// Do not deliver the new event to that reader
+    DropNewest,
+    /// Keep every event, so the queue of the reader keeps on growing until it gets read
+    Grow,
+}
+
+/// When the subscriptions and readers of an event type get the events that are sent
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DispatchMode {
+    /// Right away, while sending
+    #[default]
+    Immediate,
+    /// At the end of the frame, when `EventSystem::dispatch_queued` gets called
+    Queued,
+}
+
+/// Returned by a subscription callback to decide whether subscriptions with a lower priority and readers
+/// should still get the event
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EventPropagation {
+    Continue,
+    Stop,
+}
+
+/// Identifies a subscription, so that it can be removed with `EventSystem::unsubscribe`
+#[derive(Debug, PartialEq)]
+pub struct SubscriptionId<T> {
+    id: u64,
+    event_type: PhantomData<T>,
+}
+
+struct Subscription<T> {
+    id: u64,
+    priority: i32,
+    callback: Box<dyn FnMut(&T) -> EventPropagation>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct EventStats {
+    /// Number of readers that are currently registered
+    pub subscribers: usize,
+    /// Number of callback subscriptions
+    pub callbacks: usize,
+    /// Number of events that were sent, counted once per event no matter how many readers received it
+    pub sent: u64,
+    /// Number of events that did not reach a reader because its queue was full. An event that gets dropped
+    /// by two readers counts twice.
+    pub dropped: u64,
+    /// Largest number of unread events of a single reader
+    pub max_queued: usize,
+}
+
+/// The unread events of every reader of a single event type
+pub(super) struct EventChannel<T> {
+    queues: HashMap<u64, VecDeque<T>>,
+    next_reader_id: u64,
+    /// Sorted from high to low priority. Subscriptions with the same priority keep the order they were added in.
+    subscriptions: Vec<Subscription<T>>,
+    next_subscription_id: u64,
+    dispatch_mode: DispatchMode,
+    /// Events that wait for `dispatch_queued` when the dispatch mode is `Queued`
+    queued_events: Vec<T>,
+    capacity: usize,
+    overflow_policy: OverflowPolicy,
+    sent: u64,
+    dropped: u64,
+    type_name: &'static str,
+}
+
+impl<T: Clone> EventChannel<T> {
+    pub fn new() -> Self {
+        Self {
+            queues: HashMap::new(),
+            next_reader_id: 0,
+            subscriptions: vec![],
+            next_subscription_id: 0,
+            dispatch_mode: DispatchMode::default(),
+            queued_events: vec![],
+            capacity: DEFAULT_EVENT_CAPACITY,
+            overflow_policy: OverflowPolicy::default(),
+            sent: 0,
+            dropped: 0,
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub fn add_reader(&mut self) -> u64 {
+        let reader_id = self.next_reader_id;
+        self.next_reader_id += 1;
+        self.queues.insert(reader_id, VecDeque::new());
+
+        reader_id
+    }
+
+    pub fn remove_reader(&mut self, reader_id: u64) {
+        self.queues.remove(&reader_id);
+    }
+
+    pub fn subscribe(&mut self, priority: i32, callback: Box<dyn FnMut(&T) -> EventPropagation>) -> SubscriptionId<T> {
+        let id = self.next_subscription_id;
+        self.next_subscription_id += 1;
+
+        let index = self.subscriptions.partition_point(|subscription| subscription.priority >= priority);
+        self.subscriptions.insert(index, Subscription { id, priority, callback });
+
+        SubscriptionId { id, event_type: PhantomData }
+    }
+
+    pub fn unsubscribe(&mut self, subscription_id: &SubscriptionId<T>) {
+        self.subscriptions.retain(|subscription| subscription.id != subscription_id.id);
+    }
+
+    /// Takes the channel instead of `self`, because the subscriptions run without the channel being borrowed.
+    /// That way a callback can read an `EventReader` of the same event type.
+    pub fn send(channel: &RefCell<Self>, value: T) {
+        let dispatch_mode = {
+            let mut channel = channel.borrow_mut();
+            channel.sent += 1;
+            channel.dispatch_mode
+        };
+
+        match dispatch_mode {
+            DispatchMode::Immediate => Self::dispatch(channel, value),
+            DispatchMode::Queued => channel.borrow_mut().queued_events.push(value),
+        }
+    }
+
+    pub fn dispatch_queued(channel: &RefCell<Self>) {
+        let queued_events = std::mem::take(&mut channel.borrow_mut().queued_events);
+        for value in queued_events {
+            Self::dispatch(channel, value);
+        }
+    }
+
+    /// Run the subscriptions from high to low priority and hand the event to the readers, unless one of the
+    /// subscriptions stops the propagation
+    fn dispatch(channel: &RefCell<Self>, value: T) {
+        let mut subscriptions = std::mem::take(&mut channel.borrow_mut().subscriptions);
+        let is_stopped = subscriptions.iter_mut()
+            .any(|subscription| (subscription.callback)(&value) == EventPropagation::Stop);
+
+        let mut channel = channel.borrow_mut();
+        channel.subscriptions = subscriptions;
+
+        if !is_stopped {
+            channel.deliver(value);
+        }
+    }
+
+    fn deliver(&mut self, value: T) {
+        let was_dropping = self.dropped > 0;
+
+        for queue in self.queues.values_mut() {
+            if queue.len() < self.capacity {
+                queue.push_back(value.clone());
+                continue;
+            }
+
+            match self.overflow_policy {
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                    queue.push_back(value.clone());
+                    self.dropped += 1;
+                },
+                OverflowPolicy::DropNewest => self.dropped += 1,
+                OverflowPolicy::Grow => queue.push_back(value.clone()),
+            }
+        }
+
+        if !was_dropping && self.dropped > 0 {
+            log::engine_warn(format!("dropping {} events because a reader does not keep up", self.type_name));
+        }
+    }
+
+    pub fn read(&mut self, reader_id: u64) -> Vec<T> {
+        match self.queues.get_mut(&reader_id) {
+            Some(queue) => queue.drain(..).collect(),
+            None => vec![],
+        }
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+    }
+
+    pub fn set_overflow_policy(&mut self, overflow_policy: OverflowPolicy) {
+        self.overflow_policy = overflow_policy;
+    }
+
+    pub fn set_dispatch_mode(channel: &RefCell<Self>, dispatch_mode: DispatchMode) {
+        if dispatch_mode == DispatchMode::Immediate {
+            Self::dispatch_queued(channel);
+        }
+
+        channel.borrow_mut().dispatch_mode = dispatch_mode;
+    }
+
+    pub fn stats(&self) -> EventStats {
+        EventStats {
+            subscribers: self.queues.len(),
+            callbacks: self.subscriptions.len(),
+            sent: self.sent,
+            dropped: self.dropped,
+            max_queued: self.queues.values().map(|queue| queue.len()).max().unwrap_or(0),
+        }
+    }
+}
+
+/// Gives access to the channel of an event type without knowing the type
+pub(super) trait AnyEventChannel {
+    fn stats(&self) -> EventStats;
+    fn type_name(&self) -> &'static str;
+    fn dispatch_queued(&self);
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Clone + 'static> AnyEventChannel for Rc<RefCell<EventChannel<T>>> {
+    fn stats(&self) -> EventStats {
+        self.borrow().stats()
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.borrow().type_name
+    }
+
+    fn dispatch_queued(&self) {
+        EventChannel::dispatch_queued(self);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
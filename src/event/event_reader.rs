@@ -1,40 +1,42 @@
-use std::sync::mpsc::TryRecvError;
+use std::{cell::RefCell, rc::Rc};
 
-pub struct EventReader<T: Clone + Sync> {
-    bus_reader: Option<bus::BusReader<T>>,
+use super::event_channel::EventChannel;
+
+/// Receives every event of type `T` that gets sent after the reader got registered. Dropping or closing the
+/// reader unregisters it from the `EventSystem`.
+pub struct EventReader<T: Clone> {
+    channel: Option<Rc<RefCell<EventChannel<T>>>>,
+    reader_id: u64,
 }
 
-impl<T: Clone + Sync> EventReader<T> {
-    pub fn new(bus_reader: bus::BusReader<T>) -> Self {
-        Self { bus_reader: Some(bus_reader) }
+impl<T: Clone> EventReader<T> {
+    pub(super) fn new(channel: Rc<RefCell<EventChannel<T>>>) -> Self {
+        let reader_id = channel.borrow_mut().add_reader();
+        Self { channel: Some(channel), reader_id }
     }
 
+    /// Take all events that were sent since the last read
     pub fn read(&mut self) -> Vec<T> {
-        let mut result = Vec::<T>::new();
+        match &self.channel {
+            Some(channel) => channel.borrow_mut().read(self.reader_id),
+            None => vec![],
+        }
+    }
 
-        loop {
-            match &mut self.bus_reader {
-                Some(some) => {
-                    match some.try_recv() {
-                        Ok(value) => {
-                            result.push(value);
-                        }
-                        Err(TryRecvError::Empty) => {
-                            break;
-                        }
-                        Err(TryRecvError::Disconnected) => {} // the sender has hungup, maybe the event system got destroyed?
-                    }
-                }
-                None => {
-                    break;
-                }
-            }
+    /// Stop receiving events
+    pub fn close(&mut self) {
+        if let Some(channel) = self.channel.take() {
+            channel.borrow_mut().remove_reader(self.reader_id);
         }
+    }
 
-        return result;
+    pub fn is_closed(&self) -> bool {
+        self.channel.is_none()
     }
+}
 
-    pub fn close(&mut self) {
-        self.bus_reader.take();
+impl<T: Clone> Drop for EventReader<T> {
+    fn drop(&mut self) {
+        self.close();
     }
 }
@@ -1,39 +1,100 @@
-use std::{collections::HashMap, any::{TypeId, Any}};
+use std::{any::TypeId, cell::RefCell, collections::HashMap, rc::Rc};
 
-use super::EventReader;
-
-struct Listener<T> {
-    sender: bus::Bus<T>,
-}
+use super::{event_channel::{AnyEventChannel, EventChannel}, DispatchMode, EventPropagation, EventReader, EventStats, OverflowPolicy, SubscriptionId};
 
+/// Sends events to every `EventReader` and subscription of their type. Sending never blocks: when a reader falls
+/// behind by more than the capacity of the event type, the overflow policy of that type decides which events it
+/// loses.
+///
+/// Subscriptions get an event before the readers do, from high to low priority. A subscription can stop the
+/// propagation, in which case subscriptions with a lower priority and all readers never see the event.
 pub struct EventSystem {
-    listeners: HashMap<TypeId, Box<dyn Any>>,
+    channels: HashMap<TypeId, Box<dyn AnyEventChannel>>,
 }
 
 impl EventSystem {
     pub fn new() -> Self {
-        Self { listeners: HashMap::new() }
+        Self { channels: HashMap::new() }
     }
 
-    pub fn register<T: 'static + std::clone::Clone + std::marker::Sync>(&mut self) -> EventReader<T> {
-        let listener = self
-            .listeners
-            .entry(TypeId::of::<T>())
-            .or_insert_with(|| {
-                Box::new(Listener {
-                    sender: bus::Bus::<T>::new(256),
-                })
-            })
-            .downcast_mut::<Listener<T>>()
-            .unwrap();
+    pub fn register<T: Clone + 'static>(&mut self) -> EventReader<T> {
+        EventReader::new(self.channel::<T>())
+    }
 
-        EventReader::<T>::new(listener.sender.add_rx())
+    /// Call `callback` for every event of type `T`, with a priority of 0
+    pub fn subscribe<T: Clone + 'static>(&mut self, callback: impl FnMut(&T) -> EventPropagation + 'static) -> SubscriptionId<T> {
+        self.subscribe_with_priority(0, callback)
+    }
+
+    /// Call `callback` for every event of type `T`. Callbacks with a higher priority get called first.
+    pub fn subscribe_with_priority<T: Clone + 'static>(&mut self, priority: i32, callback: impl FnMut(&T) -> EventPropagation + 'static) -> SubscriptionId<T> {
+        self.channel::<T>().borrow_mut().subscribe(priority, Box::new(callback))
+    }
+
+    pub fn unsubscribe<T: Clone + 'static>(&mut self, subscription_id: &SubscriptionId<T>) {
+        if let Some(channel) = self.existing_channel::<T>() {
+            channel.borrow_mut().unsubscribe(subscription_id);
+        }
     }
 
     pub fn send<T: Clone + 'static>(&mut self, value: T) {
-        if let Some(listener) = self.listeners.get_mut(&TypeId::of::<T>()) {
-            let listener = listener.downcast_mut::<Listener<T>>().unwrap();
-            listener.sender.broadcast(value);
+        if let Some(channel) = self.existing_channel::<T>() {
+            EventChannel::send(channel, value);
         }
     }
+
+    /// Set the number of unread events a reader of type `T` can have before the overflow policy kicks in.
+    /// Defaults to `DEFAULT_EVENT_CAPACITY`.
+    pub fn set_capacity<T: Clone + 'static>(&mut self, capacity: usize) {
+        self.channel::<T>().borrow_mut().set_capacity(capacity);
+    }
+
+    pub fn set_overflow_policy<T: Clone + 'static>(&mut self, overflow_policy: OverflowPolicy) {
+        self.channel::<T>().borrow_mut().set_overflow_policy(overflow_policy);
+    }
+
+    /// Choose between handing out events of type `T` right away or at the end of the frame. Switching back to
+    /// `Immediate` dispatches the events that are still queued.
+    pub fn set_dispatch_mode<T: Clone + 'static>(&mut self, dispatch_mode: DispatchMode) {
+        EventChannel::set_dispatch_mode(&self.channel::<T>(), dispatch_mode);
+    }
+
+    /// Hand out the events of every type with the `Queued` dispatch mode. The engine calls this at the end of
+    /// every frame.
+    pub fn dispatch_queued(&mut self) {
+        for channel in self.channels.values() {
+            channel.dispatch_queued();
+        }
+    }
+
+    pub fn stats<T: Clone + 'static>(&self) -> EventStats {
+        match self.existing_channel::<T>() {
+            Some(channel) => channel.borrow().stats(),
+            None => EventStats::default(),
+        }
+    }
+
+    /// The stats of every event type that has been registered or configured, by type name
+    pub fn all_stats(&self) -> Vec<(&'static str, EventStats)> {
+        let mut stats: Vec<(&'static str, EventStats)> = self.channels.values()
+            .map(|channel| (channel.type_name(), channel.stats()))
+            .collect();
+        stats.sort_by_key(|(type_name, _)| *type_name);
+
+        stats
+    }
+
+    fn channel<T: Clone + 'static>(&mut self) -> Rc<RefCell<EventChannel<T>>> {
+        self.channels
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(Rc::new(RefCell::new(EventChannel::<T>::new()))))
+            .as_any()
+            .downcast_ref::<Rc<RefCell<EventChannel<T>>>>()
+            .unwrap()
+            .clone()
+    }
+
+    fn existing_channel<T: Clone + 'static>(&self) -> Option<&Rc<RefCell<EventChannel<T>>>> {
+        self.channels.get(&TypeId::of::<T>())?.as_any().downcast_ref::<Rc<RefCell<EventChannel<T>>>>()
+    }
 }
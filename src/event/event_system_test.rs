@@ -0,0 +1,262 @@
+use std::{cell::RefCell, rc::Rc};
+
+use super::{DispatchMode, EventPropagation, EventSystem, EventStats, OverflowPolicy};
+
+#[derive(Clone, Debug, PartialEq)]
+struct TestEvent(u32);
+
+#[derive(Clone, Debug, PartialEq)]
+struct OtherEvent;
+
+#[test]
+fn test_every_reader_receives_events() {
+    let mut event_system = EventSystem::new();
+    let mut first_reader = event_system.register::<TestEvent>();
+    let mut second_reader = event_system.register::<TestEvent>();
+
+    event_system.send(TestEvent(1));
+    event_system.send(TestEvent(2));
+
+    assert_eq!(vec![TestEvent(1), TestEvent(2)], first_reader.read());
+    assert!(first_reader.read().is_empty());
+    assert_eq!(vec![TestEvent(1), TestEvent(2)], second_reader.read());
+}
+
+#[test]
+fn test_send_without_readers_does_nothing() {
+    let mut event_system = EventSystem::new();
+
+    event_system.send(TestEvent(1));
+
+    assert_eq!(EventStats::default(), event_system.stats::<TestEvent>());
+}
+
+#[test]
+fn test_drop_oldest() {
+    let mut event_system = EventSystem::new();
+    event_system.set_capacity::<TestEvent>(2);
+    let mut reader = event_system.register::<TestEvent>();
+
+    for i in 0..5 {
+        event_system.send(TestEvent(i));
+    }
+
+    assert_eq!(3, event_system.stats::<TestEvent>().dropped);
+    assert_eq!(vec![TestEvent(3), TestEvent(4)], reader.read());
+}
+
+#[test]
+fn test_drop_newest() {
+    let mut event_system = EventSystem::new();
+    event_system.set_capacity::<TestEvent>(2);
+    event_system.set_overflow_policy::<TestEvent>(OverflowPolicy::DropNewest);
+    let mut reader = event_system.register::<TestEvent>();
+
+    for i in 0..5 {
+        event_system.send(TestEvent(i));
+    }
+
+    assert_eq!(3, event_system.stats::<TestEvent>().dropped);
+    assert_eq!(vec![TestEvent(0), TestEvent(1)], reader.read());
+}
+
+#[test]
+fn test_grow() {
+    let mut event_system = EventSystem::new();
+    event_system.set_capacity::<TestEvent>(2);
+    event_system.set_overflow_policy::<TestEvent>(OverflowPolicy::Grow);
+    let mut reader = event_system.register::<TestEvent>();
+
+    for i in 0..5 {
+        event_system.send(TestEvent(i));
+    }
+
+    let stats = event_system.stats::<TestEvent>();
+    assert_eq!(0, stats.dropped);
+    assert_eq!(5, stats.max_queued);
+    assert_eq!(5, reader.read().len());
+}
+
+#[test]
+fn test_slow_reader_does_not_affect_other_readers() {
+    let mut event_system = EventSystem::new();
+    event_system.set_capacity::<TestEvent>(1);
+    let _slow_reader = event_system.register::<TestEvent>();
+    let mut reader = event_system.register::<TestEvent>();
+
+    event_system.send(TestEvent(1));
+    assert_eq!(vec![TestEvent(1)], reader.read());
+    event_system.send(TestEvent(2));
+    assert_eq!(vec![TestEvent(2)], reader.read());
+
+    assert_eq!(1, event_system.stats::<TestEvent>().dropped);
+}
+
+#[test]
+fn test_dropped_and_closed_readers_unregister() {
+    let mut event_system = EventSystem::new();
+    let first_reader = event_system.register::<TestEvent>();
+    let mut second_reader = event_system.register::<TestEvent>();
+    assert_eq!(2, event_system.stats::<TestEvent>().subscribers);
+
+    drop(first_reader);
+    assert_eq!(1, event_system.stats::<TestEvent>().subscribers);
+
+    second_reader.close();
+    assert!(second_reader.is_closed());
+    assert_eq!(0, event_system.stats::<TestEvent>().subscribers);
+
+    event_system.send(TestEvent(1));
+    assert!(second_reader.read().is_empty());
+    assert_eq!(1, event_system.stats::<TestEvent>().sent);
+}
+
+#[test]
+fn test_all_stats() {
+    let mut event_system = EventSystem::new();
+    let _reader = event_system.register::<TestEvent>();
+    let _other_reader = event_system.register::<OtherEvent>();
+
+    event_system.send(TestEvent(1));
+
+    let stats = event_system.all_stats();
+    assert_eq!(2, stats.len());
+
+    let (type_name, test_event_stats) = stats.iter().find(|(type_name, _)| type_name.ends_with("TestEvent")).unwrap();
+    assert!(type_name.contains("event_system_test"));
+    assert_eq!(1, test_event_stats.sent);
+    assert_eq!(1, test_event_stats.subscribers);
+}
+
+#[test]
+fn test_subscriptions_run_by_priority() {
+    let mut event_system = EventSystem::new();
+    let calls = Rc::new(RefCell::new(vec![]));
+
+    let low_calls = calls.clone();
+    event_system.subscribe_with_priority(-1, move |event: &TestEvent| {
+        low_calls.borrow_mut().push(format!("low {}", event.0));
+        EventPropagation::Continue
+    });
+    let default_calls = calls.clone();
+    event_system.subscribe(move |event: &TestEvent| {
+        default_calls.borrow_mut().push(format!("default {}", event.0));
+        EventPropagation::Continue
+    });
+    let high_calls = calls.clone();
+    event_system.subscribe_with_priority(10, move |event: &TestEvent| {
+        high_calls.borrow_mut().push(format!("high {}", event.0));
+        EventPropagation::Continue
+    });
+
+    event_system.send(TestEvent(1));
+
+    assert_eq!(vec!["high 1", "default 1", "low 1"], *calls.borrow());
+    assert_eq!(3, event_system.stats::<TestEvent>().callbacks);
+}
+
+#[test]
+fn test_stop_propagation_hides_event_from_lower_priorities_and_readers() {
+    let mut event_system = EventSystem::new();
+    let mut reader = event_system.register::<TestEvent>();
+    let low_calls = Rc::new(RefCell::new(0));
+
+    event_system.subscribe_with_priority(1, |event: &TestEvent| {
+        if event.0 == 0 { EventPropagation::Stop } else { EventPropagation::Continue }
+    });
+    let counter = low_calls.clone();
+    event_system.subscribe(move |_: &TestEvent| {
+        *counter.borrow_mut() += 1;
+        EventPropagation::Continue
+    });
+
+    event_system.send(TestEvent(0));
+    event_system.send(TestEvent(1));
+
+    assert_eq!(1, *low_calls.borrow());
+    assert_eq!(vec![TestEvent(1)], reader.read());
+}
+
+#[test]
+fn test_unsubscribe() {
+    let mut event_system = EventSystem::new();
+    let calls = Rc::new(RefCell::new(0));
+
+    let counter = calls.clone();
+    let subscription_id = event_system.subscribe(move |_: &TestEvent| {
+        *counter.borrow_mut() += 1;
+        EventPropagation::Continue
+    });
+
+    event_system.send(TestEvent(0));
+    event_system.unsubscribe(&subscription_id);
+    event_system.send(TestEvent(1));
+
+    assert_eq!(1, *calls.borrow());
+    assert_eq!(0, event_system.stats::<TestEvent>().callbacks);
+}
+
+#[test]
+fn test_queued_dispatch() {
+    let mut event_system = EventSystem::new();
+    event_system.set_dispatch_mode::<TestEvent>(DispatchMode::Queued);
+    let mut reader = event_system.register::<TestEvent>();
+    let calls = Rc::new(RefCell::new(0));
+
+    let counter = calls.clone();
+    event_system.subscribe(move |_: &TestEvent| {
+        *counter.borrow_mut() += 1;
+        EventPropagation::Continue
+    });
+
+    event_system.send(TestEvent(1));
+    event_system.send(TestEvent(2));
+
+    assert_eq!(0, *calls.borrow());
+    assert!(reader.read().is_empty());
+
+    event_system.dispatch_queued();
+
+    assert_eq!(2, *calls.borrow());
+    assert_eq!(vec![TestEvent(1), TestEvent(2)], reader.read());
+}
+
+#[test]
+fn test_switching_to_immediate_dispatches_queued_events() {
+    let mut event_system = EventSystem::new();
+    event_system.set_dispatch_mode::<TestEvent>(DispatchMode::Queued);
+    let mut reader = event_system.register::<TestEvent>();
+
+    event_system.send(TestEvent(1));
+    event_system.set_dispatch_mode::<TestEvent>(DispatchMode::Immediate);
+
+    assert_eq!(vec![TestEvent(1)], reader.read());
+}
+
+#[test]
+fn test_subscription_can_read_reader_of_same_type() {
+    let mut event_system = EventSystem::new();
+    let reader = Rc::new(RefCell::new(event_system.register::<TestEvent>()));
+    let read_events = Rc::new(RefCell::new(vec![]));
+
+    let (callback_reader, callback_read_events) = (reader.clone(), read_events.clone());
+    event_system.subscribe(move |_: &TestEvent| {
+        callback_read_events.borrow_mut().extend(callback_reader.borrow_mut().read());
+        EventPropagation::Continue
+    });
+
+    event_system.send(TestEvent(1));
+    event_system.send(TestEvent(2));
+
+    // The subscription runs before the event reaches the reader, so it sees the events that came before
+    assert_eq!(vec![TestEvent(1)], *read_events.borrow());
+    assert_eq!(vec![TestEvent(2)], reader.borrow_mut().read());
+
+    event_system.set_dispatch_mode::<TestEvent>(DispatchMode::Queued);
+    event_system.send(TestEvent(3));
+    event_system.send(TestEvent(4));
+    event_system.dispatch_queued();
+
+    assert_eq!(vec![TestEvent(1), TestEvent(3)], *read_events.borrow());
+    assert_eq!(vec![TestEvent(4)], reader.borrow_mut().read());
+}
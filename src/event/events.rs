@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+
+use crate::{graphics::{scene::{scene_factory, Scene, SceneFactory, SceneTransition}, ui::UiWidgetId}, ResourceId};
+
 // Window events
 #[derive(Clone, Debug)]
 pub struct WindowResizeEvent {
@@ -10,6 +14,36 @@ pub struct PixelDensityChangeEvent {
     pub pixel_density: f32,
 }
 
+#[derive(Clone, Debug)]
+pub struct WindowFocusChanged {
+    pub focused: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct WindowMinimized {
+    /// False when the window got restored
+    pub minimized: bool,
+}
+
+/// A file got dragged on to the window and released
+#[derive(Clone, Debug)]
+pub struct FileDropped {
+    pub path: PathBuf,
+}
+
+/// The user tried to close the window. The window closes at the end of the first frame whose updates got this
+/// event, unless a `CancelClose` gets sent before that frame ends. A queued `CloseRequested` arrives at the end
+/// of a frame, so the window then decides one frame later. After cancelling, send `CloseWindow` to close the
+/// window anyway, for example after asking the user to save their changes.
+#[derive(Clone, Debug)]
+pub struct CloseRequested {}
+/// Keep the window open after a `CloseRequested`
+#[derive(Clone, Debug)]
+pub struct CancelClose {}
+/// Close the window and stop the app
+#[derive(Clone, Debug)]
+pub struct CloseWindow {}
+
 // Cursor events
 #[derive(Clone, Debug)]
 pub struct LockCursor {}
@@ -26,3 +60,140 @@ pub struct SetCursorPosition {
     pub x: f32,
     pub y: f32,
 }
+
+// Window control events
+#[derive(Clone, Debug)]
+pub struct SetWindowTitle {
+    pub title: String,
+}
+#[derive(Clone, Debug)]
+pub struct SetFullscreen {
+    pub fullscreen: bool,
+}
+/// Switch between fullscreen and windowed mode
+#[derive(Clone, Debug)]
+pub struct ToggleFullscreen {}
+/// The new size of the window, in logical units
+#[derive(Clone, Debug)]
+pub struct SetWindowSize {
+    pub width: u32,
+    pub height: u32,
+}
+/// Turn vsync on or off, see `Window::set_vsync`
+#[derive(Clone, Debug)]
+pub struct SetVsync {
+    pub vsync: bool,
+}
+
+// Text input events
+/// Allow input method editors (IME) to compose text, see `Window::set_ime_allowed`
+#[derive(Clone, Debug)]
+pub struct SetImeAllowed {
+    pub allowed: bool,
+}
+/// Move the IME candidate box, in logical units
+#[derive(Clone, Debug)]
+pub struct SetImePosition {
+    pub x: f32,
+    pub y: f32,
+}
+
+// UI widget events
+#[derive(Clone, Debug)]
+pub struct ButtonClicked {
+    pub widget_id: ResourceId<UiWidgetId>,
+}
+
+/// Only sent when the value of the slider actually changed
+#[derive(Clone, Debug)]
+pub struct SliderChanged {
+    pub widget_id: ResourceId<UiWidgetId>,
+    pub new_value: f32,
+    pub change_amount: f32,
+}
+
+#[derive(Clone, Debug)]
+pub struct DropdownSelected {
+    pub widget_id: ResourceId<UiWidgetId>,
+    pub value: u32,
+}
+
+/// The mouse started hovering a button, slider, dropdown or icon
+#[derive(Clone, Debug)]
+pub struct WidgetHoverEnter {
+    pub widget_id: ResourceId<UiWidgetId>,
+}
+/// The mouse stopped hovering a button, slider, dropdown or icon
+#[derive(Clone, Debug)]
+pub struct WidgetHoverLeave {
+    pub widget_id: ResourceId<UiWidgetId>,
+}
+
+// Scene events
+/// Add a scene on top of the scene stack, pausing the currently active scene
+#[derive(Clone)]
+pub struct PushScene {
+    pub scene_factory: SceneFactory,
+    pub transition: SceneTransition,
+}
+impl PushScene {
+    pub fn new<T: Scene>() -> Self {
+        Self { scene_factory: scene_factory::<T>(), transition: SceneTransition::Instant }
+    }
+
+    pub fn with_transition(mut self, transition: SceneTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+/// Remove the active scene from the scene stack, resuming the scene below it
+#[derive(Clone, Debug)]
+pub struct PopScene {
+    pub transition: SceneTransition,
+}
+impl PopScene {
+    pub fn new() -> Self {
+        Self { transition: SceneTransition::Instant }
+    }
+
+    pub fn with_transition(mut self, transition: SceneTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+/// Swap the active scene for a new scene
+#[derive(Clone)]
+pub struct ReplaceScene {
+    pub scene_factory: SceneFactory,
+    pub transition: SceneTransition,
+}
+impl ReplaceScene {
+    pub fn new<T: Scene>() -> Self {
+        Self { scene_factory: scene_factory::<T>(), transition: SceneTransition::Instant }
+    }
+
+    pub fn with_transition(mut self, transition: SceneTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+// Capture events
+/// Save the next frame that gets drawn as a png file
+#[derive(Clone, Debug)]
+pub struct TakeScreenshot {
+    /// When not set, the screenshot gets saved in the working directory with the current time in its name
+    pub path: Option<String>,
+}
+impl TakeScreenshot {
+    pub fn new() -> Self {
+        Self { path: None }
+    }
+
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
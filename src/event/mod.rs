@@ -1,8 +1,19 @@
 pub mod events;
 pub use events::*;
 
+mod event_channel;
+pub use event_channel::DispatchMode;
+pub use event_channel::EventPropagation;
+pub use event_channel::EventStats;
+pub use event_channel::SubscriptionId;
+pub use event_channel::OverflowPolicy;
+pub use event_channel::DEFAULT_EVENT_CAPACITY;
+
 mod event_reader;
 pub use event_reader::EventReader;
 
 mod event_system;
 pub use event_system::EventSystem;
+
+#[cfg(test)]
+mod event_system_test;
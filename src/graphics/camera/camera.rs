@@ -2,7 +2,7 @@ use glam::Vec3;
 
 use crate::graphics::shader::UniformValue;
 
-use super::{projection::Projection, view::View, LookDirectionLimits};
+use super::{projection::Projection, view::View, LookDirectionLimits, ProjectionMode};
 
 pub struct Camera {
     projection: Projection,
@@ -17,6 +17,21 @@ impl Camera {
         }
     }
 
+    /// A camera for 2D games, that shows `height` world units vertically. Zooming scales that height, like it
+    /// would scale the fov of a perspective camera.
+    pub fn new_orthographic(aspect_ratio: f32, height: f32, near_plane: f32, far_plane: f32) -> Self {
+        let mut camera = Self::new(aspect_ratio, 45.0, near_plane, far_plane);
+        camera.projection.set_ortho_height(height);
+        camera.projection.set_mode(ProjectionMode::Orthographic);
+
+        camera
+    }
+
+    /// Advances a transition between projection modes, see `transition_projection_mode`
+    pub fn update(&mut self, delta_time: f32) {
+        self.projection.update(delta_time);
+    }
+
     pub fn projection_for_shader(&self) -> UniformValue {
         return UniformValue::from(self.projection.for_shader())
     }
@@ -75,6 +90,37 @@ impl Camera {
         self.projection.zoom(amount);
     }
 
+    pub fn projection_mode(&self) -> ProjectionMode {
+        self.projection.mode()
+    }
+    pub fn set_projection_mode(&mut self, mode: ProjectionMode) {
+        self.projection.set_mode(mode);
+    }
+    /// Blends smoothly to `mode` over `duration` seconds, which requires calling `update` every update
+    pub fn transition_projection_mode(&mut self, mode: ProjectionMode, duration: f32) {
+        self.projection.transition_to_mode(mode, duration);
+    }
+    pub fn is_transitioning_projection(&self) -> bool {
+        self.projection.is_transitioning()
+    }
+    /// 0 for perspective, 1 for orthographic and in between during a transition
+    pub fn projection_blend(&self) -> f32 {
+        self.projection.blend.value
+    }
+    pub fn ortho_height(&self) -> f32 {
+        self.projection.ortho_height()
+    }
+    pub fn set_ortho_height(&mut self, height: f32) {
+        self.projection.set_ortho_height(height);
+    }
+    /// The distance from the camera at which objects look the same size in both projection modes
+    pub fn set_ortho_distance(&mut self, distance: f32) {
+        self.projection.ortho_distance = distance;
+    }
+    pub fn set_aspect_ratio(&mut self, aspect_ratio: f32) {
+        self.projection.aspect_ratio = aspect_ratio;
+    }
+
     pub fn set_position(&mut self, position: Vec3) {
         self.view.position = position;
     }
@@ -100,6 +146,18 @@ impl Camera {
         self.view.position.z += amount;
     }
 
+    pub fn get_position(&self) -> Vec3 {
+        self.view.position
+    }
+    /// The normalized direction the camera looks in
+    pub fn get_direction(&self) -> Vec3 {
+        self.view.direction
+    }
+    /// The normalized direction to the right of the camera, parallel to the ground
+    pub fn get_right(&self) -> Vec3 {
+        self.view.direction.cross(Vec3::Y).normalize_or_zero()
+    }
+
     pub fn set_look_sensitivity(&mut self, amount: f32) {
         self.view.look_sensetivity = amount;
     }
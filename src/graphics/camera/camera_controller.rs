@@ -0,0 +1,11 @@
+use crate::input::Input;
+
+use super::Camera;
+
+/// Moves a `Camera` based on the input of the player. Call `update` once per update, from `Scene::update`.
+///
+/// Controllers rotate the camera through `Camera::rotate`, so the camera's look sensitivity and
+/// `LookDirectionLimits` apply. Input that was consumed by the interface gets ignored.
+pub trait CameraController {
+    fn update(&mut self, camera: &mut Camera, input: &Input, delta_time: f32);
+}
@@ -0,0 +1,114 @@
+use glam::Vec3;
+
+use crate::{input::{ButtonState, Input, Key, MouseButton}, time};
+
+use super::{Camera, CameraController, FlyController, FollowController, LookDirectionLimits, OrbitController};
+
+fn camera() -> Camera {
+    Camera::new(1.0, 45.0, 0.1, 100.0)
+}
+
+fn assert_close(expected: Vec3, actual: Vec3) {
+    assert!(expected.distance(actual) < 0.001, "expected {:?}, got {:?}", expected, actual);
+}
+
+#[test]
+fn test_fly_controller() {
+    let mut camera = camera();
+    let mut controller = FlyController::new().with_movement_speed(6.0);
+    let mut input = Input::new();
+
+    input.keyboard.register_key_event(Key::W, ButtonState::Down);
+    controller.update(&mut camera, &input, 0.5);
+    assert_close(camera.get_direction() * 3.0, camera.get_position());
+
+    // the keyboard is ignored while the interface has focus
+    input.keyboard.set_consumed_by_ui(true);
+    controller.update(&mut camera, &input, 0.5);
+    assert_close(camera.get_direction() * 3.0, camera.get_position());
+}
+
+#[test]
+fn test_fly_controller_respects_look_direction_limits() {
+    let mut camera = camera();
+    camera.set_rotation_limits(LookDirectionLimits { left: 10.0, right: 10.0, top: 10.0, bottom: 10.0 });
+    let direction = camera.get_direction();
+
+    let mut controller = FlyController::new().with_look_speed(1.0);
+    let mut input = Input::new();
+    input.mouse.register_move_event(1000.0, 0.0);
+    controller.update(&mut camera, &input, time::DELTA);
+
+    let angle = camera.get_direction().angle_between(direction).to_degrees();
+    assert!((angle - 10.0).abs() < 0.01, "turned {} degrees", angle);
+
+    // looking around only happens while holding the look button
+    let mut camera = self::camera();
+    let mut controller = FlyController::new().with_look_button(MouseButton::Right);
+    controller.update(&mut camera, &input, time::DELTA);
+    assert_close(direction, camera.get_direction());
+
+    input.mouse.register_button_event(MouseButton::Right, ButtonState::Down);
+    controller.update(&mut camera, &input, time::DELTA);
+    assert!(camera.get_direction().distance(direction) > 0.001);
+}
+
+#[test]
+fn test_orbit_controller() {
+    let mut camera = camera();
+    let target = Vec3::new(1.0, 2.0, 3.0);
+    let mut controller = OrbitController::new(target, 10.0);
+    let mut input = Input::new();
+
+    controller.update(&mut camera, &input, time::DELTA);
+    assert_close(target - camera.get_direction() * 10.0, camera.get_position());
+
+    // orbiting keeps the distance to the target
+    input.mouse.register_button_event(MouseButton::Left, ButtonState::Down);
+    input.mouse.register_move_event(100.0, 50.0);
+    controller.update(&mut camera, &input, time::DELTA);
+    assert!((10.0 - camera.get_position().distance(target)).abs() < 0.001);
+    assert_close(target, camera.get_position() + camera.get_direction() * 10.0);
+
+    // scrolling up moves closer, within the limits
+    input.reset();
+    input.mouse.register_scroll_y_event(100.0);
+    controller = controller.with_distance_limits(9.0, 20.0);
+    controller.update(&mut camera, &input, time::DELTA);
+    assert_eq!(9.0, controller.distance());
+
+    // panning moves the target sideways
+    input.reset();
+    input.mouse.register_button_event(MouseButton::Right, ButtonState::Down);
+    input.mouse.register_move_event(100.0, 0.0);
+    controller.update(&mut camera, &input, time::DELTA);
+    assert!(controller.target().distance(target) > 0.1);
+    assert!((controller.target() - target).dot(camera.get_direction()).abs() < 0.001);
+}
+
+#[test]
+fn test_follow_controller() {
+    let mut camera = camera();
+    let mut controller = FollowController::new(Vec3::ZERO, 5.0).with_height(2.0).with_damping(5.0);
+    let input = Input::new();
+
+    controller.update(&mut camera, &input, time::DELTA);
+    let offset = camera.get_position();
+    assert_close(-camera.get_direction() * 5.0 + Vec3::Y * 2.0, offset);
+
+    // the camera trails behind the target and catches up over time
+    controller.set_target(Vec3::new(10.0, 0.0, 0.0));
+    controller.update(&mut camera, &input, time::DELTA);
+    let trailing = camera.get_position() - offset;
+    assert!(trailing.x > 0.0 && trailing.x < 10.0);
+
+    for _ in 0..120 {
+        controller.update(&mut camera, &input, time::DELTA);
+    }
+    assert_close(Vec3::new(10.0, 0.0, 0.0) + offset, camera.get_position());
+
+    controller.set_target(Vec3::new(-10.0, 0.0, 0.0));
+    controller.snap_to_target();
+    controller.update(&mut camera, &input, time::DELTA);
+    assert_close(Vec3::new(-10.0, 0.0, 0.0) + offset, camera.get_position());
+}
@@ -0,0 +1,102 @@
+use crate::input::{Input, Key, MouseButton};
+
+use super::{Camera, CameraController};
+
+/// Free-flying camera: the keys move along the look direction and the mouse looks around
+pub struct FlyController {
+    /// Units per second
+    pub movement_speed: f32,
+    /// Degrees per pixel of mouse movement, before the look sensitivity of the camera gets applied
+    pub look_speed: f32,
+    /// Fov degrees per pixel of scroll
+    pub zoom_speed: f32,
+    /// Only look around while this button is held, or always when `None`
+    pub look_button: Option<MouseButton>,
+    pub forward_key: Key,
+    pub back_key: Key,
+    pub left_key: Key,
+    pub right_key: Key,
+    pub up_key: Key,
+    pub down_key: Key,
+}
+
+impl FlyController {
+    pub fn new() -> Self {
+        Self {
+            movement_speed: 10.0,
+            look_speed: 0.02,
+            zoom_speed: 0.1,
+            look_button: None,
+            forward_key: Key::W,
+            back_key: Key::S,
+            left_key: Key::A,
+            right_key: Key::D,
+            up_key: Key::Shift,
+            down_key: Key::Cntrl,
+        }
+    }
+
+    pub fn with_movement_speed(mut self, movement_speed: f32) -> Self {
+        self.movement_speed = movement_speed;
+        self
+    }
+
+    pub fn with_look_speed(mut self, look_speed: f32) -> Self {
+        self.look_speed = look_speed;
+        self
+    }
+
+    pub fn with_zoom_speed(mut self, zoom_speed: f32) -> Self {
+        self.zoom_speed = zoom_speed;
+        self
+    }
+
+    pub fn with_look_button(mut self, look_button: MouseButton) -> Self {
+        self.look_button = Some(look_button);
+        self
+    }
+
+    /// Keys for moving forward, back, left, right, up and down
+    pub fn with_keys(mut self, forward: Key, back: Key, left: Key, right: Key, up: Key, down: Key) -> Self {
+        self.forward_key = forward;
+        self.back_key = back;
+        self.left_key = left;
+        self.right_key = right;
+        self.up_key = up;
+        self.down_key = down;
+        self
+    }
+
+    fn axis(input: &Input, negative: Key, positive: Key) -> f32 {
+        let mut value = 0.0;
+        if input.keyboard.is_key_held(negative) { value -= 1.0; }
+        if input.keyboard.is_key_held(positive) { value += 1.0; }
+        value
+    }
+}
+
+impl CameraController for FlyController {
+    fn update(&mut self, camera: &mut Camera, input: &Input, delta_time: f32) {
+        if !input.keyboard.is_consumed_by_ui() {
+            let distance = self.movement_speed * delta_time;
+
+            camera.move_forth(Self::axis(input, self.back_key, self.forward_key) * distance);
+            camera.move_horizontal(Self::axis(input, self.left_key, self.right_key) * distance);
+            camera.move_up(Self::axis(input, self.down_key, self.up_key) * distance);
+        }
+
+        if input.mouse.is_consumed_by_ui() {
+            return;
+        }
+
+        let is_looking = self.look_button.is_none_or(|button| input.mouse.is_button_held(button));
+        if is_looking && input.mouse.did_move() {
+            let moved = input.mouse.get_moved() * self.look_speed;
+            camera.rotate(moved.x, moved.y);
+        }
+
+        if input.mouse.get_scroll_y() != 0.0 {
+            camera.zoom(input.mouse.get_scroll_y() as f32 * self.zoom_speed);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use glam::Vec3;
+
+use crate::input::{Input, MouseButton};
+
+use super::{Camera, CameraController};
+
+/// Third-person camera that stays behind a target, lagging behind its movement for a smoother view. Update
+/// the target with `set_target` before every update, for example with the position of the player.
+pub struct FollowController {
+    target: Vec3,
+    /// The position the camera is centered on, which trails `target`
+    smoothed_target: Option<Vec3>,
+    distance: f32,
+    /// How far above the target the camera floats, in units
+    pub height: f32,
+    /// How quickly the camera catches up with the target. Higher is tighter, at 5 it covers about 95% of the
+    /// distance in 0.6 seconds. At 0 or less the camera sticks to the target.
+    pub damping: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Degrees per pixel of mouse movement, before the look sensitivity of the camera gets applied
+    pub look_speed: f32,
+    /// Fraction of the distance to the target per pixel of scroll
+    pub zoom_speed: f32,
+    /// Only look around while this button is held, or always when `None`
+    pub look_button: Option<MouseButton>,
+}
+
+impl FollowController {
+    pub fn new(target: Vec3, distance: f32) -> Self {
+        Self {
+            target,
+            smoothed_target: None,
+            distance,
+            height: 1.0,
+            damping: 5.0,
+            min_distance: 1.0,
+            max_distance: 50.0,
+            look_speed: 0.02,
+            zoom_speed: 0.002,
+            look_button: None,
+        }
+    }
+
+    pub fn with_height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    pub fn with_distance_limits(mut self, min_distance: f32, max_distance: f32) -> Self {
+        self.min_distance = min_distance;
+        self.max_distance = max_distance.max(min_distance);
+        self
+    }
+
+    pub fn with_look_speed(mut self, look_speed: f32) -> Self {
+        self.look_speed = look_speed;
+        self
+    }
+
+    pub fn with_zoom_speed(mut self, zoom_speed: f32) -> Self {
+        self.zoom_speed = zoom_speed;
+        self
+    }
+
+    pub fn with_look_button(mut self, look_button: MouseButton) -> Self {
+        self.look_button = Some(look_button);
+        self
+    }
+
+    pub fn target(&self) -> Vec3 {
+        self.target
+    }
+
+    pub fn set_target(&mut self, target: Vec3) {
+        self.target = target;
+    }
+
+    /// Moves the camera to the target right away, for example after the target teleported
+    pub fn snap_to_target(&mut self) {
+        self.smoothed_target = Some(self.target);
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn set_distance(&mut self, distance: f32) {
+        self.distance = distance.clamp(self.min_distance, self.max_distance);
+    }
+}
+
+impl CameraController for FollowController {
+    fn update(&mut self, camera: &mut Camera, input: &Input, delta_time: f32) {
+        if !input.mouse.is_consumed_by_ui() {
+            let is_looking = self.look_button.is_none_or(|button| input.mouse.is_button_held(button));
+            if is_looking && input.mouse.did_move() {
+                let moved = input.mouse.get_moved() * self.look_speed;
+                camera.rotate(moved.x, moved.y);
+            }
+
+            let scroll_y = input.mouse.get_scroll_y() as f32;
+            if scroll_y != 0.0 {
+                self.set_distance(self.distance * (1.0 - scroll_y * self.zoom_speed).max(0.1));
+            }
+        }
+
+        let smoothed_target = match self.smoothed_target {
+            Some(smoothed_target) if self.damping > 0.0 => {
+                smoothed_target + (self.target - smoothed_target) * (1.0 - (-self.damping * delta_time).exp())
+            },
+            _ => self.target,
+        };
+        self.smoothed_target = Some(smoothed_target);
+
+        camera.set_position(smoothed_target - camera.get_direction() * self.distance + Vec3::Y * self.height);
+    }
+}
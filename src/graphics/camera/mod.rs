@@ -1,7 +1,22 @@
 mod camera;
 mod projection;
 mod view;
+mod camera_controller;
+mod fly_controller;
+mod orbit_controller;
+mod follow_controller;
 
 pub use camera::Camera;
 pub use view::LookDirectionLimits;
 pub use projection::ZoomLimits;
+pub use projection::ProjectionMode;
+pub use projection::DEFAULT_ORTHO_DISTANCE;
+pub use camera_controller::CameraController;
+pub use fly_controller::FlyController;
+pub use orbit_controller::OrbitController;
+pub use follow_controller::FollowController;
+
+#[cfg(test)]
+mod projection_test;
+#[cfg(test)]
+mod camera_controller_test;
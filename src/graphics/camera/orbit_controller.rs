@@ -0,0 +1,107 @@
+use glam::Vec3;
+
+use crate::input::{Input, MouseButton};
+
+use super::{Camera, CameraController};
+
+/// Orbits the camera around a target, like the viewport of a 3D editor. Dragging with `orbit_button` rotates
+/// around the target, dragging with `pan_button` moves the target and scrolling changes the distance to it.
+pub struct OrbitController {
+    target: Vec3,
+    distance: f32,
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Degrees per pixel of mouse movement, before the look sensitivity of the camera gets applied
+    pub orbit_speed: f32,
+    /// Fraction of the distance to the target per pixel of mouse movement
+    pub pan_speed: f32,
+    /// Fraction of the distance to the target per pixel of scroll
+    pub zoom_speed: f32,
+    pub orbit_button: MouseButton,
+    pub pan_button: MouseButton,
+}
+
+impl OrbitController {
+    pub fn new(target: Vec3, distance: f32) -> Self {
+        Self {
+            target,
+            distance,
+            min_distance: 0.5,
+            max_distance: 500.0,
+            orbit_speed: 0.1,
+            pan_speed: 0.002,
+            zoom_speed: 0.002,
+            orbit_button: MouseButton::Left,
+            pan_button: MouseButton::Right,
+        }
+    }
+
+    pub fn with_distance_limits(mut self, min_distance: f32, max_distance: f32) -> Self {
+        self.min_distance = min_distance;
+        self.max_distance = max_distance.max(min_distance);
+        self
+    }
+
+    pub fn with_orbit_speed(mut self, orbit_speed: f32) -> Self {
+        self.orbit_speed = orbit_speed;
+        self
+    }
+
+    pub fn with_pan_speed(mut self, pan_speed: f32) -> Self {
+        self.pan_speed = pan_speed;
+        self
+    }
+
+    pub fn with_zoom_speed(mut self, zoom_speed: f32) -> Self {
+        self.zoom_speed = zoom_speed;
+        self
+    }
+
+    pub fn with_buttons(mut self, orbit_button: MouseButton, pan_button: MouseButton) -> Self {
+        self.orbit_button = orbit_button;
+        self.pan_button = pan_button;
+        self
+    }
+
+    pub fn target(&self) -> Vec3 {
+        self.target
+    }
+
+    pub fn set_target(&mut self, target: Vec3) {
+        self.target = target;
+    }
+
+    pub fn distance(&self) -> f32 {
+        self.distance
+    }
+
+    pub fn set_distance(&mut self, distance: f32) {
+        self.distance = distance.clamp(self.min_distance, self.max_distance);
+    }
+}
+
+impl CameraController for OrbitController {
+    fn update(&mut self, camera: &mut Camera, input: &Input, _delta_time: f32) {
+        if !input.mouse.is_consumed_by_ui() {
+            let moved = input.mouse.get_moved();
+
+            if input.mouse.is_button_held(self.orbit_button) && input.mouse.did_move() {
+                camera.rotate(moved.x * self.orbit_speed, moved.y * self.orbit_speed);
+            }
+
+            if input.mouse.is_button_held(self.pan_button) && input.mouse.did_move() {
+                let right = camera.get_right();
+                let up = right.cross(camera.get_direction());
+                self.target += (up * moved.y - right * moved.x) * self.pan_speed * self.distance;
+            }
+
+            let scroll_y = input.mouse.get_scroll_y() as f32;
+            if scroll_y != 0.0 {
+                self.set_distance(self.distance * (1.0 - scroll_y * self.zoom_speed).max(0.1));
+            }
+        }
+
+        // The camera only rotates through `Camera::rotate`, so its direction already respects the limits
+        camera.set_position(self.target - camera.get_direction() * self.distance);
+    }
+}
@@ -1,10 +1,33 @@
 use glam::Mat4;
 
+/// Distance from the camera at which objects look the same size in both projection modes, unless set otherwise
+pub const DEFAULT_ORTHO_DISTANCE: f32 = 10.0;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ProjectionMode {
+    /// Objects further away look smaller, like in the real world
+    Perspective,
+    /// Objects keep their size regardless of their distance, for 2D games, isometric views and top-down views
+    Orthographic,
+}
+
 pub struct Projection {
     pub aspect_ratio: f32,
     pub near_plane: f32,
     pub far_plane: f32,
     pub fov: Fov,
+    /// The orthographic view covers what the perspective view shows at this distance, which keeps zooming
+    /// working the same in both modes
+    pub ortho_distance: f32,
+    pub blend: ProjectionBlend,
+}
+
+/// How far the projection is between perspective (0) and orthographic (1)
+pub struct ProjectionBlend {
+    pub value: f32,
+    pub target: f32,
+    /// Change of `value` per second
+    pub speed: f32,
 }
 
 pub struct ZoomLimits {
@@ -42,18 +65,93 @@ impl Projection {
                     max_zoom_out: fov * 1.5,
                 }
             },
+            ortho_distance: DEFAULT_ORTHO_DISTANCE,
+            blend: ProjectionBlend { value: 0.0, target: 0.0, speed: 0.0 },
         }
     }
 
     pub fn for_shader(&self) -> Mat4 {
+        match self.blend.value {
+            value if value <= 0.0 => self.perspective(),
+            value if value >= 1.0 => self.orthographic(),
+            // Interpolating the matrices moves the vanishing point away smoothly, where interpolating the
+            // fov would have to pass through a fov of 0
+            value => self.perspective() * (1.0 - value) + self.orthographic() * value,
+        }
+    }
+
+    fn perspective(&self) -> Mat4 {
         Mat4::perspective_rh_gl(
-            (self.fov.base + self.fov.zoom).to_radians(), 
+            self.current_fov().to_radians(), 
             self.aspect_ratio, 
             self.near_plane, 
             self.far_plane
         )
     }
 
+    fn orthographic(&self) -> Mat4 {
+        let half_height = self.ortho_height() / 2.0;
+        let half_width = half_height * self.aspect_ratio;
+
+        Mat4::orthographic_rh_gl(-half_width, half_width, -half_height, half_height, self.near_plane, self.far_plane)
+    }
+
+    fn current_fov(&self) -> f32 {
+        self.fov.base + self.fov.zoom
+    }
+
+    /// The height in world units that the orthographic view covers, which follows the zoom
+    pub fn ortho_height(&self) -> f32 {
+        2.0 * self.ortho_distance * (self.current_fov().to_radians() / 2.0).tan()
+    }
+
+    /// Sets `ortho_distance` so that, without zoom, the orthographic view covers `height` world units
+    pub fn set_ortho_height(&mut self, height: f32) {
+        self.ortho_distance = height / (2.0 * (self.fov.base.to_radians() / 2.0).tan());
+    }
+
+    /// The mode the projection is in, or is transitioning to
+    pub fn mode(&self) -> ProjectionMode {
+        if self.blend.target >= 1.0 { ProjectionMode::Orthographic } else { ProjectionMode::Perspective }
+    }
+
+    pub fn set_mode(&mut self, mode: ProjectionMode) {
+        let value = Self::blend_value(mode);
+        self.blend = ProjectionBlend { value, target: value, speed: 0.0 };
+    }
+
+    /// Blends to `mode` over `duration` seconds, as `update` gets called
+    pub fn transition_to_mode(&mut self, mode: ProjectionMode, duration: f32) {
+        if duration <= 0.0 {
+            self.set_mode(mode);
+            return;
+        }
+
+        self.blend.target = Self::blend_value(mode);
+        self.blend.speed = 1.0 / duration;
+    }
+
+    pub fn is_transitioning(&self) -> bool {
+        self.blend.value != self.blend.target
+    }
+
+    pub fn update(&mut self, delta_time: f32) {
+        let step = self.blend.speed * delta_time;
+
+        if (self.blend.target - self.blend.value).abs() <= step {
+            self.blend.value = self.blend.target;
+        } else {
+            self.blend.value += step.copysign(self.blend.target - self.blend.value);
+        }
+    }
+
+    fn blend_value(mode: ProjectionMode) -> f32 {
+        match mode {
+            ProjectionMode::Perspective => 0.0,
+            ProjectionMode::Orthographic => 1.0,
+        }
+    }
+
     pub fn zoom(&mut self, amount: f32) {
         self.fov.zoom -= amount;
         self.fov.confine_zoom_limits();
@@ -0,0 +1,63 @@
+use glam::{Mat4, Vec3, Vec4};
+
+use super::{projection::Projection, ProjectionMode};
+
+fn project(matrix: Mat4, point: Vec3) -> Vec3 {
+    let clip = matrix * Vec4::new(point.x, point.y, point.z, 1.0);
+    clip.truncate() / clip.w
+}
+
+#[test]
+fn test_orthographic_size_matches_perspective_at_ortho_distance() {
+    let mut projection = Projection::new(1.0, 90.0, 0.1, 100.0);
+    projection.ortho_distance = 10.0;
+    let point = Vec3::new(5.0, 5.0, -10.0);
+
+    let perspective = project(projection.for_shader(), point);
+    projection.set_mode(ProjectionMode::Orthographic);
+    let orthographic = project(projection.for_shader(), point);
+
+    assert!((20.0 - projection.ortho_height()).abs() < 0.001);
+    assert!((perspective.x - orthographic.x).abs() < 0.001);
+    assert!((perspective.y - orthographic.y).abs() < 0.001);
+
+    // further away objects keep their size
+    let far_point = project(projection.for_shader(), Vec3::new(5.0, 5.0, -50.0));
+    assert!((orthographic.x - far_point.x).abs() < 0.001);
+}
+
+#[test]
+fn test_zoom_scales_ortho_height() {
+    let mut projection = Projection::new(1.0, 45.0, 0.1, 100.0);
+    projection.set_ortho_height(20.0);
+    assert!((20.0 - projection.ortho_height()).abs() < 0.001);
+
+    projection.zoom(10.0);
+    assert!(projection.ortho_height() < 20.0);
+
+    projection.zoom(-20.0);
+    assert!(projection.ortho_height() > 20.0);
+}
+
+#[test]
+fn test_transition() {
+    let mut projection = Projection::new(1.0, 45.0, 0.1, 100.0);
+    assert_eq!(ProjectionMode::Perspective, projection.mode());
+
+    projection.transition_to_mode(ProjectionMode::Orthographic, 1.0);
+    assert_eq!(ProjectionMode::Orthographic, projection.mode());
+    assert!(projection.is_transitioning());
+
+    projection.update(0.5);
+    assert_eq!(0.5, projection.blend.value);
+
+    projection.update(0.6);
+    assert_eq!(1.0, projection.blend.value);
+    assert!(!projection.is_transitioning());
+
+    // halfway back
+    projection.transition_to_mode(ProjectionMode::Perspective, 2.0);
+    projection.update(1.0);
+    assert_eq!(0.5, projection.blend.value);
+    assert_eq!(ProjectionMode::Perspective, projection.mode());
+}
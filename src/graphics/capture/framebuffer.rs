@@ -0,0 +1,96 @@
+use gl::types::GLuint;
+use image::RgbaImage;
+
+use crate::error::{opengl, CaptureError, LazuliError};
+
+use super::read_pixels;
+
+/// An OpenGL framebuffer with a color and depth attachment, to draw into without showing the result on screen
+pub struct Framebuffer {
+    id: GLuint,
+    color_texture_id: GLuint,
+    depth_renderbuffer_id: GLuint,
+    width: u32,
+    height: u32,
+}
+
+impl Framebuffer {
+    pub fn new(width: u32, height: u32) -> Result<Self, LazuliError> {
+        let mut framebuffer = Self {
+            id: 0,
+            color_texture_id: 0,
+            depth_renderbuffer_id: 0,
+            width,
+            height,
+        };
+
+        unsafe {
+            gl::GenFramebuffers(1, &mut framebuffer.id);
+            gl::BindFramebuffer(gl::FRAMEBUFFER, framebuffer.id);
+
+            gl::GenTextures(1, &mut framebuffer.color_texture_id);
+            gl::BindTexture(gl::TEXTURE_2D, framebuffer.color_texture_id);
+            gl::TexImage2D(
+                gl::TEXTURE_2D, 0, gl::RGBA8 as i32, width as i32, height as i32, 0,
+                gl::RGBA, gl::UNSIGNED_BYTE, std::ptr::null(),
+            );
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, gl::LINEAR as i32);
+            gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, gl::LINEAR as i32);
+            gl::FramebufferTexture2D(gl::FRAMEBUFFER, gl::COLOR_ATTACHMENT0, gl::TEXTURE_2D, framebuffer.color_texture_id, 0);
+
+            gl::GenRenderbuffers(1, &mut framebuffer.depth_renderbuffer_id);
+            gl::BindRenderbuffer(gl::RENDERBUFFER, framebuffer.depth_renderbuffer_id);
+            gl::RenderbufferStorage(gl::RENDERBUFFER, gl::DEPTH24_STENCIL8, width as i32, height as i32);
+            gl::FramebufferRenderbuffer(gl::FRAMEBUFFER, gl::DEPTH_STENCIL_ATTACHMENT, gl::RENDERBUFFER, framebuffer.depth_renderbuffer_id);
+
+            let status = gl::CheckFramebufferStatus(gl::FRAMEBUFFER);
+
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            opengl::gl_check_errors();
+
+            if status != gl::FRAMEBUFFER_COMPLETE {
+                return Err(CaptureError::IncompleteFramebuffer(status).into());
+            }
+        }
+
+        Ok(framebuffer)
+    }
+
+    /// Draw into this framebuffer instead of the screen, until `unbind` gets called
+    pub fn bind(&self) {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, self.id);
+            gl::Viewport(0, 0, self.width as i32, self.height as i32);
+            opengl::gl_check_errors();
+        }
+    }
+
+    pub fn unbind(&self) {
+        unsafe {
+            gl::BindFramebuffer(gl::FRAMEBUFFER, 0);
+            opengl::gl_check_errors();
+        }
+    }
+
+    /// Read back everything that has been drawn into this framebuffer
+    pub fn read_pixels(&self) -> RgbaImage {
+        self.bind();
+        let image = read_pixels(self.width, self.height);
+        self.unbind();
+
+        image
+    }
+
+    pub fn width(&self) -> u32 { self.width }
+    pub fn height(&self) -> u32 { self.height }
+}
+
+impl Drop for Framebuffer {
+    fn drop(&mut self) {
+        unsafe {
+            gl::DeleteRenderbuffers(1, &self.depth_renderbuffer_id);
+            gl::DeleteTextures(1, &self.color_texture_id);
+            gl::DeleteFramebuffers(1, &self.id);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::{fs, path::Path};
+
+use image::RgbaImage;
+
+use crate::{error::{AssetError, CaptureError, LazuliError}, log};
+
+use super::{compare_images, difference_image, save_png};
+
+/// Name of the environment variable that, when set to a non-empty value, makes every `GoldenImage::check`
+/// overwrite its reference image with the captured image instead of comparing them
+pub const UPDATE_GOLDEN_ENV_VAR: &str = "LAZULI_UPDATE_GOLDEN";
+
+/// A reference image on disk that captured frames get compared with, to catch rendering regressions
+pub struct GoldenImage {
+    path: String,
+    channel_tolerance: u8,
+    max_differing_fraction: f64,
+}
+
+impl GoldenImage {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            channel_tolerance: 2,
+            max_differing_fraction: 0.0,
+        }
+    }
+
+    /// Maximum difference of a single color channel for a pixel to still count as the same
+    pub fn with_channel_tolerance(mut self, channel_tolerance: u8) -> Self {
+        self.channel_tolerance = channel_tolerance;
+        self
+    }
+
+    /// Fraction of pixels, from 0.0 to 1.0, that may differ before the check fails
+    pub fn with_max_differing_fraction(mut self, max_differing_fraction: f64) -> Self {
+        self.max_differing_fraction = max_differing_fraction;
+        self
+    }
+
+    /// Compare `actual` with the reference image. When the images do not match, `actual` and an image of the
+    /// differences get saved next to the reference as `<name>.actual.png` and `<name>.diff.png`. A missing
+    /// reference image is an error, so a test can not pass by accident on a fresh checkout. When
+    /// `LAZULI_UPDATE_GOLDEN` is set, `actual` gets saved as the new reference instead.
+    pub fn check(&self, actual: &RgbaImage) -> Result<(), LazuliError> {
+        if std::env::var(UPDATE_GOLDEN_ENV_VAR).is_ok_and(|value| !value.is_empty()) {
+            return self.update(actual);
+        }
+
+        if !Path::new(&self.path).exists() {
+            return Err(CaptureError::GoldenMissing { path: self.path.clone() }.into());
+        }
+
+        let expected = image::open(&self.path)
+            .map_err(|err| AssetError::Image { path: self.path.clone(), source: err })?
+            .to_rgba8();
+
+        if expected.dimensions() != actual.dimensions() {
+            save_png(actual, self.sibling_path("actual"))?;
+
+            return Err(CaptureError::GoldenSizeMismatch {
+                path: self.path.clone(),
+                expected: expected.dimensions(),
+                actual: actual.dimensions(),
+            }.into());
+        }
+
+        let difference = compare_images(actual, &expected, self.channel_tolerance);
+
+        if difference.differing_fraction() > self.max_differing_fraction {
+            save_png(actual, self.sibling_path("actual"))?;
+            save_png(&difference_image(actual, &expected), self.sibling_path("diff"))?;
+
+            return Err(CaptureError::GoldenMismatch {
+                path: self.path.clone(),
+                differing_pixels: difference.differing_pixels,
+                max_channel_difference: difference.max_channel_difference,
+            }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Save `actual` as the reference image
+    pub fn update(&self, actual: &RgbaImage) -> Result<(), LazuliError> {
+        log::engine_info(format!("saving reference image {}", self.path));
+
+        if let Some(directory) = Path::new(&self.path).parent() {
+            fs::create_dir_all(directory).map_err(|err| AssetError::Io { path: self.path.clone(), source: err })?;
+        }
+
+        save_png(actual, &self.path)
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    fn sibling_path(&self, suffix: &str) -> String {
+        let stem = self.path.strip_suffix(".png").unwrap_or(&self.path);
+        format!("{}.{}.png", stem, suffix)
+    }
+}
@@ -0,0 +1,75 @@
+use std::{fs, path::PathBuf};
+
+use image::{Rgba, RgbaImage};
+
+use crate::error::{CaptureError, LazuliError};
+
+use super::GoldenImage;
+
+fn golden_path(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("lazuli_golden_{}_{}", std::process::id(), name));
+    _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    directory.join("reference.png")
+}
+
+#[test]
+fn test_missing_reference_fails() {
+    let path = golden_path("missing");
+    let image = RgbaImage::from_pixel(3, 3, Rgba([1, 2, 3, 255]));
+
+    let result = GoldenImage::new(path.to_string_lossy()).check(&image);
+    assert!(matches!(result, Err(LazuliError::Capture(CaptureError::GoldenMissing { .. }))));
+    assert!(!path.exists());
+
+    GoldenImage::new(path.to_string_lossy()).update(&image).unwrap();
+
+    assert!(path.exists());
+    GoldenImage::new(path.to_string_lossy()).check(&image).unwrap();
+
+    _ = fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn test_mismatch_saves_actual_and_diff() {
+    let path = golden_path("mismatch");
+    let expected = RgbaImage::from_pixel(3, 3, Rgba([0, 0, 0, 255]));
+    expected.save(&path).unwrap();
+
+    let mut actual = expected.clone();
+    actual.put_pixel(1, 1, Rgba([255, 255, 255, 255]));
+
+    let result = GoldenImage::new(path.to_string_lossy()).check(&actual);
+
+    assert!(matches!(result, Err(LazuliError::Capture(CaptureError::GoldenMismatch { differing_pixels: 1, max_channel_difference: 255, .. }))));
+    assert!(path.with_file_name("reference.actual.png").exists());
+    assert!(path.with_file_name("reference.diff.png").exists());
+
+    _ = fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn test_mismatch_within_allowed_fraction_passes() {
+    let path = golden_path("fraction");
+    let expected = RgbaImage::from_pixel(10, 10, Rgba([0, 0, 0, 255]));
+    expected.save(&path).unwrap();
+
+    let mut actual = expected.clone();
+    actual.put_pixel(1, 1, Rgba([255, 255, 255, 255]));
+
+    GoldenImage::new(path.to_string_lossy()).with_max_differing_fraction(0.01).check(&actual).unwrap();
+
+    _ = fs::remove_dir_all(path.parent().unwrap());
+}
+
+#[test]
+fn test_size_mismatch() {
+    let path = golden_path("size");
+    RgbaImage::from_pixel(3, 3, Rgba([0, 0, 0, 255])).save(&path).unwrap();
+
+    let result = GoldenImage::new(path.to_string_lossy()).check(&RgbaImage::from_pixel(4, 3, Rgba([0, 0, 0, 255])));
+
+    assert!(matches!(result, Err(LazuliError::Capture(CaptureError::GoldenSizeMismatch { expected: (3, 3), actual: (4, 3), .. }))));
+
+    _ = fs::remove_dir_all(path.parent().unwrap());
+}
@@ -0,0 +1,69 @@
+use image::{Rgba, RgbaImage};
+
+/// The result of comparing two images of the same size pixel by pixel
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImageDifference {
+    /// Number of pixels of which at least one channel differs by more than the tolerance
+    pub differing_pixels: u64,
+    /// Largest difference of a single channel over all pixels
+    pub max_channel_difference: u8,
+    pub total_pixels: u64,
+}
+
+impl ImageDifference {
+    pub fn is_match(&self) -> bool {
+        self.differing_pixels == 0
+    }
+
+    /// Fraction of the pixels that differ, from 0.0 to 1.0
+    pub fn differing_fraction(&self) -> f64 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+
+        self.differing_pixels as f64 / self.total_pixels as f64
+    }
+}
+
+/// Compare two images of the same size. A pixel only counts as different when one of its channels differs by
+/// more than `channel_tolerance`, because software and hardware renderers round colors slightly differently.
+///
+/// Panics if the images do not have the same size.
+pub fn compare_images(actual: &RgbaImage, expected: &RgbaImage, channel_tolerance: u8) -> ImageDifference {
+    assert_eq!(actual.dimensions(), expected.dimensions(), "only images of the same size can be compared");
+
+    let mut difference = ImageDifference {
+        total_pixels: actual.width() as u64 * actual.height() as u64,
+        ..Default::default()
+    };
+
+    for (actual_pixel, expected_pixel) in actual.pixels().zip(expected.pixels()) {
+        let pixel_difference = max_channel_difference(actual_pixel, expected_pixel);
+
+        difference.max_channel_difference = difference.max_channel_difference.max(pixel_difference);
+
+        if pixel_difference > channel_tolerance {
+            difference.differing_pixels += 1;
+        }
+    }
+
+    difference
+}
+
+/// An image that shows the differences between two images of the same size. Pixels that are the same are
+/// black, pixels that differ are red, brighter the more they differ.
+pub fn difference_image(actual: &RgbaImage, expected: &RgbaImage) -> RgbaImage {
+    assert_eq!(actual.dimensions(), expected.dimensions(), "only images of the same size can be compared");
+
+    RgbaImage::from_fn(actual.width(), actual.height(), |x, y| {
+        let difference = max_channel_difference(actual.get_pixel(x, y), expected.get_pixel(x, y));
+        Rgba([difference, 0, 0, 255])
+    })
+}
+
+fn max_channel_difference(a: &Rgba<u8>, b: &Rgba<u8>) -> u8 {
+    a.0.iter().zip(b.0.iter())
+        .map(|(a, b)| a.abs_diff(*b))
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,40 @@
+use image::{Rgba, RgbaImage};
+
+use super::{compare_images, difference_image};
+
+#[test]
+fn test_identical_images_match() {
+    let image = RgbaImage::from_pixel(4, 3, Rgba([10, 20, 30, 255]));
+
+    let difference = compare_images(&image, &image.clone(), 0);
+
+    assert!(difference.is_match());
+    assert_eq!(0, difference.max_channel_difference);
+    assert_eq!(12, difference.total_pixels);
+}
+
+#[test]
+fn test_differences_within_tolerance_match() {
+    let expected = RgbaImage::from_pixel(2, 2, Rgba([100, 100, 100, 255]));
+    let mut actual = expected.clone();
+    actual.put_pixel(1, 1, Rgba([102, 99, 100, 255]));
+
+    assert!(compare_images(&actual, &expected, 2).is_match());
+
+    let difference = compare_images(&actual, &expected, 1);
+    assert_eq!(1, difference.differing_pixels);
+    assert_eq!(2, difference.max_channel_difference);
+    assert_eq!(0.25, difference.differing_fraction());
+}
+
+#[test]
+fn test_difference_image_marks_differing_pixels() {
+    let expected = RgbaImage::from_pixel(2, 1, Rgba([0, 0, 0, 255]));
+    let mut actual = expected.clone();
+    actual.put_pixel(0, 0, Rgba([0, 0, 200, 255]));
+
+    let difference = difference_image(&actual, &expected);
+
+    assert_eq!(Rgba([200, 0, 0, 255]), *difference.get_pixel(0, 0));
+    assert_eq!(Rgba([0, 0, 0, 255]), *difference.get_pixel(1, 0));
+}
@@ -0,0 +1,21 @@
+mod framebuffer;
+pub use framebuffer::Framebuffer;
+
+mod screenshot;
+pub use screenshot::read_pixels;
+pub use screenshot::save_png;
+pub use screenshot::default_screenshot_path;
+
+mod image_comparison;
+pub use image_comparison::compare_images;
+pub use image_comparison::difference_image;
+pub use image_comparison::ImageDifference;
+
+mod golden_image;
+pub use golden_image::GoldenImage;
+pub use golden_image::UPDATE_GOLDEN_ENV_VAR;
+
+#[cfg(test)]
+mod image_comparison_test;
+#[cfg(test)]
+mod golden_image_test;
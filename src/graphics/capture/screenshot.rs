@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use image::RgbaImage;
+
+use crate::error::{opengl, AssetError, LazuliError};
+
+/// Read the pixels of the framebuffer that is currently bound, starting at the bottom left corner. OpenGL
+/// stores rows from bottom to top, so the rows get flipped to get an image that is the right way up.
+pub fn read_pixels(width: u32, height: u32) -> RgbaImage {
+    let mut pixels = vec![0u8; width as usize * height as usize * 4];
+
+    unsafe {
+        gl::PixelStorei(gl::PACK_ALIGNMENT, 1);
+        gl::ReadPixels(0, 0, width as i32, height as i32, gl::RGBA, gl::UNSIGNED_BYTE, pixels.as_mut_ptr() as *mut _);
+        opengl::gl_check_errors();
+    }
+
+    let image = RgbaImage::from_raw(width, height, pixels).expect("pixel buffer has the size of the image");
+    image::imageops::flip_vertical(&image)
+}
+
+pub fn save_png(image: &RgbaImage, path: impl AsRef<Path>) -> Result<(), LazuliError> {
+    image.save_with_format(path.as_ref(), image::ImageFormat::Png).map_err(|err| {
+        AssetError::Image { path: path.as_ref().to_string_lossy().to_string(), source: err }.into()
+    })
+}
+
+/// A file name in the working directory that contains the current time, like `screenshot_20240131_154502.png`
+pub fn default_screenshot_path() -> String {
+    format!("screenshot_{}.png", chrono::Local::now().format("%Y%m%d_%H%M%S%.3f"))
+}
@@ -1,21 +1,22 @@
 use std::collections::HashMap;
 
+use crate::error::LazuliError;
 use crate::graphics::{shader::ShaderBuilder, texture::ImageType};
 
 use super::{BitmapCharacter, bitmap_cache::BitmapCache};
 
 pub trait Bitmap {
     fn image(&self) -> &ImageType;
-    fn save(&self, path: &String) -> Result<(), String>;
+    fn save(&self, path: &String) -> Result<(), LazuliError>;
     fn characters(&self) -> &HashMap<char, BitmapCharacter>;
     fn line_height(&self) -> f32;
     fn spread(&self) -> u8;
-    fn to_json_cache(&self) -> Result<String, String>;
+    fn to_json_cache(&self) -> Result<String, LazuliError>;
 }
 
 pub trait BitmapBuilder {
-    fn build(&self, font: &rusttype::Font<'static>) -> Result<Box<dyn Bitmap>, String>;
-    fn get_hash(&self) -> Result<String, String>;
+    fn build(&self, font: &rusttype::Font<'static>) -> Result<Box<dyn Bitmap>, LazuliError>;
+    fn get_hash(&self) -> Result<String, LazuliError>;
     fn cache_from_json(&self, data: String) -> Option<Box<dyn BitmapCache>>;
     fn do_cache(&self) -> bool;
     fn default_shader_builder(&self) -> Box<dyn ShaderBuilder>;
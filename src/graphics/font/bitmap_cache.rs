@@ -3,7 +3,7 @@ use std::{fs, collections::{hash_map::DefaultHasher, HashMap}, hash::{Hash, Hash
 use image::GrayImage;
 use serde::{Serialize, Deserialize};
 
-use crate::{graphics::texture::ImageType, log};
+use crate::{error::{AssetError, LazuliError}, graphics::texture::ImageType, log};
 
 use super::{SdfBitmapBuilder, BitmapCharacter, sdf_bitmap::SdfBitmap, Bitmap, plain_bitmap::PlainBitmap, bitmap::BitmapBuilder};
 
@@ -94,7 +94,7 @@ pub fn load(font_path: &String, bitmap_builder: &dyn BitmapBuilder) -> Option<Bo
     return Some(bitmap_cache.build(texture));
 }
 
-pub fn save(font_path: &String, bitmap_builder: &dyn BitmapBuilder, bitmap: &Box<dyn Bitmap>) -> Result<(), String> {
+pub fn save(font_path: &String, bitmap_builder: &dyn BitmapBuilder, bitmap: &Box<dyn Bitmap>) -> Result<(), LazuliError> {
     let bitmap_builder_hash = bitmap_builder.get_hash()?;
 
     bitmap.save(&format!("{}.{}-texture.png", font_path, bitmap_builder_hash))?;
@@ -102,20 +102,20 @@ pub fn save(font_path: &String, bitmap_builder: &dyn BitmapBuilder, bitmap: &Box
     let json: String = bitmap.to_json_cache()?;
     let path = format!("{}.{}-data.json", font_path, bitmap_builder_hash);
     fs::write(Path::new(&path), json).map_err(|err| {
-        format!("failed to save bitmap cache to file: {}", err)
+        AssetError::Io { path, source: err }
     })?;
 
     Ok(())
 }
 
-fn get_bitmap_builder_hash(bitmap_builder: &SdfBitmapBuilder) -> Result<String, String> {
+fn get_bitmap_builder_hash(bitmap_builder: &SdfBitmapBuilder) -> Result<String, LazuliError> {
     match serde_json::to_string(bitmap_builder) {
         Ok(bitmap_builder_string) => {
             let mut hasher = DefaultHasher::new();
             bitmap_builder_string.hash(&mut hasher);
             Ok(hasher.finish().to_string())
         },
-        Err(err) => Err(err.to_string()),
+        Err(err) => Err(AssetError::Serialization(err).into()),
     }
 }
 
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use crate::error::LazuliError;
 use crate::graphics::texture::ImageType;
 
 use super::{Bitmap, BitmapCharacter};
@@ -16,7 +17,7 @@ impl Bitmap for MockBitmap {
         &ImageType::Mock()
     }
 
-    fn save(&self, _path: &String) -> Result<(), String> {
+    fn save(&self, _path: &String) -> Result<(), LazuliError> {
         Ok(())
     }
 
@@ -32,7 +33,7 @@ impl Bitmap for MockBitmap {
         self.spread
     }
 
-    fn to_json_cache(&self) -> Result<String, String> {
+    fn to_json_cache(&self) -> Result<String, LazuliError> {
         Ok(self.json_cache.clone())
     }
 }
@@ -1,6 +1,6 @@
 use std::{fs::File, io::Read};
 
-use crate::{asset_manager::AssetManager, graphics::{material::Material, shader::ShaderProgram, texture::GlTextureImage}, log, ResourceId};
+use crate::{error::{AssetError, LazuliError}, asset_manager::AssetManager, graphics::{material::Material, shader::ShaderProgram, texture::GlTextureImage}, log, ResourceId};
 
 use super::{Bitmap, bitmap::BitmapBuilder, bitmap_cache};
 
@@ -43,7 +43,7 @@ impl Font for GlFont {
 }
 
 impl GlFont {
-    pub fn new(bitmap_builder: &dyn BitmapBuilder, shader_id: ResourceId<Box<dyn ShaderProgram>>, asset_manager: &mut dyn AssetManager) -> Result<Self, String> {
+    pub fn new(bitmap_builder: &dyn BitmapBuilder, shader_id: ResourceId<Box<dyn ShaderProgram>>, asset_manager: &mut dyn AssetManager) -> Result<Self, LazuliError> {
         match load_font(bitmap_builder.font_file_path()) {
             Ok(font) => {
                 let atlas = Self::get_bitmap(font, bitmap_builder.font_file_path(), bitmap_builder)?;
@@ -66,7 +66,7 @@ impl GlFont {
         }
     }
 
-    fn get_bitmap(font: rusttype::Font<'static>, path: &String, bitmap_builder: &dyn BitmapBuilder) -> Result<Box<dyn Bitmap>, String> {
+    fn get_bitmap(font: rusttype::Font<'static>, path: &String, bitmap_builder: &dyn BitmapBuilder) -> Result<Box<dyn Bitmap>, LazuliError> {
         if let Some(existing_bitmap) = bitmap_cache::load(&path, bitmap_builder) {
             return Ok(existing_bitmap)
         } else {
@@ -86,18 +86,18 @@ impl GlFont {
     }
 }
 
-pub fn load_font(path: &String) -> Result<rusttype::Font<'static>, String> {
+pub fn load_font(path: &String) -> Result<rusttype::Font<'static>, LazuliError> {
     let mut file = File::open(path).map_err(|err| {
-        format!("Failed to open file: {}", err.to_string())
+        AssetError::Io { path: path.clone(), source: err }
     })?;
 
     let mut font_data: Vec<u8> = Vec::new();
     file.read_to_end(&mut font_data).map_err(|err| {
-        format!("Failed to read file: {}", err.to_string())
+        AssetError::Io { path: path.clone(), source: err }
     })?;
 
     match rusttype::Font::try_from_vec(font_data) {
         Some(font) => Ok(font),
-        None => Err(format!("Failed to create Font")),
+        None => Err(AssetError::InvalidFont { path: path.clone() }.into()),
     }
 }
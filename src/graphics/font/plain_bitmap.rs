@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use image::{DynamicImage, GrayImage, Luma};
 use rusttype::PositionedGlyph;
 
-use crate::{graphics::texture::{ImageType, downsample_gray_image}, log};
+use crate::{error::{AssetError, LazuliError}, graphics::texture::{ImageType, downsample_gray_image}, log};
 
 use super::{BitmapCharacter, Bitmap, bitmap_cache::PlainBitmapCache, PlainBitmapBuilder};
 
@@ -18,10 +18,8 @@ impl Bitmap for PlainBitmap {
         &self.image
     }
 
-    fn save(&self, path: &String) -> Result<(), String> {
-        self.image.save(path).map_err(|err| {
-            format!("Failed to save bitmap image: {}", err)
-        })
+    fn save(&self, path: &String) -> Result<(), LazuliError> {
+        self.image.save(path)
     }
 
     fn characters(&self) -> &HashMap<char, BitmapCharacter> {
@@ -36,28 +34,25 @@ impl Bitmap for PlainBitmap {
         0
     }
 
-    fn to_json_cache(&self) -> Result<String, String> {
+    fn to_json_cache(&self) -> Result<String, LazuliError> {
         let bitmap_cache = PlainBitmapCache::from(&self);
 
         serde_json::to_string(&bitmap_cache).map_err(|err| {
-            format!("failed to serialize bitmap cache: {}", err)
+            AssetError::Serialization(err).into()
         })
     }
 }
 
 impl PlainBitmap {
-    pub fn new(font: &rusttype::Font<'static>, bitmap_builder: &PlainBitmapBuilder) -> Result<PlainBitmap, String> {
+    pub fn new(font: &rusttype::Font<'static>, bitmap_builder: &PlainBitmapBuilder) -> Result<PlainBitmap, LazuliError> {
         if bitmap_builder.characters.len() == 0 {
-            return Err("Failed to create bitmap: character set may not be empty".to_string());
+            return Err(AssetError::InvalidBitmap("character set may not be empty".to_string()).into());
         }
 
-        let bitmap = Self::create(font, bitmap_builder).map_err(|err| {
-            format!("Failed to create bitmap: {}", err)
-        })?;
-        Ok(bitmap)
+        Self::create(font, bitmap_builder)
     }
 
-    fn create(font: &rusttype::Font<'static>, bitmap_builder: &PlainBitmapBuilder) -> Result<Self, String> {
+    fn create(font: &rusttype::Font<'static>, bitmap_builder: &PlainBitmapBuilder) -> Result<Self, LazuliError> {
         let scale = rusttype::Scale::uniform(bitmap_builder.font_size * bitmap_builder.super_sampling_factor as f32);
         let v_metrics = font.v_metrics(scale);
         let start_point = rusttype::point(bitmap_builder.padding_x as f32, bitmap_builder.padding_y as f32 + v_metrics.ascent);
@@ -2,7 +2,7 @@ use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
 
 use serde::Serialize;
 
-use crate::{graphics::shader::{GlShaderBuilder, ShaderBuilder}, log};
+use crate::{error::{AssetError, LazuliError}, graphics::shader::{GlShaderBuilder, ShaderBuilder}, log};
 
 use super::{bitmap::BitmapBuilder, Bitmap, plain_bitmap::PlainBitmap, bitmap_cache::PlainBitmapCache};
 
@@ -24,21 +24,21 @@ impl BitmapBuilder for PlainBitmapBuilder {
         self.cache
     }
 
-    fn build(&self, font: &rusttype::Font<'static>) -> Result<Box<dyn Bitmap>, String> {
+    fn build(&self, font: &rusttype::Font<'static>) -> Result<Box<dyn Bitmap>, LazuliError> {
         match PlainBitmap::new(font, &self) {
             Ok(bitmap) => return Ok(Box::new(bitmap)),
             Err(err) => Err(err),
         }
     }
 
-    fn get_hash(&self) -> Result<String, String> {
+    fn get_hash(&self) -> Result<String, LazuliError> {
         match serde_json::to_string(&self) {
             Ok(bitmap_builder_string) => {
                 let mut hasher = DefaultHasher::new();
                 bitmap_builder_string.hash(&mut hasher);
                 Ok(hasher.finish().to_string())
             },
-            Err(err) => Err(err.to_string()),
+            Err(err) => Err(AssetError::Serialization(err).into()),
         }
     }
 
@@ -2,7 +2,7 @@ use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
 
 use serde::Serialize;
 
-use crate::{graphics::shader::{GlShaderBuilder, ShaderBuilder}, log};
+use crate::{error::{AssetError, LazuliError}, graphics::shader::{GlShaderBuilder, ShaderBuilder}, log};
 
 use super::{bitmap::BitmapBuilder, Bitmap, sdf_bitmap::SdfBitmap, bitmap_cache::{SdfBitmapCache, self}};
 
@@ -20,7 +20,7 @@ pub struct SdfBitmapBuilder {
 }
 
 impl BitmapBuilder for SdfBitmapBuilder {
-    fn build(&self, font: &rusttype::Font<'static>) -> Result<Box<dyn Bitmap>, String> {
+    fn build(&self, font: &rusttype::Font<'static>) -> Result<Box<dyn Bitmap>, LazuliError> {
         match SdfBitmap::new(font, &self) {
             Ok(bitmap) => return Ok(Box::new(bitmap)),
             Err(err) => Err(err),
@@ -31,14 +31,14 @@ impl BitmapBuilder for SdfBitmapBuilder {
         self.cache
     }
 
-    fn get_hash(&self) -> Result<String, String> {
+    fn get_hash(&self) -> Result<String, LazuliError> {
         match serde_json::to_string(&self) {
             Ok(bitmap_builder_string) => {
                 let mut hasher = DefaultHasher::new();
                 bitmap_builder_string.hash(&mut hasher);
                 Ok(hasher.finish().to_string())
             },
-            Err(err) => Err(err.to_string()),
+            Err(err) => Err(AssetError::Serialization(err).into()),
         }
     }
 
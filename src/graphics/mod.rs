@@ -3,6 +3,7 @@ mod transform;
 mod camera;
 
 pub mod renderer;
+pub mod capture;
 pub mod shader;
 pub mod window;
 pub mod texture;
@@ -17,6 +18,12 @@ pub use transform::Transform;
 pub use camera::Camera;
 pub use camera::LookDirectionLimits;
 pub use camera::ZoomLimits;
+pub use camera::ProjectionMode;
+pub use camera::DEFAULT_ORTHO_DISTANCE;
+pub use camera::CameraController;
+pub use camera::FlyController;
+pub use camera::OrbitController;
+pub use camera::FollowController;
 
 pub mod color;
 pub use color::Color;
@@ -1,17 +1,17 @@
-use crate::{
-    graphics::{scene::Scene, ui::Interface}, 
+use crate::{error::LazuliError, 
+    graphics::{scene::SceneManager, ui::Interface}, 
     error::opengl, asset_manager::AssetManager
 };
 
 use super::fps::Fps;
 
 pub struct Renderer {
-    pub scene: Box<dyn Scene>,
+    pub scene_manager: SceneManager,
     fps: Fps,
 }
 
 impl Renderer {
-    pub fn new(scene: Box<dyn Scene>) -> Result<Self, String> {
+    pub fn new(scene_manager: SceneManager) -> Result<Self, LazuliError> {
         unsafe {
             gl::BlendFunc(gl::SRC_ALPHA, gl::ONE_MINUS_SRC_ALPHA);
             gl::Enable(gl::BLEND);
@@ -20,16 +20,16 @@ impl Renderer {
         }
 
         Ok(Self{
-            scene, fps: Fps::new(),
+            scene_manager, fps: Fps::new(),
         })
     }
 
-    pub fn draw(&mut self, asset_manager: &mut dyn AssetManager, interface: &Interface) {
+    pub fn draw(&mut self, asset_manager: &mut dyn AssetManager, interface: &Interface, interpolation: f32) {
         unsafe {
             gl::ClearColor(0.45, 0.4, 0.6, 1.0);
             gl::Clear(gl::COLOR_BUFFER_BIT | gl::DEPTH_BUFFER_BIT);
 
-            self.scene.draw(asset_manager);
+            self.scene_manager.draw(asset_manager, interpolation);
             interface.draw(asset_manager);
             self.fps.update_fps_count();
 
@@ -0,0 +1,17 @@
+mod scene;
+pub use scene::Scene;
+pub use scene::SceneFactory;
+pub use scene::scene_factory;
+
+mod scene_manager;
+pub use scene_manager::SceneManager;
+
+mod scene_transition;
+pub use scene_transition::SceneTransition;
+pub use scene_transition::TransitionPhase;
+pub use scene_transition::FadeProgress;
+
+#[cfg(test)]
+mod scene_manager_test;
+#[cfg(test)]
+mod scene_transition_test;
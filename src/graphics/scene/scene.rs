@@ -0,0 +1,51 @@
+use std::any::Any;
+
+use glam::Vec2;
+
+use crate::{error::LazuliError, event::EventSystem, input::Input, asset_manager::AssetManager, world::World};
+
+use crate::graphics::ui::Interface;
+
+pub trait Scene: Any {
+    fn new(event_system: &mut EventSystem, window_size: Vec2, pixel_density: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Self, LazuliError> where Self: Sized;
+
+    /// `interpolation` is the progress from the last update towards the next update, in the range [0.0, 1.0).
+    /// It can be used to smoothly draw objects in between two simulation steps.
+    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager, interpolation: f32);
+
+    /// Gets called at a fixed rate of `time::TICK_RATE` times per second, independent of the frame rate.
+    /// Use `time::DELTA` as the time that passed since the previous update.
+    fn update(&mut self, event_system: &mut EventSystem, input: &Input, asset_manager: &mut dyn AssetManager, interface: &mut Interface);
+
+    /// Gets called when the scene gets added to the scene stack
+    fn on_enter(&mut self, _event_system: &mut EventSystem, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {}
+
+    /// Gets called when the scene gets removed from the scene stack, either by being popped or replaced
+    fn on_exit(&mut self, _event_system: &mut EventSystem, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {}
+
+    /// Gets called when another scene gets pushed on top of this scene. A paused scene does not get updated, but
+    /// it does still get drawn. Since all scenes share the same interface, a scene should hide its own ui when
+    /// it should not be visible while paused.
+    fn on_pause(&mut self, _event_system: &mut EventSystem, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {}
+
+    /// Gets called when the scene on top of this scene got popped, making this the active scene again
+    fn on_resume(&mut self, _event_system: &mut EventSystem, _asset_manager: &mut dyn AssetManager, _interface: &mut Interface) {}
+
+    /// The world of the scene, if it has one. The engine draws it right after `Scene::draw`.
+    fn world(&self) -> Option<&World> { None }
+
+    /// The world of the scene, if it has one. The engine runs its systems right after `Scene::update`.
+    fn mut_world(&mut self) -> Option<&mut World> { None }
+}
+
+/// Creates a scene so that it can be added to the scene stack. Use `scene_factory` to get one for a scene type.
+pub type SceneFactory = fn(&mut EventSystem, Vec2, f32, &mut dyn AssetManager, &mut Interface) -> Result<Box<dyn Scene>, LazuliError>;
+
+pub fn scene_factory<T: Scene>() -> SceneFactory {
+    create_boxed_scene::<T>
+}
+
+fn create_boxed_scene<T: Scene>(event_system: &mut EventSystem, window_size: Vec2, pixel_density: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Box<dyn Scene>, LazuliError> {
+    let scene = T::new(event_system, window_size, pixel_density, asset_manager, interface)?;
+    Ok(Box::new(scene))
+}
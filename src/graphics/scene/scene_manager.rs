@@ -0,0 +1,253 @@
+use std::{any::Any, cell::RefCell, collections::VecDeque, rc::Rc};
+
+use crate::{asset_manager::AssetManager, event::{EventPropagation, EventSystem, PopScene, PushScene, ReplaceScene}, graphics::{ui::{shapes::RectangleBuilder, Interface, UiElementId, MAX_Z_INDEX}, Color}, input::Input, log, ResourceId};
+
+use super::{scene_transition::{FadeProgress, SceneTransition}, Scene, SceneFactory};
+
+/// A request to change the scene stack that is waiting for a transition to cover up the screen
+enum SceneOperation {
+    Push(SceneFactory),
+    Pop,
+    Replace(SceneFactory),
+}
+
+struct ActiveTransition {
+    progress: FadeProgress,
+    color: Color,
+    overlay_id: Option<ResourceId<UiElementId>>,
+    operation: Option<SceneOperation>,
+}
+
+/// Keeps a stack of scenes. Only the scene on top of the stack gets updated, but all scenes get drawn, starting
+/// from the bottom of the stack. This makes it possible to, for example, push a pause menu on top of a level.
+///
+/// Scenes can change the stack by sending a `PushScene`, `PopScene` or `ReplaceScene` event through the
+/// `EventSystem`. These requests get handled right after the active scene has been updated, in the order they
+/// were sent.
+pub struct SceneManager {
+    scenes: Vec<Box<dyn Scene>>,
+    transition: Option<ActiveTransition>,
+
+    /// The scene events that were sent since the last update, filled by subscriptions
+    scene_requests: Rc<RefCell<VecDeque<(SceneOperation, SceneTransition)>>>,
+}
+
+impl SceneManager {
+    pub fn new(mut initial_scene: Box<dyn Scene>, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Self {
+        let scene_requests = Rc::new(RefCell::new(VecDeque::new()));
+
+        let push_requests = scene_requests.clone();
+        event_system.subscribe(move |event: &PushScene| {
+            push_requests.borrow_mut().push_back((SceneOperation::Push(event.scene_factory), event.transition.clone()));
+            EventPropagation::Continue
+        });
+        let pop_requests = scene_requests.clone();
+        event_system.subscribe(move |event: &PopScene| {
+            pop_requests.borrow_mut().push_back((SceneOperation::Pop, event.transition.clone()));
+            EventPropagation::Continue
+        });
+        let replace_requests = scene_requests.clone();
+        event_system.subscribe(move |event: &ReplaceScene| {
+            replace_requests.borrow_mut().push_back((SceneOperation::Replace(event.scene_factory), event.transition.clone()));
+            EventPropagation::Continue
+        });
+
+        initial_scene.on_enter(event_system, asset_manager, interface);
+
+        Self {
+            scenes: vec![initial_scene],
+            transition: None,
+            scene_requests,
+        }
+    }
+
+    pub fn update(&mut self, event_system: &mut EventSystem, input: &Input, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        if let Some(scene) = self.scenes.last_mut() {
+            scene.update(event_system, input, asset_manager, interface);
+
+            if let Some(world) = scene.mut_world() {
+                world.run_systems(event_system, input, asset_manager);
+            }
+        }
+
+        self.update_transition(event_system, asset_manager, interface);
+        self.handle_scene_events(event_system, asset_manager, interface);
+    }
+
+    pub unsafe fn draw(&self, asset_manager: &mut dyn AssetManager, interpolation: f32) {
+        for scene in self.scenes.iter() {
+            scene.draw(asset_manager, interpolation);
+
+            if let Some(world) = scene.world() {
+                world.draw(asset_manager);
+            }
+        }
+    }
+
+    pub fn push_scene(&mut self, scene_factory: SceneFactory, transition: SceneTransition, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        self.start_operation(SceneOperation::Push(scene_factory), transition, event_system, asset_manager, interface);
+    }
+
+    pub fn pop_scene(&mut self, transition: SceneTransition, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        self.start_operation(SceneOperation::Pop, transition, event_system, asset_manager, interface);
+    }
+
+    pub fn replace_scene(&mut self, scene_factory: SceneFactory, transition: SceneTransition, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        self.start_operation(SceneOperation::Replace(scene_factory), transition, event_system, asset_manager, interface);
+    }
+
+    pub fn active_scene(&self) -> Option<&dyn Scene> {
+        self.scenes.last().map(|scene| scene.as_ref())
+    }
+
+    /// Get the active scene, if it is of type `T`
+    pub fn active_scene_as<T: Scene>(&self) -> Option<&T> {
+        let scene: &dyn Any = self.scenes.last()?.as_ref();
+        scene.downcast_ref::<T>()
+    }
+
+    /// Get the active scene, if it is of type `T`
+    pub fn mut_active_scene_as<T: Scene>(&mut self) -> Option<&mut T> {
+        let scene: &mut dyn Any = self.scenes.last_mut()?.as_mut();
+        scene.downcast_mut::<T>()
+    }
+
+    pub fn number_of_scenes(&self) -> usize {
+        self.scenes.len()
+    }
+
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    fn handle_scene_events(&mut self, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        // Scenes that get created or entered can send scene events as well, so the queue can not stay borrowed
+        loop {
+            let request = self.scene_requests.borrow_mut().pop_front();
+            match request {
+                Some((operation, transition)) => self.start_operation(operation, transition, event_system, asset_manager, interface),
+                None => break,
+            }
+        }
+    }
+
+    fn start_operation(&mut self, operation: SceneOperation, transition: SceneTransition, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        if self.transition.is_some() {
+            log::engine_warn("SceneManager is ignoring a scene change because another scene transition is still in progress");
+            return;
+        }
+
+        match transition {
+            SceneTransition::Instant => {
+                self.apply_operation(operation, event_system, asset_manager, interface);
+            },
+            SceneTransition::Fade { duration, color } => {
+                let (r, g, b, _) = color.to_rgba_tuple();
+                let window_size = *interface.element_registry().size();
+
+                let overlay_id = match interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
+                    .with_size(window_size)
+                    .with_color(Color::Rgba(r, g, b, 0.0))
+                    .with_z_index(MAX_Z_INDEX)
+                    .with_handle_input_events(false)
+                , asset_manager) {
+                    Ok(id) => Some(id),
+                    Err(err) => {
+                        log::engine_err(format!("SceneManager failed to create fade overlay: {}", err));
+                        None
+                    },
+                };
+
+                self.transition = Some(ActiveTransition {
+                    progress: FadeProgress::new(duration),
+                    color,
+                    overlay_id,
+                    operation: Some(operation),
+                });
+            },
+        }
+    }
+
+    fn update_transition(&mut self, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        let mut transition = match self.transition.take() {
+            Some(transition) => transition,
+            None => return,
+        };
+
+        if transition.progress.advance() {
+            if let Some(operation) = transition.operation.take() {
+                self.apply_operation(operation, event_system, asset_manager, interface);
+            }
+        }
+
+        if let Some(overlay_id) = &transition.overlay_id {
+            let (r, g, b, _) = transition.color.to_rgba_tuple();
+            _ = interface.mut_element_registry().set_element_color(overlay_id, Color::Rgba(r, g, b, transition.progress.coverage()));
+        }
+
+        if transition.progress.is_done() {
+            if let Some(overlay_id) = &transition.overlay_id {
+                _ = interface.mut_element_registry().remove_element(overlay_id);
+            }
+        } else {
+            self.transition = Some(transition);
+        }
+    }
+
+    fn apply_operation(&mut self, operation: SceneOperation, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) {
+        match operation {
+            SceneOperation::Push(scene_factory) => {
+                let mut new_scene = match Self::create_scene(scene_factory, event_system, asset_manager, interface) {
+                    Some(scene) => scene,
+                    None => return,
+                };
+
+                if let Some(scene) = self.scenes.last_mut() {
+                    scene.on_pause(event_system, asset_manager, interface);
+                }
+
+                new_scene.on_enter(event_system, asset_manager, interface);
+                self.scenes.push(new_scene);
+            },
+            SceneOperation::Pop => {
+                if self.scenes.len() <= 1 {
+                    log::engine_warn("SceneManager can not pop the last scene");
+                    return;
+                }
+
+                let mut scene = self.scenes.pop().unwrap();
+                scene.on_exit(event_system, asset_manager, interface);
+
+                if let Some(scene) = self.scenes.last_mut() {
+                    scene.on_resume(event_system, asset_manager, interface);
+                }
+            },
+            SceneOperation::Replace(scene_factory) => {
+                let mut new_scene = match Self::create_scene(scene_factory, event_system, asset_manager, interface) {
+                    Some(scene) => scene,
+                    None => return,
+                };
+
+                if let Some(mut scene) = self.scenes.pop() {
+                    scene.on_exit(event_system, asset_manager, interface);
+                }
+
+                new_scene.on_enter(event_system, asset_manager, interface);
+                self.scenes.push(new_scene);
+            },
+        }
+    }
+
+    fn create_scene(scene_factory: SceneFactory, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Option<Box<dyn Scene>> {
+        let window_size = *interface.element_registry().size();
+        let pixel_density = interface.element_registry().pixel_density();
+
+        match scene_factory(event_system, window_size, pixel_density, asset_manager, interface) {
+            Ok(scene) => Some(scene),
+            Err(err) => {
+                log::engine_err(format!("SceneManager failed to create scene: {}", err));
+                None
+            },
+        }
+    }
+}
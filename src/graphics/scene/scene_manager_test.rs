@@ -0,0 +1,161 @@
+use std::cell::RefCell;
+
+use glam::Vec2;
+
+use crate::{error::LazuliError, asset_manager::{asset_manager_mock::MockAssetManager, AssetManager}, event::{EventSystem, PopScene, PushScene, ReplaceScene}, graphics::ui::Interface, input::Input};
+
+use super::{scene_factory, Scene, SceneManager, SceneTransition};
+
+thread_local! {
+    static HOOK_CALLS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+fn log_hook_call(call: impl Into<String>) {
+    HOOK_CALLS.with(|calls| calls.borrow_mut().push(call.into()));
+}
+
+fn take_hook_calls() -> Vec<String> {
+    HOOK_CALLS.with(|calls| calls.borrow_mut().drain(..).collect())
+}
+
+macro_rules! test_scene {
+    ($name:ident) => {
+        struct $name {
+            nr_updates: u32,
+        }
+
+        impl Scene for $name {
+            fn new(_: &mut EventSystem, _: Vec2, _: f32, _: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
+                Ok(Self { nr_updates: 0 })
+            }
+            unsafe fn draw(&self, _: &mut dyn AssetManager, _: f32) {}
+            fn update(&mut self, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager, _: &mut Interface) {
+                self.nr_updates += 1;
+            }
+            fn on_enter(&mut self, _: &mut EventSystem, _: &mut dyn AssetManager, _: &mut Interface) {
+                log_hook_call(format!("{} enter", stringify!($name)));
+            }
+            fn on_exit(&mut self, _: &mut EventSystem, _: &mut dyn AssetManager, _: &mut Interface) {
+                log_hook_call(format!("{} exit", stringify!($name)));
+            }
+            fn on_pause(&mut self, _: &mut EventSystem, _: &mut dyn AssetManager, _: &mut Interface) {
+                log_hook_call(format!("{} pause", stringify!($name)));
+            }
+            fn on_resume(&mut self, _: &mut EventSystem, _: &mut dyn AssetManager, _: &mut Interface) {
+                log_hook_call(format!("{} resume", stringify!($name)));
+            }
+        }
+    };
+}
+
+test_scene!(LevelScene);
+test_scene!(PauseScene);
+test_scene!(OtherLevelScene);
+
+struct FailingScene {}
+
+impl Scene for FailingScene {
+    fn new(_: &mut EventSystem, _: Vec2, _: f32, _: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
+        Err(LazuliError::Other("failed on purpose".to_string()))
+    }
+    unsafe fn draw(&self, _: &mut dyn AssetManager, _: f32) {}
+    fn update(&mut self, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager, _: &mut Interface) {}
+}
+
+fn create_scene_manager() -> (SceneManager, EventSystem, MockAssetManager, Interface) {
+    let mut event_system = EventSystem::new();
+    let mut asset_manager = MockAssetManager::new();
+    let mut interface = Interface::new(&mut event_system, Vec2::new(800.0, 600.0), 1.0);
+
+    let initial_scene = LevelScene::new(&mut event_system, Vec2::ZERO, 1.0, &mut asset_manager, &mut interface).unwrap();
+    let scene_manager = SceneManager::new(Box::new(initial_scene), &mut event_system, &mut asset_manager, &mut interface);
+
+    (scene_manager, event_system, asset_manager, interface)
+}
+
+#[test]
+fn test_push_and_pop_scene() {
+    let (mut scene_manager, mut event_system, mut asset_manager, mut interface) = create_scene_manager();
+    assert_eq!(vec!["LevelScene enter"], take_hook_calls());
+
+    scene_manager.push_scene(scene_factory::<PauseScene>(), SceneTransition::Instant, &mut event_system, &mut asset_manager, &mut interface);
+    assert_eq!(2, scene_manager.number_of_scenes());
+    assert!(scene_manager.active_scene_as::<PauseScene>().is_some());
+    assert_eq!(vec!["LevelScene pause", "PauseScene enter"], take_hook_calls());
+
+    scene_manager.pop_scene(SceneTransition::Instant, &mut event_system, &mut asset_manager, &mut interface);
+    assert_eq!(1, scene_manager.number_of_scenes());
+    assert!(scene_manager.active_scene_as::<LevelScene>().is_some());
+    assert_eq!(vec!["PauseScene exit", "LevelScene resume"], take_hook_calls());
+}
+
+#[test]
+fn test_replace_scene() {
+    let (mut scene_manager, mut event_system, mut asset_manager, mut interface) = create_scene_manager();
+    take_hook_calls();
+
+    scene_manager.replace_scene(scene_factory::<OtherLevelScene>(), SceneTransition::Instant, &mut event_system, &mut asset_manager, &mut interface);
+    assert_eq!(1, scene_manager.number_of_scenes());
+    assert!(scene_manager.active_scene_as::<OtherLevelScene>().is_some());
+    assert_eq!(vec!["LevelScene exit", "OtherLevelScene enter"], take_hook_calls());
+}
+
+#[test]
+fn test_can_not_pop_last_scene() {
+    let (mut scene_manager, mut event_system, mut asset_manager, mut interface) = create_scene_manager();
+    take_hook_calls();
+
+    scene_manager.pop_scene(SceneTransition::Instant, &mut event_system, &mut asset_manager, &mut interface);
+    assert_eq!(1, scene_manager.number_of_scenes());
+    assert!(take_hook_calls().is_empty());
+}
+
+#[test]
+fn test_failing_scene_does_not_change_stack() {
+    let (mut scene_manager, mut event_system, mut asset_manager, mut interface) = create_scene_manager();
+    take_hook_calls();
+
+    scene_manager.push_scene(scene_factory::<FailingScene>(), SceneTransition::Instant, &mut event_system, &mut asset_manager, &mut interface);
+    scene_manager.replace_scene(scene_factory::<FailingScene>(), SceneTransition::Instant, &mut event_system, &mut asset_manager, &mut interface);
+    assert_eq!(1, scene_manager.number_of_scenes());
+    assert!(scene_manager.active_scene_as::<LevelScene>().is_some());
+    assert!(take_hook_calls().is_empty());
+}
+
+#[test]
+fn test_scene_events() {
+    let (mut scene_manager, mut event_system, mut asset_manager, mut interface) = create_scene_manager();
+    let input = Input::new();
+
+    event_system.send(PushScene::new::<PauseScene>());
+    scene_manager.update(&mut event_system, &input, &mut asset_manager, &mut interface);
+    assert!(scene_manager.active_scene_as::<PauseScene>().is_some());
+
+    // only the active scene gets updated
+    scene_manager.update(&mut event_system, &input, &mut asset_manager, &mut interface);
+    assert_eq!(1, scene_manager.active_scene_as::<PauseScene>().unwrap().nr_updates);
+
+    event_system.send(PopScene::new());
+    scene_manager.update(&mut event_system, &input, &mut asset_manager, &mut interface);
+    assert_eq!(1, scene_manager.active_scene_as::<LevelScene>().unwrap().nr_updates);
+
+    event_system.send(ReplaceScene::new::<OtherLevelScene>());
+    scene_manager.update(&mut event_system, &input, &mut asset_manager, &mut interface);
+    assert!(scene_manager.active_scene_as::<OtherLevelScene>().is_some());
+    assert_eq!(1, scene_manager.number_of_scenes());
+}
+
+#[test]
+fn test_scene_events_are_handled_in_send_order() {
+    let (mut scene_manager, mut event_system, mut asset_manager, mut interface) = create_scene_manager();
+    let input = Input::new();
+    take_hook_calls();
+
+    event_system.send(PushScene::new::<PauseScene>());
+    event_system.send(PopScene::new());
+    scene_manager.update(&mut event_system, &input, &mut asset_manager, &mut interface);
+
+    assert_eq!(1, scene_manager.number_of_scenes());
+    assert!(scene_manager.active_scene_as::<LevelScene>().is_some());
+    assert_eq!(vec!["LevelScene pause", "PauseScene enter", "PauseScene exit", "LevelScene resume"], take_hook_calls());
+}
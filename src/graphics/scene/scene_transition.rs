@@ -0,0 +1,94 @@
+use crate::{graphics::Color, time};
+
+#[derive(Clone, Debug, Default)]
+pub enum SceneTransition {
+    /// Switch scenes right away
+    #[default]
+    Instant,
+    /// Fade the screen to the given color, switch scenes and fade back. The duration is in seconds and covers
+    /// both fading out and fading in.
+    Fade { duration: f32, color: Color },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransitionPhase {
+    /// Covering up the old scene. The scene switch happens once this phase is done.
+    Out,
+    /// Revealing the new scene
+    In,
+    Done,
+}
+
+/// Keeps track of the progress of a fade transition. It gets advanced once per update, so its progress does not
+/// depend on the frame rate.
+pub struct FadeProgress {
+    phase: TransitionPhase,
+    /// Number of seconds that passed in the current phase
+    elapsed: f32,
+    /// Duration of a single phase, in seconds
+    phase_duration: f32,
+}
+
+impl FadeProgress {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            phase: TransitionPhase::Out,
+            elapsed: 0.0,
+            phase_duration: (duration / 2.0).max(0.0),
+        }
+    }
+
+    /// Advance the transition by a single update. Returns true if the `Out` phase finished during this update,
+    /// which means the scenes should now be switched.
+    pub fn advance(&mut self) -> bool {
+        if self.phase == TransitionPhase::Done {
+            return false;
+        }
+
+        self.elapsed += time::DELTA;
+
+        if self.elapsed < self.phase_duration {
+            return false;
+        }
+
+        self.elapsed = 0.0;
+
+        match self.phase {
+            TransitionPhase::Out => {
+                self.phase = TransitionPhase::In;
+                true
+            },
+            TransitionPhase::In => {
+                self.phase = TransitionPhase::Done;
+                false
+            },
+            TransitionPhase::Done => false,
+        }
+    }
+
+    /// How much of the screen is covered, from 0.0 (not covered) to 1.0 (fully covered)
+    pub fn coverage(&self) -> f32 {
+        if self.phase_duration <= 0.0 {
+            return match self.phase {
+                TransitionPhase::Out => 1.0,
+                _ => 0.0,
+            };
+        }
+
+        let progress = (self.elapsed / self.phase_duration).clamp(0.0, 1.0);
+
+        match self.phase {
+            TransitionPhase::Out => progress,
+            TransitionPhase::In => 1.0 - progress,
+            TransitionPhase::Done => 0.0,
+        }
+    }
+
+    pub fn phase(&self) -> TransitionPhase {
+        self.phase
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.phase == TransitionPhase::Done
+    }
+}
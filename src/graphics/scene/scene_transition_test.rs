@@ -0,0 +1,37 @@
+use crate::time;
+
+use super::{FadeProgress, TransitionPhase};
+
+#[test]
+fn test_fade_progress() {
+    let updates_per_phase = 5;
+    let mut progress = FadeProgress::new(time::DELTA * updates_per_phase as f32 * 2.0);
+
+    assert_eq!(TransitionPhase::Out, progress.phase());
+    assert_eq!(0.0, progress.coverage());
+
+    for _ in 0..updates_per_phase - 1 {
+        assert_eq!(false, progress.advance());
+    }
+    assert!(progress.coverage() > 0.5);
+
+    // switching scenes happens when the screen is fully covered
+    assert!(progress.advance());
+    assert_eq!(TransitionPhase::In, progress.phase());
+    assert_eq!(1.0, progress.coverage());
+
+    for _ in 0..updates_per_phase {
+        assert_eq!(false, progress.advance());
+    }
+    assert!(progress.is_done());
+    assert_eq!(0.0, progress.coverage());
+}
+
+#[test]
+fn test_fade_progress_without_duration() {
+    let mut progress = FadeProgress::new(0.0);
+
+    assert!(progress.advance());
+    assert_eq!(false, progress.advance());
+    assert!(progress.is_done());
+}
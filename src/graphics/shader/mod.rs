@@ -3,6 +3,7 @@ mod shader;
 mod uniform;
 mod shader_builder;
 mod custom_shader_values;
+mod shader_watcher;
 
 pub use shader::Shader;
 pub use program::ShaderProgram;
@@ -10,6 +11,7 @@ pub use uniform::UniformValue;
 pub use shader_builder::ShaderBuilder;
 pub use shader_builder::GlShaderBuilder;
 pub use custom_shader_values::CustomShaderValues;
+pub use shader_watcher::ShaderWatcher;
 
 pub use shader::PATH_COLORED_VERT;
 pub use shader::PATH_COLORED_FRAG;
@@ -24,3 +26,6 @@ pub use shader::PATH_MOVING_TRIANGLE_FRAG;
 pub mod shader_builder_mock;
 pub mod shader_mock;
 pub mod program_mock;
+
+#[cfg(test)]
+mod shader_watcher_test;
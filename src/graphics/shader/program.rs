@@ -1,8 +1,8 @@
-use std::ffi::CString;
+use std::{cell::{Cell, RefCell}, collections::HashMap, ffi::CString};
 
 use gl::types::{GLuint, GLint};
 
-use crate::{error::opengl, log};
+use crate::{error::{opengl, LazuliError, ShaderError}, log};
 
 use super::{shader::GlShader, uniform::UniformValue};
 
@@ -10,12 +10,22 @@ pub trait ShaderProgram {
     fn apply(&self);
     fn set_uniform(&self, name: &str, value: &UniformValue);
     fn get_uniform_location(&self, name: &str) -> i32;
-    fn get_attribute_location(&self, attribute: &str) -> Result<GLuint, String>;
+    fn get_attribute_location(&self, attribute: &str) -> Result<GLuint, LazuliError>;
+
+    /// The uniforms that were set on this program, so that they can be set again after rebuilding it. Only
+    /// available while recording uniforms, see `set_record_uniforms`.
+    fn known_uniforms(&self) -> Vec<(String, UniformValue)> { vec![] }
+
+    /// Remember the uniforms that get set from now on. Only needed for shaders that can get rebuilt, because
+    /// recording costs an allocation per uniform that gets set.
+    fn set_record_uniforms(&self, _record: bool) {}
 }
 
 #[derive(Debug)]
 pub struct GlShaderProgram {
-    pub id: GLuint
+    pub id: GLuint,
+    uniforms: RefCell<HashMap<String, UniformValue>>,
+    record_uniforms: Cell<bool>,
 }
 
 impl ShaderProgram for GlShaderProgram {
@@ -38,6 +48,10 @@ impl ShaderProgram for GlShaderProgram {
         
         value.set_uniform(location);
         opengl::gl_check_errors();
+
+        if self.record_uniforms.get() {
+            self.uniforms.borrow_mut().insert(name.to_string(), value.clone());
+        }
     }
 
     fn get_uniform_location(&self, name: &str) -> i32 {
@@ -52,9 +66,9 @@ impl ShaderProgram for GlShaderProgram {
         }
     }
 
-    fn get_attribute_location(&self, attribute: &str) -> Result<GLuint, String> {
+    fn get_attribute_location(&self, attribute: &str) -> Result<GLuint, LazuliError> {
         let attribute_as_cstring = CString::new(attribute).map_err(|err| {
-            format!("failed to creating CString from attribute [{}]: {}", attribute, err.to_string())
+            ShaderError::InvalidString { value: attribute.to_string(), source: err }
         })?;
 
         unsafe {
@@ -63,7 +77,7 @@ impl ShaderProgram for GlShaderProgram {
             if result == -1 {
                 log::engine_err(format!("Could not find attribute location of \"{}\"", attribute));
                 opengl::gl_clear_errors();
-                return Err(format!("Could not find attribute location of {}", attribute));
+                return Err(ShaderError::AttributeNotFound(attribute.to_string()).into());
             }
             
             opengl::gl_check_errors();
@@ -71,19 +85,33 @@ impl ShaderProgram for GlShaderProgram {
             Ok(result as GLuint)
         }
     }
+
+    fn known_uniforms(&self) -> Vec<(String, UniformValue)> {
+        self.uniforms.borrow().iter().map(|(name, value)| (name.clone(), value.clone())).collect()
+    }
+
+    fn set_record_uniforms(&self, record: bool) {
+        self.record_uniforms.set(record);
+
+        if !record {
+            self.uniforms.borrow_mut().clear();
+        }
+    }
 }
 
 impl GlShaderProgram {
-    pub fn new(path_vert: &String, path_frag: &String) -> Result<Self, String> {
+    pub fn new(path_vert: &String, path_frag: &String) -> Result<Self, LazuliError> {
         let vertex_shader = GlShader::new(path_vert, gl::VERTEX_SHADER)?;
         let fragment_shader = GlShader::new(path_frag, gl::FRAGMENT_SHADER)?;
         return Self::from_shaders(&[vertex_shader, fragment_shader]);
     }
 
-    pub fn from_shaders(shaders: &[GlShader]) -> Result<Self, String> {
+    pub fn from_shaders(shaders: &[GlShader]) -> Result<Self, LazuliError> {
         unsafe {
             let program = Self {
-                id: gl::CreateProgram()
+                id: gl::CreateProgram(),
+                uniforms: RefCell::new(HashMap::new()),
+                record_uniforms: Cell::new(false),
             };
 
             opengl::gl_check_errors();
@@ -104,7 +132,7 @@ impl GlShaderProgram {
             if success == 1 {
                 Ok(program)
             } else {
-                Err(program.get_shader_program_error())
+                Err(ShaderError::Link { log: program.get_shader_program_error() }.into())
             }
         }
     }
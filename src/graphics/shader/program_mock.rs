@@ -1,3 +1,5 @@
+use crate::error::LazuliError;
+
 use super::ShaderProgram;
 
 pub struct MockShaderProgram {
@@ -13,7 +15,7 @@ impl ShaderProgram for MockShaderProgram {
         1
     }
 
-    fn get_attribute_location(&self, _attribute: &str) -> Result<gl::types::GLuint, String> {
+    fn get_attribute_location(&self, _attribute: &str) -> Result<gl::types::GLuint, LazuliError> {
         Ok(2)
     }
 }
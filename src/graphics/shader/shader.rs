@@ -2,7 +2,8 @@ use std::{ffi::CString, fs};
 use std::ptr;
 use gl::types::{GLuint, GLenum, GLint};
 
-use crate::error::opengl;
+use crate::error::{opengl, LazuliError, ShaderError};
+
 
 pub const PATH_COLORED_VERT: &str = "./assets/shaders/colored.vert";
 pub const PATH_COLORED_FRAG: &str = "./assets/shaders/colored.frag";
@@ -21,7 +22,7 @@ pub struct GlShader {
 }
 
 impl GlShader {
-    pub fn new(path: &String, shader_type: GLenum) -> Result<Self, String> {
+    pub fn new(path: &String, shader_type: GLenum) -> Result<Self, LazuliError> {
         let source_code = load_shader_source(path)?;
 
         unsafe {
@@ -42,7 +43,11 @@ impl GlShader {
             if success == 1 {
                 Ok(shader)
             } else {
-                Err(format!("failed to compile {} shader {}: [{}]", shader_type_to_string(shader_type), path, shader.get_shader_error()))
+                Err(ShaderError::Compile {
+                    path: path.clone(),
+                    shader_type: shader_type_to_string(shader_type),
+                    log: shader.get_shader_error(),
+                }.into())
             }
         }
     }
@@ -80,21 +85,21 @@ impl Drop for GlShader {
 }
 
 /// TODO cache result for if we reuse the shader source
-fn load_shader_source(path: &String) -> Result<CString, String> {
+fn load_shader_source(path: &String) -> Result<CString, LazuliError> {
     let source_code = fs::read_to_string(path).map_err(|err| {
-        format!("failed to read shader file with path '{}': {}", path, err.to_string())
+        ShaderError::Io { path: path.clone(), source: err }
     })?;
 
     CString::new(source_code).map_err(|err| {
-        format!("failed to create shader CString from file '{}': {}", path, err.to_string())
+        ShaderError::InvalidString { value: path.clone(), source: err }.into()
     })
 }
 
-fn shader_type_to_string(shader_type: GLenum) -> String {
+fn shader_type_to_string(shader_type: GLenum) -> &'static str {
     match shader_type {
-        gl::VERTEX_SHADER => String::from("vertex"),
-        gl::FRAGMENT_SHADER => String::from("fragment"),
-        gl::GEOMETRY_SHADER => String::from("geometry"),
-        _ => String::from("unknown"),
+        gl::VERTEX_SHADER => "vertex",
+        gl::FRAGMENT_SHADER => "fragment",
+        gl::GEOMETRY_SHADER => "geometry",
+        _ => "unknown",
     }
 }
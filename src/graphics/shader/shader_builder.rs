@@ -1,25 +1,34 @@
 use std::{hash::{Hash, Hasher}, collections::hash_map::DefaultHasher};
 
+use crate::error::LazuliError;
+
 use super::{program::GlShaderProgram, ShaderProgram};
 
 pub trait ShaderBuilder {
-    fn build(&self) -> Result<Box<dyn ShaderProgram>, String>;
-    fn hash(&self) -> Result<u64, String>;
+    fn build(&self) -> Result<Box<dyn ShaderProgram>, LazuliError>;
+    fn hash(&self) -> Result<u64, LazuliError>;
+
+    /// Files that the shader gets built from. When one of them changes, the shader can be rebuilt.
+    fn source_paths(&self) -> Vec<String> { vec![] }
 }
 
 impl ShaderBuilder for GlShaderBuilder {
-    fn build(&self) -> Result<Box<dyn ShaderProgram>, String> {
+    fn build(&self) -> Result<Box<dyn ShaderProgram>, LazuliError> {
         let shader_program = GlShaderProgram::new(&self.vertex_shader_path, &self.fragment_shader_path)?;
         Ok(Box::new(shader_program))
     }
 
-    fn hash(&self) -> Result<u64, String> {
+    fn hash(&self) -> Result<u64, LazuliError> {
         let mut hasher = DefaultHasher::new();
         self.vertex_shader_path.hash(&mut hasher);
         self.fragment_shader_path.hash(&mut hasher);
 
         Ok(hasher.finish())
     }
+
+    fn source_paths(&self) -> Vec<String> {
+        vec![self.vertex_shader_path.clone(), self.fragment_shader_path.clone()]
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,15 +1,17 @@
+use crate::error::LazuliError;
+
 use super::{program_mock::MockShaderProgram, ShaderBuilder, ShaderProgram};
 
 pub struct MockShaderBuilder {
     pub hash: u64,
 }
 impl ShaderBuilder for MockShaderBuilder {
-    fn build(&self) -> Result<Box<dyn ShaderProgram>, String> {
+    fn build(&self) -> Result<Box<dyn ShaderProgram>, LazuliError> {
         let shader_program = MockShaderProgram{};
         Ok(Box::new(shader_program))
     }
 
-    fn hash(&self) -> Result<u64, String> {
+    fn hash(&self) -> Result<u64, LazuliError> {
         Ok(self.hash)
     }
 }
@@ -0,0 +1,109 @@
+use std::{fs, time::{Duration, Instant, SystemTime}};
+
+use crate::ResourceId;
+
+use super::ShaderProgram;
+
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct WatchedFile {
+    path: String,
+    modified: Option<SystemTime>,
+}
+
+struct WatchedShader {
+    shader_id: ResourceId<Box<dyn ShaderProgram>>,
+    files: Vec<WatchedFile>,
+}
+
+/// Keeps track of the source files of shader programs by polling their modification time, so that shaders can be
+/// recompiled when they change on disk.
+pub struct ShaderWatcher {
+    shaders: Vec<WatchedShader>,
+    poll_interval: Duration,
+    last_poll: Option<Instant>,
+}
+
+impl ShaderWatcher {
+    pub fn new() -> Self {
+        Self {
+            shaders: vec![],
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            last_poll: None,
+        }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    pub fn watch(&mut self, shader_id: ResourceId<Box<dyn ShaderProgram>>, paths: Vec<String>) {
+        if paths.is_empty() {
+            return;
+        }
+
+        self.unwatch(&shader_id);
+
+        let files = paths.into_iter().map(|path| {
+            let modified = modified_time(&path);
+            WatchedFile { path, modified }
+        }).collect();
+
+        self.shaders.push(WatchedShader { shader_id, files });
+    }
+
+    pub fn unwatch(&mut self, shader_id: &ResourceId<Box<dyn ShaderProgram>>) {
+        self.shaders.retain(|shader| !shader.shader_id.equals(shader_id));
+    }
+
+    pub fn is_watching(&self, shader_id: &ResourceId<Box<dyn ShaderProgram>>) -> bool {
+        self.shaders.iter().any(|shader| shader.shader_id.equals(shader_id))
+    }
+
+    /// Check for changed shaders, but at most once every poll interval
+    pub fn poll(&mut self) -> Vec<ResourceId<Box<dyn ShaderProgram>>> {
+        let now = Instant::now();
+
+        if let Some(last_poll) = self.last_poll {
+            if now.duration_since(last_poll) < self.poll_interval {
+                return vec![];
+            }
+        }
+
+        self.last_poll = Some(now);
+        self.changed_shaders()
+    }
+
+    /// Get the shaders of which at least one source file changed since the last check. Files that can not be
+    /// read (for example because an editor is in the middle of saving them) are skipped until they can be read.
+    pub fn changed_shaders(&mut self) -> Vec<ResourceId<Box<dyn ShaderProgram>>> {
+        let mut changed = vec![];
+
+        for shader in self.shaders.iter_mut() {
+            let mut is_changed = false;
+
+            for file in shader.files.iter_mut() {
+                let modified = match modified_time(&file.path) {
+                    Some(modified) => modified,
+                    None => continue,
+                };
+
+                if file.modified != Some(modified) {
+                    file.modified = Some(modified);
+                    is_changed = true;
+                }
+            }
+
+            if is_changed {
+                changed.push(shader.shader_id.duplicate());
+            }
+        }
+
+        changed
+    }
+}
+
+fn modified_time(path: &String) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
@@ -0,0 +1,102 @@
+use std::{fs::{self, File}, path::PathBuf, time::{Duration, SystemTime}};
+
+use crate::ResourceId;
+
+use super::ShaderWatcher;
+
+fn create_shader_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("lazuli_shader_watcher_{}_{}", std::process::id(), name));
+    fs::write(&path, "void main() {}").unwrap();
+    path
+}
+
+fn touch(path: &PathBuf, seconds_later: u64) {
+    let file = File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(seconds_later)).unwrap();
+}
+
+#[test]
+fn test_unchanged_shaders_are_not_reported() {
+    let vert = create_shader_file("unchanged.vert");
+    let frag = create_shader_file("unchanged.frag");
+
+    let mut watcher = ShaderWatcher::new();
+    watcher.watch(ResourceId::new(1), vec![vert.to_string_lossy().to_string(), frag.to_string_lossy().to_string()]);
+
+    assert!(watcher.changed_shaders().is_empty());
+
+    _ = fs::remove_file(vert);
+    _ = fs::remove_file(frag);
+}
+
+#[test]
+fn test_changed_shader_is_reported_once() {
+    let vert = create_shader_file("changed.vert");
+    let frag = create_shader_file("changed.frag");
+    let other = create_shader_file("other.frag");
+
+    let mut watcher = ShaderWatcher::new();
+    watcher.watch(ResourceId::new(1), vec![vert.to_string_lossy().to_string(), frag.to_string_lossy().to_string()]);
+    watcher.watch(ResourceId::new(2), vec![other.to_string_lossy().to_string()]);
+
+    touch(&frag, 10);
+
+    let changed = watcher.changed_shaders();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(*changed[0].id(), 1);
+
+    assert!(watcher.changed_shaders().is_empty());
+
+    _ = fs::remove_file(vert);
+    _ = fs::remove_file(frag);
+    _ = fs::remove_file(other);
+}
+
+#[test]
+fn test_missing_file_is_skipped_until_it_exists() {
+    let path = std::env::temp_dir().join(format!("lazuli_shader_watcher_{}_missing.vert", std::process::id()));
+    _ = fs::remove_file(&path);
+
+    let mut watcher = ShaderWatcher::new();
+    watcher.watch(ResourceId::new(1), vec![path.to_string_lossy().to_string()]);
+
+    assert!(watcher.changed_shaders().is_empty());
+
+    fs::write(&path, "void main() {}").unwrap();
+    assert_eq!(watcher.changed_shaders().len(), 1);
+
+    _ = fs::remove_file(path);
+}
+
+#[test]
+fn test_poll_is_throttled() {
+    let vert = create_shader_file("throttled.vert");
+
+    let mut watcher = ShaderWatcher::new().with_poll_interval(Duration::from_secs(60));
+    watcher.watch(ResourceId::new(1), vec![vert.to_string_lossy().to_string()]);
+
+    assert!(watcher.poll().is_empty());
+
+    touch(&vert, 10);
+    assert!(watcher.poll().is_empty());
+    assert_eq!(watcher.changed_shaders().len(), 1);
+
+    _ = fs::remove_file(vert);
+}
+
+#[test]
+fn test_unwatch() {
+    let vert = create_shader_file("unwatched.vert");
+
+    let mut watcher = ShaderWatcher::new();
+    watcher.watch(ResourceId::new(1), vec![vert.to_string_lossy().to_string()]);
+    assert!(watcher.is_watching(&ResourceId::new(1)));
+
+    watcher.unwatch(&ResourceId::new(1));
+    assert!(!watcher.is_watching(&ResourceId::new(1)));
+
+    touch(&vert, 10);
+    assert!(watcher.changed_shaders().is_empty());
+
+    _ = fs::remove_file(vert);
+}
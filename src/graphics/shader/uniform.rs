@@ -1,6 +1,7 @@
 use gl::types::GLuint;
 use glam::{Mat4, Vec2, Vec4, Vec3};
 
+#[derive(Clone, Debug)]
 pub enum UniformValue {
     Float(f32),
     Float2(f32, f32),
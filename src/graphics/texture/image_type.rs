@@ -1,3 +1,5 @@
+use crate::error::{AssetError, LazuliError};
+
 pub enum ImageType {
     RgbaImage (image::RgbaImage),
     GrayImage (image::GrayImage),
@@ -6,16 +8,16 @@ pub enum ImageType {
 
 impl ImageType {
     /// save the bitmap image to a file
-    pub fn save(&self, path: &String) -> Result<(), String> {
+    pub fn save(&self, path: &String) -> Result<(), LazuliError> {
         match self {
             ImageType::RgbaImage(img) => {
                 img.save(path).map_err(|err| {
-                    format!("Failed to save RgbaImage instance: {}", err)
+                    AssetError::Image { path: path.clone(), source: err }.into()
                 })
             },
             ImageType::GrayImage(img) => {
                 img.save(path).map_err(|err| {
-                    format!("Failed to save GrayImage instance: {}", err)
+                    AssetError::Image { path: path.clone(), source: err }.into()
                 })
             },
             ImageType::Mock() => Ok(()),
@@ -2,7 +2,7 @@ use gl::types::{GLuint, GLenum};
 use glam::Vec2;
 use image::RgbaImage;
 
-use crate::{error::opengl, log};
+use crate::{error::{opengl, AssetError, LazuliError}, log};
 
 use super::{texture_image::GlTextureImage, TextureImage};
 
@@ -31,7 +31,7 @@ impl GlTexture {
         Self { id, original_size: Vec2::ZERO }
     }
 
-    pub fn new_from_path(path: impl Into<String>) -> Result<Self, String> {
+    pub fn new_from_path(path: impl Into<String>) -> Result<Self, LazuliError> {
         let mut texture = Self::create();
 
         let path_string = path.into();
@@ -49,13 +49,13 @@ impl GlTexture {
                 Ok(texture)
             }
             Err(err) => {
-                Err(format!("Failed to load texture image from path {:?}: {}", path_string, err))
+                Err(AssetError::Image { path: path_string, source: err }.into())
             }
         }
     }
 
     // Currently always returns Ok, but returns a Result to keep consistent with new_from_path
-    pub fn new_from_image(img: &dyn TextureImage) -> Result<Self, String> {
+    pub fn new_from_image(img: &dyn TextureImage) -> Result<Self, LazuliError> {
         let mut texture = Self::create();
         texture.bind();
 
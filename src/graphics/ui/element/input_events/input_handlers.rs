@@ -47,6 +47,16 @@ impl InputEventHandlers {
         handler.did_capture()
     }
 
+    pub fn did_handle_event(&self, event: InputEvent) -> bool {
+        match event {
+            InputEvent::MouseLeftUp => self.mouse_left_up_handler.did_handle(),
+            InputEvent::MouseLeftDown => self.mouse_left_down_handler.did_handle(),
+            InputEvent::MouseLeftDrag => self.mouse_left_drag_handler.is_handling(),
+            InputEvent::Hover => self.hover_handler.did_handle(),
+            InputEvent::Scroll => self.scroll_handler.did_handle(),
+        }
+    }
+
     pub fn reset(&mut self, input: &Input) {
         self.mouse_left_down_handler.reset();
         self.mouse_left_up_handler.reset();
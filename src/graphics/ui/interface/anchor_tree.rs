@@ -1,6 +1,6 @@
 use std::any::TypeId;
 
-use crate::{ResourceId, graphics::ui::UiElementId};
+use crate::{error::{LazuliError, UiError}, ResourceId, graphics::ui::UiElementId};
 
 #[derive(Debug, Clone)]
 pub struct AnchorElementIdentifier {
@@ -194,11 +194,11 @@ impl AnchorTree {
     }
 
     /// Add element as anchored to parent
-    pub fn add_element_anchor(&mut self, parent_type_id: TypeId, parent_element_id: &ResourceId<UiElementId>, type_id: TypeId, element_id: ResourceId<UiElementId>) -> Result<(), String> {
+    pub fn add_element_anchor(&mut self, parent_type_id: TypeId, parent_element_id: &ResourceId<UiElementId>, type_id: TypeId, element_id: ResourceId<UiElementId>) -> Result<(), LazuliError> {
         match self.get_mut(parent_type_id, parent_element_id) {
             Some(anchor) => Ok(anchor.push(type_id, element_id)),
             None => {
-                Err(format!("anchor with type {:?} and id {:?} was not found", parent_type_id, parent_element_id))
+                Err(UiError::AnchorNotFound(parent_element_id.duplicate()).into())
             },
         }
     }
@@ -385,10 +385,10 @@ impl AnchorTree {
 
     /// Add children to the given parent.
     /// Returns an error if the parent was not found
-    pub fn add_children(&mut self, parent_id: &ResourceId<UiElementId>, mut children: Vec<AnchoredElement>) -> Result<(), String> {
+    pub fn add_children(&mut self, parent_id: &ResourceId<UiElementId>, mut children: Vec<AnchoredElement>) -> Result<(), LazuliError> {
         match self.get_mut_by_id(parent_id) {
             Some(parent) => Ok(parent.anchored_elements.append(&mut children)),
-            None => Err(format!("parent with id {} was not found", parent_id.id())),
+            None => Err(UiError::AnchorNotFound(parent_id.duplicate()).into()),
         }
     }
 
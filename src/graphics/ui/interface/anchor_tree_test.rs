@@ -1,6 +1,6 @@
 use std::any::TypeId;
 
-use crate::{graphics::ui::element::ui_element_mock::UiElementMock, ResourceId};
+use crate::{error::LazuliError, graphics::ui::element::ui_element_mock::UiElementMock, ResourceId};
 
 use super::anchor_tree::AnchorTree;
 
@@ -30,7 +30,7 @@ fn test_add_element_anchor() {
 ///     
 ///     3
 /// 
-fn test_get() -> Result<(), String> {
+fn test_get() -> Result<(), LazuliError> {
     let mut anchor_tree = AnchorTree::new();
     let type_id = TypeId::of::<UiElementMock>();
     let non_existing_type_id = TypeId::of::<String>();
@@ -84,7 +84,7 @@ fn test_get() -> Result<(), String> {
 ///            \
 ///              --> 4 --> 5
 /// 
-fn test_get_children() -> Result<(), String> {
+fn test_get_children() -> Result<(), LazuliError> {
     let mut anchor_tree = AnchorTree::new();
     let type_id = TypeId::of::<UiElementMock>();
 
@@ -117,7 +117,7 @@ fn test_get_children() -> Result<(), String> {
 ///            \
 ///              --> 4 --> 5
 /// 
-fn test_get_parent() -> Result<(), String> {
+fn test_get_parent() -> Result<(), LazuliError> {
     let mut anchor_tree = AnchorTree::new();
     let type_id = TypeId::of::<UiElementMock>();
 
@@ -151,7 +151,7 @@ fn test_get_parent() -> Result<(), String> {
 ///            \
 ///              --> 4 --> 5
 /// 
-fn test_remove_element_by_id() -> Result<(), String> {
+fn test_remove_element_by_id() -> Result<(), LazuliError> {
     let mut anchor_tree = AnchorTree::new();
     let type_id = TypeId::of::<UiElementMock>();
 
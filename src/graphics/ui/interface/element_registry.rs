@@ -2,7 +2,7 @@ use std::any::TypeId;
 
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, graphics::{font::Font, shader::{CustomShaderValues, UniformValue}, ui::{bounds_2d::Bounds2d, element::{ui_element::UiElement, world_element_data::WorldElementData, AnchorElementData, InputEvent}, shapes::{Rectangle, RectangleBuilder}, Position, Text, TextBuilder, UiElementId}, Color}, input::{Input, ButtonAction, MouseButton}, log, ResourceId};
+use crate::{error::{LazuliError, UiError}, asset_manager::AssetManager, graphics::{font::Font, shader::{CustomShaderValues, UniformValue}, ui::{bounds_2d::Bounds2d, element::{ui_element::UiElement, world_element_data::WorldElementData, AnchorElementData, InputEvent}, shapes::{Rectangle, RectangleBuilder}, Position, Text, TextBuilder, UiElementId}, Color}, input::{Input, ButtonAction, MouseButton}, log, ResourceId};
 
 use super::{interface, element_list::{ElementList, OrderedElementsItem, self}, anchor_tree::{AnchorTree, AnchoredElement, AnchorElementIdentifier}};
 
@@ -23,6 +23,9 @@ pub struct ElementRegistry {
     pixel_density: f32,
 
     anchor_tree: AnchorTree,
+
+    /// Whether an element handled mouse input during the last update
+    is_mouse_captured: bool,
 }
 
 impl ElementRegistry {
@@ -36,6 +39,7 @@ impl ElementRegistry {
             pixel_density,
 
             anchor_tree: AnchorTree::new(),
+            is_mouse_captured: false,
         }
     }
 
@@ -47,26 +51,31 @@ impl ElementRegistry {
         self.text_elements.reset_event_handlers(input);
         self.rectangle_elements.reset_event_handlers(input);
 
-        self.handle_input_event(InputEvent::Hover, input);
+        let mut is_mouse_captured = self.handle_input_event(InputEvent::Hover, input);
 
         if input.mouse.has_scroll() {
-            self.handle_input_event(InputEvent::Scroll, input);
+            is_mouse_captured |= self.handle_input_event(InputEvent::Scroll, input);
         }
 
         if input.mouse.is_button_down(MouseButton::Left) {
-            self.handle_input_event(InputEvent::MouseLeftDown, input);
+            is_mouse_captured |= self.handle_input_event(InputEvent::MouseLeftDown, input);
 
             if !input.mouse.is_button_up(MouseButton::Left) {
-                self.handle_input_event(InputEvent::MouseLeftDrag, input);
+                is_mouse_captured |= self.handle_input_event(InputEvent::MouseLeftDrag, input);
             }
         } 
         if input.mouse.is_button_up(MouseButton::Left) {
-            self.handle_input_event(InputEvent::MouseLeftUp, input);
+            is_mouse_captured |= self.handle_input_event(InputEvent::MouseLeftUp, input);
         }
+
+        // A drag keeps going when the mouse leaves the element, like when dragging a slider past its end
+        self.is_mouse_captured = is_mouse_captured || self.is_any_element_dragged();
     }
 
-    fn handle_input_event(&mut self, event: InputEvent, input: &Input) {
+    /// Returns true if an element handled the event
+    fn handle_input_event(&mut self, event: InputEvent, input: &Input) -> bool {
         let mouse_position = self.map_mouse_position(input);
+        let mut did_handle = false;
 
         for i in (0..self.ordered_elements.len()).rev() {
             let element_type = self.ordered_elements[i].element_type;
@@ -74,8 +83,13 @@ impl ElementRegistry {
 
             match self.get_mut_ui_element_by_index(element_type, element_index) {
                 Some(element) => {                    
-                    if element.world_data().show && element.world_data().is_within(mouse_position) && element.mut_world_data().event_handlers.register_event(event, input) {
-                        break;
+                    if element.world_data().show && element.world_data().is_within(mouse_position) {
+                        let did_capture = element.mut_world_data().event_handlers.register_event(event, input);
+                        did_handle |= element.world_data().event_handlers.did_handle_event(event);
+
+                        if did_capture {
+                            break;
+                        }
                     }
                 },
                 None => {
@@ -84,6 +98,21 @@ impl ElementRegistry {
                 },
             }
         }
+
+        did_handle
+    }
+
+    fn is_any_element_dragged(&self) -> bool {
+        self.ordered_elements.iter().any(|item| {
+            self.get_ui_element_by_index(item.element_type, item.index)
+                .is_some_and(|element| element.world_data().event_handlers.mouse_left_drag_handler.is_handling())
+        })
+    }
+
+    /// Whether the mouse is over a ui element that handles input, or an element handled a click, scroll or
+    /// drag during the last update. Scenes should then ignore the mouse, see `MouseDevice::is_consumed_by_ui`.
+    pub fn is_mouse_captured(&self) -> bool {
+        self.is_mouse_captured
     }
     
     pub fn set_pixel_density(&mut self, pixel_density: f32) {
@@ -187,7 +216,7 @@ impl ElementRegistry {
         return None
     }
 
-    pub fn create_text(&mut self, text: impl Into<String>, font_id: Option<&ResourceId<Box<dyn Font>>>, text_builder: &TextBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiElementId>, String> {
+    pub fn create_text(&mut self, text: impl Into<String>, font_id: Option<&ResourceId<Box<dyn Font>>>, text_builder: &TextBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiElementId>, LazuliError> {
         let font_id_to_use = match font_id {
             Some(id) => id.duplicate(),
             None => interface::default_font(asset_manager)?,
@@ -218,7 +247,7 @@ impl ElementRegistry {
         id
     }
 
-    pub fn create_rectangle(&mut self, builder: &RectangleBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiElementId>, String> {
+    pub fn create_rectangle(&mut self, builder: &RectangleBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiElementId>, LazuliError> {
         let rectangle_element = builder.build(asset_manager, self)?;
         self.on_create_element(&rectangle_element, asset_manager);
 
@@ -236,9 +265,9 @@ impl ElementRegistry {
         id
     }
 
-    pub fn remove_element(&mut self, element_id: &ResourceId<UiElementId>) -> Result<(), String> {
+    pub fn remove_element(&mut self, element_id: &ResourceId<UiElementId>) -> Result<(), LazuliError> {
         if !self.remove_element_from_element_list(element_id) {
-            return Err(format!("Element not found in element lists"));
+            return Err(UiError::ElementNotFound(element_id.duplicate()).into());
         }
 
         match self.anchor_tree.remove_element_by_id(element_id) {
@@ -248,7 +277,7 @@ impl ElementRegistry {
                 }
             },
             None => {
-                return Err(format!("Element not found in element lists"));
+                return Err(UiError::ElementNotFound(element_id.duplicate()).into());
             }, 
         };
 
@@ -263,9 +292,9 @@ impl ElementRegistry {
     }
 
     /// Recursively remove the element of the anchored element from the element lists
-    fn remove_anchored_element(&mut self, mut anchored_element: AnchoredElement) -> Result<(), String> {
+    fn remove_anchored_element(&mut self, mut anchored_element: AnchoredElement) -> Result<(), LazuliError> {
         if !self.remove_element_from_element_list(&anchored_element.identifier().element_id) {
-            return Err(format!("Element not found in element lists"));
+            return Err(UiError::ElementNotFound(anchored_element.identifier().element_id.duplicate()).into());
         }
 
         for child in anchored_element.take_children() {
@@ -419,58 +448,58 @@ impl ElementRegistry {
         }
     }
 
-    pub fn get_element_scale(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, String> {
+    pub fn get_element_scale(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, LazuliError> {
         match self.get_ui_element_by_id(element_id) {
             Some(element) => Ok(element.world_data().scale()),
-            None => Err(format!("failed to get scale because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn get_mut_element_custom_shader_values(&mut self, element_id: &ResourceId<UiElementId>) -> Result<&mut CustomShaderValues, String> {
+    pub fn get_mut_element_custom_shader_values(&mut self, element_id: &ResourceId<UiElementId>) -> Result<&mut CustomShaderValues, LazuliError> {
         match self.get_mut_ui_element_by_id(element_id) {
             Some(element) => {
                 Ok(element.mut_custom_shader_values())
             },
-            None => Err(format!("element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn set_element_color(&mut self, element_id: &ResourceId<UiElementId>, color: Color) -> Result<(), String> {
+    pub fn set_element_color(&mut self, element_id: &ResourceId<UiElementId>, color: Color) -> Result<(), LazuliError> {
         match self.get_mut_ui_element_by_id(element_id) {
             Some(element) => {
                 Ok(element.set_color(color))
             },
-            None => Err(format!("failed to set element color because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn set_element_z_index(&mut self, element_id: &ResourceId<UiElementId>, z_index: f32) -> Result<(), String> {
+    pub fn set_element_z_index(&mut self, element_id: &ResourceId<UiElementId>, z_index: f32) -> Result<(), LazuliError> {
         match self.get_mut_ui_element_by_id(element_id) {
             Some(element) => {
                 element.mut_world_data().z_index = z_index;
                 self.update_ordered_elements();
                 Ok(())
             },
-            None => Err(format!("failed to set element color because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn set_element_draw_bounds(&mut self, element_id: &ResourceId<UiElementId>, draw_bounds: Bounds2d) -> Result<(), String> {
+    pub fn set_element_draw_bounds(&mut self, element_id: &ResourceId<UiElementId>, draw_bounds: Bounds2d) -> Result<(), LazuliError> {
         match self.get_mut_ui_element_by_id(element_id) {
             Some(element) => {
                 Ok(element.mut_world_data().draw_bounds = draw_bounds)
             },
-            None => Err(format!("failed to set element color because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn set_element_position_transform(&mut self, element_id: &ResourceId<UiElementId>, position_transform: Vec2) -> Result<(), String> {
+    pub fn set_element_position_transform(&mut self, element_id: &ResourceId<UiElementId>, position_transform: Vec2) -> Result<(), LazuliError> {
         match self.get_mut_ui_element_by_id(element_id) {
             Some(element) => {
                 element.mut_world_data().position_transform = position_transform;
                 return self.update_anchor_tree(element_id);
             },
-            None => Err(format!("failed to set element position_transform because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
@@ -483,7 +512,7 @@ impl ElementRegistry {
         }
     }
 
-    pub fn set_element_position(&mut self, element_id: &ResourceId<UiElementId>, position: Position) -> Result<(), String> {
+    pub fn set_element_position(&mut self, element_id: &ResourceId<UiElementId>, position: Position) -> Result<(), LazuliError> {
         self.reposition_anchor_tree_element(element_id, position);
 
         let window_size = self.window_size.clone();
@@ -494,7 +523,7 @@ impl ElementRegistry {
                 element.mut_world_data().set_position(position, window_size, anchor_element_data);
                 return self.update_anchor_tree(element_id);
             },
-            None => Err(format!("failed to set element position because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
@@ -508,7 +537,7 @@ impl ElementRegistry {
         None
     }
 
-    pub fn set_element_scale(&mut self, element_id: &ResourceId<UiElementId>, scale: Vec2) -> Result<(), String> {
+    pub fn set_element_scale(&mut self, element_id: &ResourceId<UiElementId>, scale: Vec2) -> Result<(), LazuliError> {
         let window_size = self.size().clone();
 
         let anchor_element_data = self.get_anchor_element_data(element_id)?;
@@ -518,11 +547,11 @@ impl ElementRegistry {
                 element.mut_world_data().set_scale(scale, window_size, anchor_element_data);
                 return self.update_anchor_tree(element_id);
             },
-            None => Err(format!("failed to set scale because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    fn update_anchor_tree(&mut self, element_id: &ResourceId<UiElementId>) -> Result<(), String> {
+    fn update_anchor_tree(&mut self, element_id: &ResourceId<UiElementId>) -> Result<(), LazuliError> {
         let parent = self.anchor_tree.get_by_id(element_id).unwrap().identifier().clone();
         self.update_anchor_element_position(&parent)?;
 
@@ -534,7 +563,7 @@ impl ElementRegistry {
         Ok(())
     }
 
-    fn update_anchor_element_position(&mut self, anchor_identifier: &AnchorElementIdentifier) -> Result<(), String> {
+    fn update_anchor_element_position(&mut self, anchor_identifier: &AnchorElementIdentifier) -> Result<(), LazuliError> {
         let anchor_element_data = self.get_anchor_element_data(&anchor_identifier.element_id)?;
 
         if anchor_identifier.type_id == TypeId::of::<Rectangle>() {
@@ -548,14 +577,14 @@ impl ElementRegistry {
                 .mut_world_data()
                 .calculate_position(self.window_size.clone(), anchor_element_data);
         } else {
-            return Err(format!("Unhnadled type {:?}", anchor_identifier.type_id));
+            return Err(UiError::UnhandledElementType(anchor_identifier.type_id).into());
         }
 
         Ok(())
     }
 
     /// Get anchor element data of the anchor element of the given element
-    pub fn get_anchor_element_data(&self, element_id: &ResourceId<UiElementId>) -> Result<Option<AnchorElementData>, String> {
+    pub fn get_anchor_element_data(&self, element_id: &ResourceId<UiElementId>) -> Result<Option<AnchorElementData>, LazuliError> {
         match self.get_anchor_element_id(element_id)? {
             Some(anchor_element_id) => {
                 Ok(Some(self.get_anchor_data(&anchor_element_id).unwrap()))
@@ -565,7 +594,7 @@ impl ElementRegistry {
     }
 
     /// Get the anchor element data of the given element
-    pub fn get_anchor_data(&self, anchor_element_id: &ResourceId<UiElementId>) -> Result<AnchorElementData, String> {
+    pub fn get_anchor_data(&self, anchor_element_id: &ResourceId<UiElementId>) -> Result<AnchorElementData, LazuliError> {
         Ok(AnchorElementData{
             id: anchor_element_id.clone(),
             size: self.get_element_size(anchor_element_id)?,
@@ -573,47 +602,47 @@ impl ElementRegistry {
         })
     }
 
-    pub fn get_anchor_element_id(&self, element_id: &ResourceId<UiElementId>) -> Result<Option<ResourceId<UiElementId>>, String> {
+    pub fn get_anchor_element_id(&self, element_id: &ResourceId<UiElementId>) -> Result<Option<ResourceId<UiElementId>>, LazuliError> {
         match self.get_ui_element_by_id(element_id) {
             Some(element) => {
                 Ok(element.world_data().position_type().get_anchor_element_id())
             },
-            None => Err(format!("failed to get anchor element id because element with id {} was not found", element_id.id())),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
     /// get the base size of the element, not counting it's scale
-    pub fn get_element_base_size(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, String> {
+    pub fn get_element_base_size(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, LazuliError> {
         match self.get_ui_element_by_id(element_id) {
             Some(element) => Ok(element.world_data().size()),
-            None => Err(format!("failed to get size because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
     /// get the base size of the element multiplied by its scale
-    pub fn get_element_size(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, String> {        
+    pub fn get_element_size(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, LazuliError> {        
         match self.get_ui_element_by_id(element_id) {
             Some(element) => Ok(element.world_data().size() * element.world_data().scale()),
-            None => Err(format!("failed to get size because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
     /// Get the position of the element as the center pixel (in world space)
-    pub fn get_element_screen_position(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, String> {
+    pub fn get_element_screen_position(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, LazuliError> {
         match self.get_ui_element_by_id(element_id) {
             Some(element) => Ok(element.world_data().position()),
-            None => Err(format!("failed to get size because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn get_element_position_transform(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, String> {
+    pub fn get_element_position_transform(&self, element_id: &ResourceId<UiElementId>) -> Result<Vec2, LazuliError> {
         match self.get_ui_element_by_id(element_id) {
             Some(element) => Ok(element.world_data().position_transform),
-            None => Err(format!("failed to get position transform because element with id {:?} was not found", element_id.id())),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
-    pub fn set_text(&mut self, text_element_id: &ResourceId<UiElementId>, text: &String, asset_manager: &mut dyn AssetManager) -> Result<(), String> {
+    pub fn set_text(&mut self, text_element_id: &ResourceId<UiElementId>, text: &String, asset_manager: &mut dyn AssetManager) -> Result<(), LazuliError> {
         let window_size: Vec2 = self.window_size.clone();
         let anchor_data = self.get_anchor_element_data(text_element_id)?;
 
@@ -622,11 +651,11 @@ impl ElementRegistry {
                 text_element.set_text(text, window_size, anchor_data, asset_manager)?;
                 return self.update_anchor_tree(text_element_id);
             },
-            None => Err(format!("failed to set text because text element with id {:?} was not found", text_element_id)),
+            None => Err(UiError::ElementNotFound(text_element_id.duplicate()).into()),
         }
     }
 
-    pub fn set_rectangle_width(&mut self, rectangle_id: &ResourceId<UiElementId>, new_width: f32) -> Result<(), String> {
+    pub fn set_rectangle_width(&mut self, rectangle_id: &ResourceId<UiElementId>, new_width: f32) -> Result<(), LazuliError> {
         let window_size: Vec2 = self.window_size.clone();
         let anchor_data = self.get_anchor_element_data(rectangle_id)?;
 
@@ -635,10 +664,10 @@ impl ElementRegistry {
                 rectangle.set_width(new_width, window_size, anchor_data);
                 return self.update_anchor_tree(rectangle_id);
             },
-            None => Err(format!("failed to set rectangle width because rectanglei with id {:?} was not found", rectangle_id)),
+            None => Err(UiError::ElementNotFound(rectangle_id.duplicate()).into()),
         }
     }
-    pub fn set_rectangle_height(&mut self, rectangle_id: &ResourceId<UiElementId>, new_height: f32) -> Result<(), String> {
+    pub fn set_rectangle_height(&mut self, rectangle_id: &ResourceId<UiElementId>, new_height: f32) -> Result<(), LazuliError> {
         let window_size: Vec2 = self.window_size.clone();
         let anchor_data = self.get_anchor_element_data(rectangle_id)?;
 
@@ -647,10 +676,10 @@ impl ElementRegistry {
                 rectangle.set_height(new_height, window_size, anchor_data);
                 return self.update_anchor_tree(rectangle_id);
             },
-            None => Err(format!("failed to set rectangle height because rectanglei with id {:?} was not found", rectangle_id)),
+            None => Err(UiError::ElementNotFound(rectangle_id.duplicate()).into()),
         }
     }
-    pub fn set_rectangle_size(&mut self, rectangle_id: &ResourceId<UiElementId>, new_size: Vec2) -> Result<(), String> {
+    pub fn set_rectangle_size(&mut self, rectangle_id: &ResourceId<UiElementId>, new_size: Vec2) -> Result<(), LazuliError> {
         let window_size: Vec2 = self.window_size.clone();
         let anchor_data = self.get_anchor_element_data(rectangle_id)?;
 
@@ -659,7 +688,7 @@ impl ElementRegistry {
                 rectangle.set_size(new_size, window_size, anchor_data);
                 return self.update_anchor_tree(rectangle_id);
             },
-            None => Err(format!("failed to set rectangle size because rectanglei with id {:?} was not found", rectangle_id)),
+            None => Err(UiError::ElementNotFound(rectangle_id.duplicate()).into()),
         }
     }
     pub fn get_rectangle_border(&mut self, rectangle_id: &ResourceId<UiElementId>) -> &super::super::shapes::RectangleBorder {
@@ -668,28 +697,28 @@ impl ElementRegistry {
     pub fn get_mut_rectangle_border(&mut self, rectangle_id: &ResourceId<UiElementId>) -> &mut super::super::shapes::RectangleBorder {
         self.rectangle_elements.get_mut_by_id(rectangle_id).unwrap().get_mut_border()
     }
-    pub fn set_rectangle_texture_padding(&mut self, rectangle_id: &ResourceId<UiElementId>, texture_padding: f32) -> Result<(), String> {
+    pub fn set_rectangle_texture_padding(&mut self, rectangle_id: &ResourceId<UiElementId>, texture_padding: f32) -> Result<(), LazuliError> {
         match self.rectangle_elements.get_mut_by_id(rectangle_id) {
             Some(rectangle) => { Ok(rectangle.set_texture_padding(texture_padding)) },
-            None => Err(format!("failed to set rectangle texture padding because rectanglei with id {:?} was not found", rectangle_id)),
+            None => Err(UiError::ElementNotFound(rectangle_id.duplicate()).into()),
         }
     }
 
-    pub fn set_element_visibility(&mut self, element_id: &ResourceId<UiElementId>, visible: bool) -> Result<(), String> {
+    pub fn set_element_visibility(&mut self, element_id: &ResourceId<UiElementId>, visible: bool) -> Result<(), LazuliError> {
         match self.get_mut_ui_element_by_id(element_id) {
             Some(element) => {
                 Ok(element.mut_world_data().show = visible)
             },
-            None => Err(format!("failed to set element visibility because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
     
-    pub fn element_world_data(&self, element_id: &ResourceId<UiElementId>) -> Result<&WorldElementData, String> {
+    pub fn element_world_data(&self, element_id: &ResourceId<UiElementId>) -> Result<&WorldElementData, LazuliError> {
         match self.get_ui_element_by_id(element_id) {
             Some(element) => {
                 Ok(element.world_data())
             },
-            None => Err(format!("failed to check if element is shown because element with id {:?} was not found", element_id)),
+            None => Err(UiError::ElementNotFound(element_id.duplicate()).into()),
         }
     }
 
@@ -707,6 +736,7 @@ impl ElementRegistry {
     }
 
     pub fn size(&self) -> &Vec2 { &self.window_size }
+    pub fn pixel_density(&self) -> f32 { self.pixel_density }
     pub fn width(&self) -> f32 { self.window_size.x }
     pub fn height(&self) -> f32 { self.window_size.y }
 }
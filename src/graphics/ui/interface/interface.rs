@@ -1,6 +1,8 @@
+use std::{cell::Cell, rc::Rc};
+
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, event::{EventReader, EventSystem, PixelDensityChangeEvent, WindowResizeEvent}, graphics::{font::{Font, PlainBitmapBuilder}, ui::{bounds_2d::Bounds2d, layout::LayoutBuilder, widget::{ButtonBuilder, DropdownBuilder, IconBuilder, SliderBuilder, SliderUpdateResult}, LayoutUpdateTarget, Position, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, input::Input, log, ResourceId};
+use crate::{error::{LazuliError, UiError}, asset_manager::AssetManager, event::{ButtonClicked, DropdownSelected, EventPropagation, EventSystem, PixelDensityChangeEvent, SliderChanged, WidgetHoverEnter, WidgetHoverLeave, WindowResizeEvent}, graphics::{font::{Font, PlainBitmapBuilder}, ui::{bounds_2d::Bounds2d, layout::LayoutBuilder, widget::{ButtonBuilder, DropdownBuilder, IconBuilder, SliderBuilder, SliderUpdateResult}, LayoutUpdateTarget, Position, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, input::{ButtonAction, Input, MouseButton}, log, ResourceId};
 
 use super::{ElementRegistry, widget_registry::{WidgetRegistry, WidgetRegistryUdpateResult}, layout_registry::LayoutRegistry};
 
@@ -11,44 +13,67 @@ pub struct Interface {
 
     size: Vec2,
     scroll_speed: f32,
+    keyboard_focus: Option<ResourceId<UiWidgetId>>,
+    /// Widgets that take keyboard focus when they get clicked
+    focusable_widgets: Vec<ResourceId<UiWidgetId>>,
 
-    window_resize_listener: EventReader<WindowResizeEvent>,
-    pixel_density_change_listener: EventReader<PixelDensityChangeEvent>,
+    /// The last window size and pixel density that were sent, set by subscriptions and handled during the next update
+    pending_window_size: Rc<Cell<Option<Vec2>>>,
+    pending_pixel_density: Rc<Cell<Option<f32>>>,
 }
 
 impl Interface {
     pub fn new(event_system: &mut EventSystem, window_size: Vec2, pixel_density: f32) -> Self {
+        let pending_window_size = Rc::new(Cell::new(None));
+        let pending_window_size_setter = pending_window_size.clone();
+        event_system.subscribe(move |event: &WindowResizeEvent| {
+            pending_window_size_setter.set(Some(Vec2::new(event.width as f32, event.height as f32)));
+            EventPropagation::Continue
+        });
+
+        let pending_pixel_density = Rc::new(Cell::new(None));
+        let pending_pixel_density_setter = pending_pixel_density.clone();
+        event_system.subscribe(move |event: &PixelDensityChangeEvent| {
+            pending_pixel_density_setter.set(Some(event.pixel_density));
+            EventPropagation::Continue
+        });
+
         Self {
             element_registry: ElementRegistry::new(window_size, pixel_density),
             widget_registry: WidgetRegistry::new(),
             layout_registry: LayoutRegistry::new(),
             size: window_size,
-            scroll_speed: 0.2,
+            scroll_speed: 1.0,
+            keyboard_focus: None,
+            focusable_widgets: vec![],
 
-            window_resize_listener: event_system.register(),
-            pixel_density_change_listener: event_system.register(),
+            pending_window_size,
+            pending_pixel_density,
         }
     }
 
-    pub fn update(&mut self, asset_manager: &mut dyn AssetManager, input: &Input) {
+    /// Also marks the mouse and keyboard input the interface used as consumed, see `MouseDevice::is_consumed_by_ui`
+    /// and `KeyboardDevice::is_consumed_by_ui`
+    pub fn update(&mut self, event_system: &mut EventSystem, asset_manager: &mut dyn AssetManager, input: &mut Input) {
         // We update widget_registry before element_registry so that we won't activate any mouse_up
         // events while we were still dragging an element (which gets reset by element_registry.update)
         let widget_registry_update_result = &self.widget_registry.update(input, &mut self.element_registry, asset_manager);
         self.handle_widget_registry_update_result(&widget_registry_update_result, asset_manager);
+        self.send_widget_events(widget_registry_update_result, event_system);
         self.element_registry.update(asset_manager, input);
+        self.update_input_consumption(input);
         
-        self.window_resize_listener.read().last().map(|e| {
-            let window_size = Vec2::new(e.width as f32, e.height as f32);
+        if let Some(window_size) = self.pending_window_size.take() {
             self.element_registry.handle_window_resize(window_size, asset_manager);
 
             for update_target_collection in self.layout_registry.handle_window_resize(&self.element_registry) {
                 self.handle_ui_update_targets_collection(update_target_collection);
             }
-        });
+        }
 
-        self.pixel_density_change_listener.read().last().map(|e| {
-            self.element_registry.set_pixel_density(e.pixel_density);
-        });
+        if let Some(pixel_density) = self.pending_pixel_density.take() {
+            self.element_registry.set_pixel_density(pixel_density);
+        }
 
         for update_target_collection in self.layout_registry.update(&mut self.element_registry, &mut self.widget_registry, input, self.scroll_speed) {
             self.handle_ui_update_targets_collection(update_target_collection);
@@ -78,6 +103,63 @@ impl Interface {
         }
     }
 
+    fn send_widget_events(&self, update_result: &WidgetRegistryUdpateResult, event_system: &mut EventSystem) {
+        for widget_id in &update_result.hover_left {
+            event_system.send(WidgetHoverLeave { widget_id: *widget_id });
+        }
+        for widget_id in &update_result.hover_entered {
+            event_system.send(WidgetHoverEnter { widget_id: *widget_id });
+        }
+
+        for widget_id in self.widget_registry.clicked_buttons() {
+            event_system.send(ButtonClicked { widget_id });
+        }
+        for (widget_id, result) in self.widget_registry.updated_sliders() {
+            if result.change_amount != 0.0 {
+                event_system.send(SliderChanged { widget_id, new_value: result.new_value, change_amount: result.change_amount });
+            }
+        }
+        for (widget_id, value) in self.widget_registry.updated_dropdowns() {
+            event_system.send(DropdownSelected { widget_id, value });
+        }
+    }
+
+    fn update_input_consumption(&mut self, input: &mut Input) {
+        self.update_keyboard_focus(input);
+
+        input.mouse.set_consumed_by_ui(self.element_registry.is_mouse_captured());
+        input.keyboard.set_consumed_by_ui(self.keyboard_focus.is_some());
+    }
+
+    fn update_keyboard_focus(&mut self, input: &Input) {
+        let widget_registry = &self.widget_registry;
+        self.focusable_widgets.retain(|widget_id| widget_registry.get_widget_main_element_id(widget_id).is_some());
+
+        // The focused widget loses the focus when it gets removed or hidden
+        if self.keyboard_focus.is_some_and(|widget_id| !self.is_widget_shown(&widget_id)) {
+            self.keyboard_focus = None;
+        }
+
+        // A click moves the focus to the clicked widget if it is focusable, and clears it otherwise
+        if input.mouse.is_button_down(MouseButton::Left) {
+            self.keyboard_focus = self.focusable_widgets.iter()
+                .chain(self.keyboard_focus.iter())
+                .find(|widget_id| self.is_widget_clicked(widget_id))
+                .copied();
+        }
+    }
+
+    fn is_widget_shown(&self, widget_id: &ResourceId<UiWidgetId>) -> bool {
+        self.widget_registry.get_widget_main_element_id(widget_id)
+            .and_then(|element_id| self.element_registry.get_ui_element_by_id(&element_id))
+            .is_some_and(|element| element.world_data().show)
+    }
+
+    fn is_widget_clicked(&self, widget_id: &ResourceId<UiWidgetId>) -> bool {
+        self.widget_registry.get_widget_main_element_id(widget_id)
+            .is_some_and(|element_id| self.element_registry.is_element_clicked(&element_id, MouseButton::Left, &ButtonAction::Down))
+    }
+
     pub fn draw(&self, asset_manager: &mut dyn AssetManager) {
         self.element_registry.draw(asset_manager);
     }
@@ -90,13 +172,13 @@ impl Interface {
     pub fn get_widget_main_element_id(&self, widget_id: &ResourceId<UiWidgetId>) -> Option<ResourceId<UiElementId>> {
         self.widget_registry.get_widget_main_element_id(widget_id)
     }
-    pub fn get_widget_size(&self, widget_id: &ResourceId<UiWidgetId>) -> Result<Vec2, String> {
+    pub fn get_widget_size(&self, widget_id: &ResourceId<UiWidgetId>) -> Result<Vec2, LazuliError> {
         self.widget_registry.get_widget_size(widget_id, &self.element_registry)
     }
-    pub fn get_widget_screen_position(&self, widget_id: &ResourceId<UiWidgetId>) -> Result<Vec2, String> {
+    pub fn get_widget_screen_position(&self, widget_id: &ResourceId<UiWidgetId>) -> Result<Vec2, LazuliError> {
         self.widget_registry.get_widget_screen_position(widget_id, &self.element_registry)
     }
-    pub fn get_widget_position_transform(&self, widget_id: &ResourceId<UiWidgetId>) -> Result<Vec2, String> {
+    pub fn get_widget_position_transform(&self, widget_id: &ResourceId<UiWidgetId>) -> Result<Vec2, LazuliError> {
         self.widget_registry.get_widget_position_transform(widget_id, &self.element_registry)
     }
     pub fn set_widget_position(&mut self, widget_id: &ResourceId<UiWidgetId>, position: Position) {
@@ -120,23 +202,51 @@ impl Interface {
     pub fn set_widget_visibility(&mut self, widget_id: &ResourceId<UiWidgetId>, visible: bool) {
         self.handle_ui_update_targets_visibility(UiUpdateTargets::from_widget_id(widget_id.clone(), visible));
     }
+    /// Gives the widget keyboard focus, so scenes see the keyboard as consumed until the focus gets cleared,
+    /// the player clicks somewhere else or the widget gets hidden or removed
+    pub fn set_keyboard_focus(&mut self, widget_id: &ResourceId<UiWidgetId>) {
+        self.keyboard_focus = Some(*widget_id);
+    }
+    /// Focusable widgets take keyboard focus when their main element gets clicked, for example a widget that
+    /// handles text input
+    pub fn set_widget_focusable(&mut self, widget_id: &ResourceId<UiWidgetId>, focusable: bool) {
+        self.focusable_widgets.retain(|id| !id.equals(widget_id));
+        if focusable {
+            self.focusable_widgets.push(*widget_id);
+        }
+    }
+    pub fn is_widget_focusable(&self, widget_id: &ResourceId<UiWidgetId>) -> bool {
+        self.focusable_widgets.iter().any(|id| id.equals(widget_id))
+    }
+    pub fn clear_keyboard_focus(&mut self) {
+        self.keyboard_focus = None;
+    }
+    pub fn keyboard_focus(&self) -> Option<ResourceId<UiWidgetId>> {
+        self.keyboard_focus
+    }
+    pub fn is_mouse_captured(&self) -> bool {
+        self.element_registry.is_mouse_captured()
+    }
+    pub fn is_widget_hovered(&self, widget_id: &ResourceId<UiWidgetId>) -> bool {
+        self.widget_registry.is_widget_hovered(widget_id)
+    }
 
     // button specific functions
-    pub fn create_button(&mut self, label: impl Into<String>, builder: &ButtonBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_button(&mut self, label: impl Into<String>, builder: &ButtonBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         self.widget_registry.create_button(label, builder, &mut self.element_registry, asset_manager)
     }
     pub fn is_button_clicked(&self, button_id: &ResourceId<UiWidgetId>) -> bool {
         self.widget_registry.is_button_clicked(button_id)
     }
-    pub fn set_button_background_color(&mut self, color: Color, button_id: &ResourceId<UiWidgetId>) -> Result<(), String> {
+    pub fn set_button_background_color(&mut self, color: Color, button_id: &ResourceId<UiWidgetId>) -> Result<(), LazuliError> {
         self.widget_registry.set_button_background_color(color, button_id, &mut self.element_registry)
     }
-    pub fn set_button_text_color(&mut self, color: Color, button_id: &ResourceId<UiWidgetId>) -> Result<(), String> {
+    pub fn set_button_text_color(&mut self, color: Color, button_id: &ResourceId<UiWidgetId>) -> Result<(), LazuliError> {
         self.widget_registry.set_button_text_color(color, button_id, &mut self.element_registry)
     }
 
     // slider specific functions
-    pub fn create_slider(&mut self, builder: &SliderBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_slider(&mut self, builder: &SliderBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         self.widget_registry.create_slider(builder, &mut self.element_registry, asset_manager)
     }
     pub fn slider_update_result(&self, slider_id: &ResourceId<UiWidgetId>) -> Option<SliderUpdateResult> {
@@ -147,7 +257,7 @@ impl Interface {
     }
 
     // dropdown specific functions
-    pub fn create_dropdown(&mut self, builder: &DropdownBuilder<u32>, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_dropdown(&mut self, builder: &DropdownBuilder<u32>, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         let (id, update_target_collections) = self.widget_registry.create_dropdown(builder, &mut self.element_registry, &mut self.layout_registry, asset_manager)?;
         for update_target_collection in update_target_collections {
             self.handle_ui_update_targets_collection(update_target_collection);
@@ -159,27 +269,27 @@ impl Interface {
     }
 
     // icon specific functions
-    pub fn create_icon(&mut self, builder: &IconBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_icon(&mut self, builder: &IconBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         self.widget_registry.create_icon(builder, &mut self.element_registry, asset_manager)
     }
-    pub fn set_icon_padding(&mut self, padding: f32, icon_id: &ResourceId<UiWidgetId>) -> Result<(), String> {
+    pub fn set_icon_padding(&mut self, padding: f32, icon_id: &ResourceId<UiWidgetId>) -> Result<(), LazuliError> {
         match self.widget_registry.get_widget_by_id(&icon_id) {
             Some(ui_widget) => self.element_registry.set_rectangle_texture_padding(
                 &ui_widget.get_main_element_id(), 
                 padding,
             ),
-            None => Err(format!("Icon with id {:?} not found", icon_id)),
+            None => Err(UiError::WidgetNotFound(icon_id.duplicate()).into()),
         }
     }
 
     // layout specific functions
-    pub fn create_layout(&mut self, builder: &mut impl LayoutBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiLayoutId>, String> {
+    pub fn create_layout(&mut self, builder: &mut impl LayoutBuilder, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiLayoutId>, LazuliError> {
         let (id, update_targets) = self.layout_registry.create_layout(builder, &mut self.element_registry, &mut self.widget_registry, asset_manager)?;
         self.handle_ui_update_targets_collection(update_targets);
         self.layout_registry.get_mut_layout(&id).unwrap().update_max_scroll(&mut self.element_registry, &self.widget_registry);
         Ok(id)
     }
-    pub fn add_widget_to_layout(&mut self, widget_id: &ResourceId<UiWidgetId>, layout_id: &ResourceId<UiLayoutId>) -> Result<(), String> {
+    pub fn add_widget_to_layout(&mut self, widget_id: &ResourceId<UiWidgetId>, layout_id: &ResourceId<UiLayoutId>) -> Result<(), LazuliError> {
         let update_targets = self.layout_registry.add_widget_to_layout(widget_id, layout_id, &mut self.element_registry, &mut self.widget_registry)?;
         self.handle_ui_update_targets_collection(update_targets);
         self.layout_registry.get_mut_layout(&layout_id).unwrap().update_max_scroll(&mut self.element_registry, &self.widget_registry);
@@ -305,10 +415,10 @@ impl Interface {
     }
 
     // methods for removal of widgets and layouts
-    pub fn remove_widget(&mut self, widget_id: &ResourceId<UiWidgetId>) -> Result<(), String> {
+    pub fn remove_widget(&mut self, widget_id: &ResourceId<UiWidgetId>) -> Result<(), LazuliError> {
         let widget = match self.widget_registry.remove_widget(widget_id) {
             Some(widget) => widget,
-            None => return Err(format!("widget with id {} not found", widget_id.id())),
+            None => return Err(UiError::WidgetNotFound(widget_id.duplicate()).into()),
         };
 
         let mut ui_elements_to_remove = widget.get_direct_element_ids();
@@ -324,10 +434,10 @@ impl Interface {
 
         Ok(())
     }
-    pub fn remove_layout(&mut self, layout_id: &ResourceId<UiLayoutId>) -> Result<(), String> {
+    pub fn remove_layout(&mut self, layout_id: &ResourceId<UiLayoutId>) -> Result<(), LazuliError> {
         let layout = match self.layout_registry.remove_layout(layout_id) {
             Some(layout) => layout,
-            None => return Err(format!("layout with id {} not found", layout_id.id())),
+            None => return Err(UiError::LayoutNotFound(layout_id.duplicate()).into()),
         };
 
         let mut ui_elements_to_remove = layout.get_direct_element_ids();
@@ -388,7 +498,7 @@ pub fn default_element_background_color() -> Color { Color::Rgb(56, 56, 56) }
 pub fn default_font_size() -> f32 {
     14.0
 }
-pub fn default_font(asset_manager: &mut dyn AssetManager) -> Result<ResourceId<Box<dyn Font>>, String> {
+pub fn default_font(asset_manager: &mut dyn AssetManager) -> Result<ResourceId<Box<dyn Font>>, LazuliError> {
     let bitmap_builder = PlainBitmapBuilder::new()
         .with_font_file_path("./assets/fonts/roboto.ttf".to_string())
         .with_font_size(50.0)
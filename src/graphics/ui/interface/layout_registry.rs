@@ -1,4 +1,4 @@
-use crate::{asset_manager::AssetManager, graphics::ui::{layout::LayoutBuilder, Layout, Position, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, input::Input, ResourceId};
+use crate::{error::{LazuliError, UiError}, asset_manager::AssetManager, graphics::ui::{layout::LayoutBuilder, Layout, Position, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, input::Input, ResourceId};
 
 use super::{WidgetRegistry, ElementRegistry, element_list::generate_id};
 
@@ -40,7 +40,7 @@ impl LayoutRegistry {
         element_registry: &mut ElementRegistry, 
         widget_registry: &mut WidgetRegistry, 
         asset_manager: &mut dyn AssetManager
-    ) -> Result<(ResourceId<UiLayoutId>, UpdateTargetCollection), String> {
+    ) -> Result<(ResourceId<UiLayoutId>, UpdateTargetCollection), LazuliError> {
         let (layout, update_targets) = builder.build(element_registry, widget_registry, asset_manager)?;
         let layout_id = self.add_layout(layout);
 
@@ -68,7 +68,7 @@ impl LayoutRegistry {
         layout_id: &ResourceId<UiLayoutId>, 
         element_registry: &mut ElementRegistry, 
         widget_registry: &mut WidgetRegistry
-    ) -> Result<UpdateTargetCollection, String> {
+    ) -> Result<UpdateTargetCollection, LazuliError> {
         match self.get_mut_layout(layout_id) {
             Some(layout) => Ok(layout.add_widget(widget_id, element_registry, widget_registry)),
             None => Err(Self::layout_not_found(layout_id)),
@@ -79,7 +79,7 @@ impl LayoutRegistry {
         layout_id: &ResourceId<UiLayoutId>, 
         z_index: f32, 
         element_registry: &mut ElementRegistry, 
-    ) -> Result<UiUpdateTargets<f32>, String> {
+    ) -> Result<UiUpdateTargets<f32>, LazuliError> {
         match self.get_mut_layout(layout_id) {
             Some(layout) => Ok(layout.set_z_index(z_index, element_registry)),
             None => Err(Self::layout_not_found(layout_id)),
@@ -90,7 +90,7 @@ impl LayoutRegistry {
         layout_id: &ResourceId<UiLayoutId>, 
         width: f32, 
         element_registry: &mut ElementRegistry, 
-    ) -> Result<UpdateTargetCollection, String> {
+    ) -> Result<UpdateTargetCollection, LazuliError> {
         match self.get_mut_layout(layout_id) {
             Some(layout) => Ok(layout.set_width(width, element_registry)),
             None => Err(Self::layout_not_found(layout_id)),
@@ -101,7 +101,7 @@ impl LayoutRegistry {
         layout_id: &ResourceId<UiLayoutId>, 
         visible: bool, 
         element_registry: &mut ElementRegistry, 
-    ) -> Result<UiUpdateTargets<bool>, String> {
+    ) -> Result<UiUpdateTargets<bool>, LazuliError> {
         match self.get_mut_layout(layout_id) {
             Some(layout) => Ok(layout.set_visibility(visible, element_registry)),
             None => Err(Self::layout_not_found(layout_id)),
@@ -112,15 +112,15 @@ impl LayoutRegistry {
         layout_id: &ResourceId<UiLayoutId>, 
         position: Position, 
         element_registry: &mut ElementRegistry, 
-    ) -> Result<UpdateTargetCollection, String> {
+    ) -> Result<UpdateTargetCollection, LazuliError> {
         match self.get_mut_layout(layout_id) {
             Some(layout) => Ok(layout.set_position(position, element_registry)),
             None => Err(Self::layout_not_found(layout_id)),
         }
     }
 
-    fn layout_not_found(layout_id: &ResourceId<UiLayoutId>) -> String {
-        format!("Layout with id {:?} not found", layout_id)
+    fn layout_not_found(layout_id: &ResourceId<UiLayoutId>) -> LazuliError {
+        UiError::LayoutNotFound(layout_id.duplicate()).into()
     }
 
     pub fn get_layout(&mut self, layout_id: &ResourceId<UiLayoutId>) -> Option<&Box<dyn Layout>> {
@@ -156,7 +156,7 @@ impl LayoutRegistry {
     pub fn update_layout_draw_bounds(&mut self, 
         layout_id: &ResourceId<UiLayoutId>, 
         element_registry: &ElementRegistry
-    ) -> Result<UpdateTargetCollection, String> {
+    ) -> Result<UpdateTargetCollection, LazuliError> {
         match self.get_mut_layout(layout_id) {
             Some(layout) => Ok(layout.update_draw_bounds(element_registry)),
             None => Err(Self::layout_not_found(layout_id)),
@@ -23,3 +23,5 @@ mod anchor_tree_test;
 mod widget_list_test;
 #[cfg(test)]
 mod layout_registry_test;
+#[cfg(test)]
+mod widget_registry_test;
@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{graphics::{ui::{widget::{Slider, SliderBuilder, SliderUpdateResult, Button, ButtonBuilder, UiWidget, Dropdown, DropdownBuilder, Icon, IconBuilder}, UiWidgetId, bounds_2d::Bounds2d, Position, UiElementId, UiUpdateTargets, WidgetUpdateTarget, UpdateTargetCollection}, Color}, asset_manager::AssetManager, input::Input, log, ResourceId};
+use crate::{error::{LazuliError, UiError}, graphics::{ui::{widget::{Slider, SliderBuilder, SliderUpdateResult, Button, ButtonBuilder, UiWidget, Dropdown, DropdownBuilder, Icon, IconBuilder}, UiWidgetId, bounds_2d::Bounds2d, Position, UiElementId, UiUpdateTargets, WidgetUpdateTarget, UpdateTargetCollection}, Color}, asset_manager::AssetManager, input::Input, log, ResourceId};
 
 use super::{ElementRegistry, widget_list::WidgetList, LayoutRegistry};
 
@@ -8,6 +8,10 @@ pub struct WidgetRegistryUdpateResult {
     pub update_targets_visibility: UiUpdateTargets<bool>,
     pub buttons_to_change_text: Vec<WidgetUpdateTarget<String>>,
     pub widgets_to_set_main_element_custom_shader_value_f32: Vec<WidgetUpdateTarget<(String, f32)>>,
+    /// Widgets that started being hovered during this update
+    pub hover_entered: Vec<ResourceId<UiWidgetId>>,
+    /// Widgets that stopped being hovered during this update
+    pub hover_left: Vec<ResourceId<UiWidgetId>>,
 }
 impl Default for WidgetRegistryUdpateResult {
     fn default() -> Self {
@@ -15,6 +19,8 @@ impl Default for WidgetRegistryUdpateResult {
             update_targets_visibility: Default::default(), 
             buttons_to_change_text: vec![],
             widgets_to_set_main_element_custom_shader_value_f32: vec![],
+            hover_entered: vec![],
+            hover_left: vec![],
         }
     }
 }
@@ -24,6 +30,8 @@ pub struct WidgetRegistry {
     sliders: WidgetList<Slider, Option<SliderUpdateResult>>,
     dropdowns: WidgetList<Dropdown<u32>, Option<u32>>, // TODO support more types than Dropdown<u32>
     icons: WidgetList<Icon, ()>,
+
+    hovered_widgets: Vec<ResourceId<UiWidgetId>>,
 }
 
 impl WidgetRegistry {
@@ -33,6 +41,7 @@ impl WidgetRegistry {
             sliders: WidgetList::new(None),
             dropdowns: WidgetList::new(None),
             icons: WidgetList::new(()),
+            hovered_widgets: vec![],
         }
     }
 
@@ -62,6 +71,23 @@ impl WidgetRegistry {
             entry.update_result = entry.widget.update(&clicked_button_id, &mut result);
         }
 
+        // update hover state
+        let hovered_widgets: Vec<ResourceId<UiWidgetId>> = self.buttons.entries.iter()
+            .filter(|entry| entry.widget.is_hovered(element_registry))
+            .map(|entry| entry.id)
+            .chain(self.sliders.entries.iter()
+                .filter(|entry| entry.widget.is_hovered(element_registry))
+                .map(|entry| entry.id))
+            .chain(self.dropdowns.entries.iter()
+                .filter(|entry| entry.widget.is_hovered(element_registry))
+                .map(|entry| entry.id))
+            .chain(self.icons.entries.iter()
+                .filter(|entry| entry.widget.is_hovered(element_registry))
+                .map(|entry| entry.id))
+            .collect();
+        (result.hover_entered, result.hover_left) = hover_changes(&self.hovered_widgets, &hovered_widgets);
+        self.hovered_widgets = hovered_widgets;
+
         result
     }
 
@@ -71,15 +97,15 @@ impl WidgetRegistry {
             None => None,
         }
     }
-    pub fn get_widget_size(&self, widget_id: &ResourceId<UiWidgetId>, element_registry: &ElementRegistry) -> Result<Vec2, String> {
+    pub fn get_widget_size(&self, widget_id: &ResourceId<UiWidgetId>, element_registry: &ElementRegistry) -> Result<Vec2, LazuliError> {
         let main_element_id = self.get_widget_main_element_id(widget_id).unwrap();
         element_registry.get_element_size(&main_element_id)
     }
-    pub fn get_widget_screen_position(&self, widget_id: &ResourceId<UiWidgetId>, element_registry: &ElementRegistry) -> Result<Vec2, String> {
+    pub fn get_widget_screen_position(&self, widget_id: &ResourceId<UiWidgetId>, element_registry: &ElementRegistry) -> Result<Vec2, LazuliError> {
         let main_element_id = self.get_widget_main_element_id(widget_id).unwrap();
         element_registry.get_element_screen_position(&main_element_id)
     }
-    pub fn get_widget_position_transform(&self, widget_id: &ResourceId<UiWidgetId>, element_registry: &ElementRegistry) -> Result<Vec2, String> {
+    pub fn get_widget_position_transform(&self, widget_id: &ResourceId<UiWidgetId>, element_registry: &ElementRegistry) -> Result<Vec2, LazuliError> {
         let main_element_id = self.get_widget_main_element_id(widget_id).unwrap();
         element_registry.get_element_position_transform(&main_element_id)
     }
@@ -117,7 +143,7 @@ impl WidgetRegistry {
     pub fn add_slider(&mut self, slider: Slider) -> ResourceId<UiWidgetId> {
         self.sliders.push(slider)
     }
-    pub fn create_slider(&mut self, builder: &SliderBuilder, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_slider(&mut self, builder: &SliderBuilder, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         let slider = builder.build(element_registry, asset_manager)?;
         Ok(self.sliders.push(slider))
     }
@@ -125,7 +151,7 @@ impl WidgetRegistry {
     pub fn add_button(&mut self, button: Button) -> ResourceId<UiWidgetId> {
         self.buttons.push(button)
     }
-    pub fn create_button(&mut self, label: impl Into<String>, builder: &ButtonBuilder, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_button(&mut self, label: impl Into<String>, builder: &ButtonBuilder, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         let button = builder.build(label, element_registry, asset_manager)?;
         Ok(self.buttons.push(button))
     }
@@ -133,7 +159,7 @@ impl WidgetRegistry {
     pub fn add_dropdown(&mut self, dropdown: Dropdown<u32>) -> ResourceId<UiWidgetId> {
         self.dropdowns.push(dropdown)
     }
-    pub fn create_dropdown(&mut self, builder: &DropdownBuilder<u32>, element_registry: &mut ElementRegistry, layout_registry: &mut LayoutRegistry, asset_manager: &mut dyn AssetManager) -> Result<(ResourceId<UiWidgetId>, Vec<UpdateTargetCollection>), String> {
+    pub fn create_dropdown(&mut self, builder: &DropdownBuilder<u32>, element_registry: &mut ElementRegistry, layout_registry: &mut LayoutRegistry, asset_manager: &mut dyn AssetManager) -> Result<(ResourceId<UiWidgetId>, Vec<UpdateTargetCollection>), LazuliError> {
         let (dropdown, update_targets) = builder.build(element_registry, self, layout_registry, asset_manager)?;
         let id = self.dropdowns.push(dropdown);
 
@@ -143,7 +169,7 @@ impl WidgetRegistry {
     pub fn add_icon(&mut self, icon: Icon) -> ResourceId<UiWidgetId> {
         self.icons.push(icon)
     }
-    pub fn create_icon(&mut self, builder: &IconBuilder, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, String> {
+    pub fn create_icon(&mut self, builder: &IconBuilder, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<ResourceId<UiWidgetId>, LazuliError> {
         let icon = builder.build(element_registry, asset_manager)?;
         Ok(self.icons.push(icon))
     }
@@ -159,6 +185,22 @@ impl WidgetRegistry {
         self.dropdowns.get_update_result(dropdown_id)
     }
 
+    /// Returns the ids of all buttons that were clicked during the last update
+    pub fn clicked_buttons(&self) -> Vec<ResourceId<UiWidgetId>> {
+        self.buttons.entries.iter().filter(|entry| entry.update_result).map(|entry| entry.id).collect()
+    }
+    /// Returns the ids and update results of all sliders that were updated during the last update
+    pub fn updated_sliders(&self) -> Vec<(ResourceId<UiWidgetId>, SliderUpdateResult)> {
+        self.sliders.entries.iter().filter_map(|entry| entry.update_result.map(|result| (entry.id, result))).collect()
+    }
+    /// Returns the ids and selected values of all dropdowns that got a new selection during the last update
+    pub fn updated_dropdowns(&self) -> Vec<(ResourceId<UiWidgetId>, u32)> {
+        self.dropdowns.entries.iter().filter_map(|entry| entry.update_result.map(|value| (entry.id, value))).collect()
+    }
+    pub fn is_widget_hovered(&self, widget_id: &ResourceId<UiWidgetId>) -> bool {
+        self.hovered_widgets.iter().any(|id| id.equals(widget_id))
+    }
+
 
     // ================================================== \\
     // ============ Methods to get a UiWidget ============ \\
@@ -238,17 +280,17 @@ impl WidgetRegistry {
         }
     }
 
-    pub fn set_button_background_color(&self, color: Color, button_id: &ResourceId<UiWidgetId>, element_registry: &mut ElementRegistry) -> Result<(), String> {
+    pub fn set_button_background_color(&self, color: Color, button_id: &ResourceId<UiWidgetId>, element_registry: &mut ElementRegistry) -> Result<(), LazuliError> {
         match self.get_button(button_id) {
             Some(button) => button.set_background_color(color, element_registry),
-            None => Err( format!("Failed to set button background color because button with id {} was not found", button_id.id()) ),
+            None => Err(UiError::WidgetNotFound(button_id.duplicate()).into()),
         }
     }
 
-    pub fn set_button_text_color(&self, color: Color, button_id: &ResourceId<UiWidgetId>, element_registry: &mut ElementRegistry) -> Result<(), String> {
+    pub fn set_button_text_color(&self, color: Color, button_id: &ResourceId<UiWidgetId>, element_registry: &mut ElementRegistry) -> Result<(), LazuliError> {
         match self.get_button(button_id) {
             Some(button) => button.set_text_color(color, element_registry),
-            None => Err( format!("Failed to set button text color because button with id {} was not found", button_id.id()) ),
+            None => Err(UiError::WidgetNotFound(button_id.duplicate()).into()),
         }
     }
 
@@ -256,6 +298,8 @@ impl WidgetRegistry {
     // ============ Methods to remove widgets ============ \\
 
     pub fn remove_widget(&mut self, widget_id: &ResourceId<UiWidgetId>) -> Option<Box<dyn UiWidget>> {
+        self.hovered_widgets.retain(|id| !id.equals(widget_id));
+
         self.remove_button(widget_id).or_else(||{ 
         self.remove_slider(widget_id).or_else(||{
         self.remove_dropdown(widget_id).or_else(||{
@@ -276,3 +320,12 @@ impl WidgetRegistry {
         self.icons.remove(widget_id).map(|widget| Box::new(widget) as Box<dyn UiWidget>)
     }
 }
+
+/// Compares the widgets hovered during the previous update with the ones hovered now.
+/// Returns the widgets that started being hovered and the widgets that stopped being hovered.
+pub(super) fn hover_changes(previous: &[ResourceId<UiWidgetId>], current: &[ResourceId<UiWidgetId>]) -> (Vec<ResourceId<UiWidgetId>>, Vec<ResourceId<UiWidgetId>>) {
+    let entered = current.iter().filter(|id| !previous.iter().any(|prev| prev.equals(id))).copied().collect();
+    let left = previous.iter().filter(|id| !current.iter().any(|cur| cur.equals(id))).copied().collect();
+
+    (entered, left)
+}
@@ -0,0 +1,21 @@
+use crate::{graphics::ui::UiWidgetId, ResourceId};
+use super::widget_registry::hover_changes;
+
+#[test]
+fn test_hover_changes() {
+    let a: ResourceId<UiWidgetId> = ResourceId::new(1);
+    let b: ResourceId<UiWidgetId> = ResourceId::new(2);
+    let c: ResourceId<UiWidgetId> = ResourceId::new(3);
+
+    let (entered, left) = hover_changes(&[], &[a, b]);
+    assert_eq!(vec![*a.id(), *b.id()], entered.iter().map(|id| *id.id()).collect::<Vec<u32>>());
+    assert!(left.is_empty());
+
+    let (entered, left) = hover_changes(&[a, b], &[b, c]);
+    assert_eq!(vec![*c.id()], entered.iter().map(|id| *id.id()).collect::<Vec<u32>>());
+    assert_eq!(vec![*a.id()], left.iter().map(|id| *id.id()).collect::<Vec<u32>>());
+
+    let (entered, left) = hover_changes(&[b, c], &[b, c]);
+    assert!(entered.is_empty());
+    assert!(left.is_empty());
+}
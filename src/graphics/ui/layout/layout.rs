@@ -1,4 +1,4 @@
-use crate::{asset_manager::AssetManager, graphics::ui::{interface::WidgetRegistry, ElementRegistry, Position, UiElementId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, input::Input, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, graphics::ui::{interface::WidgetRegistry, ElementRegistry, Position, UiElementId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, input::Input, ResourceId};
 
 /// Widgets in a layout should get a higher z_index than the background
 pub const LAYOUT_ELEMENT_EXTRA_Z_INDEX: f32  = 0.1;
@@ -25,5 +25,5 @@ pub trait Layout {
 }
 
 pub trait LayoutBuilder {
-    fn build(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, asset_manager: &mut dyn AssetManager) -> Result<(Box<dyn Layout>, UpdateTargetCollection), String>;
+    fn build(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, asset_manager: &mut dyn AssetManager) -> Result<(Box<dyn Layout>, UpdateTargetCollection), LazuliError>;
 }
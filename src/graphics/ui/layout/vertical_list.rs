@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, graphics::{ui::{bounds_2d::Bounds2d, element::InputEvent, interface::{self, WidgetRegistry}, padding::Padding, shapes::RectangleBuilder, AnchorPoint, ElementRegistry, Position, UiElementId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection, WidgetUpdateTarget}, Color}, input::{Input, MouseButton}, log, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, graphics::{ui::{bounds_2d::Bounds2d, element::InputEvent, interface::{self, WidgetRegistry}, padding::Padding, shapes::RectangleBuilder, AnchorPoint, ElementRegistry, Position, UiElementId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection, WidgetUpdateTarget}, Color}, input::{Input, ScrollMode, SmoothScroll}, log, time, ResourceId};
 
 use super::{Layout, layout::{LAYOUT_ELEMENT_EXTRA_Z_INDEX, LAYOUT_SCROLLBAR_EXTRA_Z_INDEX, LayoutBuilder}};
 
@@ -16,6 +16,7 @@ pub struct VerticalList {
     max_height: f32,
     current_scroll: f32,
     max_scroll: f32,
+    scroll: SmoothScroll,
     scroll_on_start_scrollbar_drag: f32,
     padding: Padding,
     draw_bounds: Bounds2d,
@@ -84,8 +85,18 @@ impl Layout for VerticalList {
     fn update(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, input: &Input, scroll_speed: f32) -> UpdateTargetCollection {
         let mut update_targets = UpdateTargetCollection::default();
 
-        let new_scroll_amount = self.calculate_new_scroll_amount(&element_registry, input, scroll_speed);
-        self.update_scroll(new_scroll_amount, &mut update_targets, element_registry, widget_registry, input);
+        self.scroll.set_bounds(0.0, self.max_scroll);
+        match self.calculate_scroll_from_scrollbar_drag(element_registry, input) {
+            Some(scroll_amount) => self.scroll.jump_to(scroll_amount),
+            None => {
+                if self.is_scrolled(element_registry) {
+                    self.scroll.scroll_by(-input.mouse.get_scroll_y() as f32 * scroll_speed);
+                }
+            },
+        }
+
+        let new_scroll_amount = self.scroll.update(time::DELTA);
+        self.update_scroll(new_scroll_amount, &mut update_targets, element_registry, widget_registry);
 
         update_targets
     }
@@ -192,13 +203,9 @@ impl Layout for VerticalList {
 }
 
 impl VerticalList {
-    fn calculate_new_scroll_amount(&mut self, element_registry: &ElementRegistry, input: &Input, scroll_speed: f32) -> f32 {
-        let scrollbar_element_id = {
-            match self.scrollbar_element_id {
-                Some(scrollbar_element_id) => scrollbar_element_id,
-                None => return self.calculate_new_scroll_amount_from_scroll(input, scroll_speed),
-            }
-        };
+    /// Returns the scroll amount that matches the position of the scrollbar, if it is being dragged
+    fn calculate_scroll_from_scrollbar_drag(&mut self, element_registry: &ElementRegistry, input: &Input) -> Option<f32> {
+        let scrollbar_element_id = self.scrollbar_element_id?;
 
         match element_registry.get_ui_element_by_id(&scrollbar_element_id) {
             Some(element) => {
@@ -209,7 +216,7 @@ impl VerticalList {
 
                     let amount_moved = input.mouse.get_position_y() as f32 - element.world_data().event_handlers.mouse_left_drag_handler.get_drag_start_position().y;
                     let extra_scroll = (amount_moved / self.max_height) * (self.max_scroll + self.max_height);
-                    return (self.scroll_on_start_scrollbar_drag + extra_scroll).clamp(0.0, self.max_scroll);
+                    return Some((self.scroll_on_start_scrollbar_drag + extra_scroll).clamp(0.0, self.max_scroll));
                 }
             },
             None => {
@@ -217,19 +224,11 @@ impl VerticalList {
             },
         }
 
-        return self.calculate_new_scroll_amount_from_scroll(input, scroll_speed);
+        None
     }
 
-    fn calculate_new_scroll_amount_from_scroll(&self, input: &Input, scroll_speed: f32) -> f32 {
-        (self.current_scroll - input.mouse.get_scroll_y() as f32 * scroll_speed).clamp(0.0, self.max_scroll)
-    }
-
-    fn update_scroll(&mut self, new_scroll_amount: f32, update_target_collection: &mut UpdateTargetCollection, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, input: &Input) {
-        if !self.should_update_scroll(element_registry, input) {
-            return;
-        }
-
-        if self.current_scroll == new_scroll_amount {
+    fn update_scroll(&mut self, new_scroll_amount: f32, update_target_collection: &mut UpdateTargetCollection, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry) {
+        if self.widget_ids.is_empty() || self.current_scroll == new_scroll_amount {
             return;
         }
         self.current_scroll = new_scroll_amount;
@@ -238,28 +237,15 @@ impl VerticalList {
         self.update_scroll_bar(element_registry);
     }
 
-    fn should_update_scroll(&self, element_registry: &ElementRegistry, input: &Input) -> bool {
-        if self.widget_ids.is_empty() && input.mouse.get_scroll_y() == 0.0 {
-            return false;
-        }
-
+    /// Whether the mouse scrolled while hovering the list
+    fn is_scrolled(&self, element_registry: &ElementRegistry) -> bool {
         match element_registry.get_ui_element_by_id(&self.background_element_id) {
-            Some(element) => {
-                if element.world_data().event_handlers.scroll_handler.did_handle() {
-                    return true;
-                }
-            },
+            Some(element) => element.world_data().event_handlers.scroll_handler.did_handle(),
             None => {
                 log::engine_warn(format!("VerticalList can not update scroll because background element (id={}) was not found", self.background_element_id.id()));
+                false
             },
         }
-
-        match self.scrollbar_element_id {
-            Some(scrollbar_element_id) => {
-                return element_registry.is_element_dragged(&scrollbar_element_id, MouseButton::Left);
-            },
-            None => return false,
-        }
     }
 
     fn update_scroll_for_widgets(&self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry) -> UpdateTargetCollection {
@@ -342,10 +328,11 @@ pub struct VerticalListBuilder {
     scrollbar_color: Color,
     scrollbar_width: f32,
     scrollbar_inset: Vec2,
+    scroll_mode: ScrollMode,
 }
 
 impl LayoutBuilder for VerticalListBuilder {
-    fn build(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, asset_manager: &mut dyn AssetManager) -> Result<(Box<dyn Layout>, UpdateTargetCollection), String> {
+    fn build(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, asset_manager: &mut dyn AssetManager) -> Result<(Box<dyn Layout>, UpdateTargetCollection), LazuliError> {
         let (layout, update_targets) = self.build(element_registry, widget_registry, asset_manager)?;
         Ok((Box::new(layout), update_targets))
     }
@@ -370,10 +357,11 @@ impl VerticalListBuilder {
             scrollbar_width: 5.0,
             scrollbar_color: Color::Rgba(255, 255, 255, 0.7),
             scrollbar_inset: Vec2::new(2.0, 2.0),
+            scroll_mode: ScrollMode::Instant,
         }
     }
 
-    pub fn build(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, asset_manager: &mut dyn AssetManager) -> Result<(VerticalList, UpdateTargetCollection), String> {
+    pub fn build(&mut self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, asset_manager: &mut dyn AssetManager) -> Result<(VerticalList, UpdateTargetCollection), LazuliError> {
         let background_width = match self.width {
             Width::Fixed(width) => width,
             Width::MaxWidth(max_width) => { 
@@ -425,6 +413,7 @@ impl VerticalListBuilder {
             position: self.position, 
             max_height: self.max_height,
             current_scroll: 0.0,
+            scroll: SmoothScroll::new(self.scroll_mode),
             max_scroll: 0.0, // Will be set after the widget position have been set because only after that we can calculate the maximum scroll
             scroll_on_start_scrollbar_drag: 0.0,
             padding: self.padding.clone(),
@@ -568,6 +557,11 @@ impl VerticalListBuilder {
         self.scrollbar_inset.y = scrollbar_inset_y;
         self
     }
+
+    pub fn with_scroll_mode(mut self, scroll_mode: ScrollMode) -> Self {
+        self.scroll_mode = scroll_mode;
+        self
+    }
 }
 
 fn calculate_background_height(widget_ids: &Vec<ResourceId<UiWidgetId>>, gap_size: f32, padding: &Padding, element_registry: &ElementRegistry, widget_registry: &WidgetRegistry) -> f32 {
@@ -18,6 +18,8 @@ pub use interface::default_element_background_color;
 pub use interface::default_font;
 pub use interface::default_font_size;
 pub use interface::default_text_color;
+pub use interface::MIN_Z_INDEX;
+pub use interface::MAX_Z_INDEX;
 
 mod layout;
 pub use layout::Layout;
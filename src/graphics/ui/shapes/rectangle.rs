@@ -1,6 +1,6 @@
 use glam::{Vec2, Vec4, Vec3};
 
-use crate::{asset_manager::AssetManager, error::opengl, graphics::{material::Material, renderer::buffer::{Buffer, Vao}, shader::{CustomShaderValues, GlShaderBuilder, UniformValue}, ui::{bounds_2d::Bounds2d, element::{ui_element::UiElement, world_element_data::WorldElementData, AnchorElementData, AnchorPoint, InputEvent}, interface::{is_valid_z_index, map_z_index_for_shader}, ElementRegistry, Position, UiTexture}, Color}, log, set_attribute, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, error::opengl, graphics::{material::Material, renderer::buffer::{Buffer, Vao}, shader::{CustomShaderValues, GlShaderBuilder, UniformValue}, ui::{bounds_2d::Bounds2d, element::{ui_element::UiElement, world_element_data::WorldElementData, AnchorElementData, AnchorPoint, InputEvent}, interface::{is_valid_z_index, map_z_index_for_shader}, ElementRegistry, Position, UiTexture}, Color}, log, set_attribute, ResourceId};
 use crate::graphics::shapes::RECTANGLE_INDICES;
 
 use super::rectangle_border::{Border, BorderSize, BorderRadius};
@@ -218,7 +218,7 @@ impl RectangleBuilder {
         }
     }
 
-    pub fn build(&self, asset_manager: &mut dyn AssetManager, element_registry: &ElementRegistry) -> Result<Rectangle, String> {
+    pub fn build(&self, asset_manager: &mut dyn AssetManager, element_registry: &ElementRegistry) -> Result<Rectangle, LazuliError> {
         let shader_builder = match self.shader_builder.clone() {
             Some(custom_shader_self) => custom_shader_self,
             None => self.default_shader_builder(),
@@ -394,4 +394,13 @@ impl RectangleBuilder {
         self.handle_input_events.push((input_event, handle));
         self
     }
+
+    /// Set whether the rectangle handles any input event. A rectangle that does not handle input, like a
+    /// background, lets the mouse through to the scene, see `MouseDevice::is_consumed_by_ui`.
+    pub fn with_handle_input_events(mut self, handle: bool) -> Self {
+        for input_event in [InputEvent::MouseLeftDown, InputEvent::MouseLeftUp, InputEvent::MouseLeftDrag, InputEvent::Hover, InputEvent::Scroll] {
+            self.handle_input_events.push((input_event, handle));
+        }
+        self
+    }
 }
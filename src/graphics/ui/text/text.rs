@@ -1,6 +1,6 @@
 use glam::{Vec2, Vec4, Vec3};
 
-use crate::{asset_manager::AssetManager, graphics::{font::Font, material::Material, shader::{CustomShaderValues, UniformValue}, ui::{element::{ui_element::UiElement, world_element_data::WorldElementData, AnchorElementData, AnchorPoint, InputEvent}, interface::{self, is_valid_z_index, map_z_index_for_shader}, ElementRegistry, Position}, Color, Transform}, log, ResourceId};
+use crate::{error::{AssetError, LazuliError}, asset_manager::AssetManager, graphics::{font::Font, material::Material, shader::{CustomShaderValues, UniformValue}, ui::{element::{ui_element::UiElement, world_element_data::WorldElementData, AnchorElementData, AnchorPoint, InputEvent}, interface::{self, is_valid_z_index, map_z_index_for_shader}, ElementRegistry, Position}, Color, Transform}, log, ResourceId};
 
 use super::glyph::Glyph;
 
@@ -70,7 +70,7 @@ impl UiElement for Text {
 }
 
 impl Text {
-    pub fn set_text(&mut self, text: &String, window_size: Vec2, anchor_element_data: Option<AnchorElementData>, asset_manager: &mut dyn AssetManager) -> Result<(), String> {
+    pub fn set_text(&mut self, text: &String, window_size: Vec2, anchor_element_data: Option<AnchorElementData>, asset_manager: &mut dyn AssetManager) -> Result<(), LazuliError> {
         let font_space_size;
         let bitmap_spread;
         let total_width;
@@ -83,7 +83,7 @@ impl Text {
                 total_width = Self::get_total_width(&text, font, self.letter_spacing, bitmap_spread);
                 bitmap_characters = font.atlas().characters().clone()
             },
-            None => return Err(format!("Failed to get font by id {}", self.font_id.id())),
+            None => return Err(AssetError::NotFound { asset_type: "font", id: *self.font_id.id() }.into()),
         }
 
         let mut start_x: f32 = 0.0 - total_width / 2.0;
@@ -181,13 +181,13 @@ impl TextBuilder {
         }
     }
 
-    pub fn build(&self, text: impl Into<String>, font_id: &ResourceId<Box<dyn Font>>, asset_manager: &mut dyn AssetManager, element_registry: &mut ElementRegistry) -> Result<Text, String> {
+    pub fn build(&self, text: impl Into<String>, font_id: &ResourceId<Box<dyn Font>>, asset_manager: &mut dyn AssetManager, element_registry: &mut ElementRegistry) -> Result<Text, LazuliError> {
         let font_material_id;
         match asset_manager.get_font_by_id(font_id) {
             Some(font) => {
                 font_material_id = font.get_material_id().duplicate();
             },
-            None => return Err(format!("Failed to get font by id {}", font_id.id())),
+            None => return Err(AssetError::NotFound { asset_type: "font", id: *font_id.id() }.into()),
         }
 
         let mut world_data = WorldElementData::new(
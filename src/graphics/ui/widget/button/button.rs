@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, graphics::{font::PlainBitmapBuilder, ui::{self, bounds_2d::Bounds2d, element::{ui_element::UiElement, AnchorPoint}, interface::{self, is_valid_z_index}, text::TextAlign, widget::UiWidget, ElementRegistry, Padding, Position, TextBuilder, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, input::{ButtonAction, MouseButton}, log, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, graphics::{font::PlainBitmapBuilder, ui::{self, bounds_2d::Bounds2d, element::{ui_element::UiElement, AnchorPoint}, interface::{self, is_valid_z_index}, text::TextAlign, widget::UiWidget, ElementRegistry, Padding, Position, TextBuilder, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, input::{ButtonAction, MouseButton}, log, ResourceId};
 
 pub struct Button {
     text_element_id: ResourceId<UiElementId>,
@@ -99,7 +99,7 @@ impl Button {
         )
     }
 
-    pub fn set_scale(&mut self, scale: Vec2, element_registry: &mut ElementRegistry) -> Result<(), String> {
+    pub fn set_scale(&mut self, scale: Vec2, element_registry: &mut ElementRegistry) -> Result<(), LazuliError> {
         element_registry.set_element_scale(&self.background_element_id, scale)?;
         element_registry.set_element_scale(&self.text_element_id, scale)?;
         Ok(())
@@ -110,11 +110,11 @@ impl Button {
     pub fn text_element_id(&self) -> ResourceId<UiElementId> { self.text_element_id.clone() }
     pub fn padding(&self) -> &Padding { &self.padding }
 
-    pub fn set_background_color(&self, color: Color, element_registry: &mut ElementRegistry) -> Result<(), String> {
+    pub fn set_background_color(&self, color: Color, element_registry: &mut ElementRegistry) -> Result<(), LazuliError> {
         element_registry.set_element_color(&self.background_element_id, color)
     }
 
-    pub fn set_text_color(&self, color: Color, element_registry: &mut ElementRegistry) -> Result<(), String> {
+    pub fn set_text_color(&self, color: Color, element_registry: &mut ElementRegistry) -> Result<(), LazuliError> {
         element_registry.set_element_color(&self.text_element_id, color)
     }
 }
@@ -158,7 +158,7 @@ impl ButtonBuilder {
         }
     }
 
-    pub fn build(&self, label: impl Into<String>, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<Button, String> {
+    pub fn build(&self, label: impl Into<String>, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<Button, LazuliError> {
         let font_id = match &self.font_path {
             Some(font_path) => asset_manager.load_font(&PlainBitmapBuilder::new()
                 .with_font_file_path(font_path.clone())
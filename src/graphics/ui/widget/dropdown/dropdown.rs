@@ -3,7 +3,7 @@ use std::{fmt::Debug, f32::consts::PI};
 use glam::Vec2;
 use interface::WidgetRegistryUdpateResult;
 
-use crate::{asset_manager::AssetManager, graphics::{ui::{bounds_2d::Bounds2d, element::InputEvent, interface::{self, is_valid_z_index, LayoutRegistry, WidgetRegistry, MAX_Z_INDEX}, widget::{ButtonBuilder, IconBuilder, UiWidget}, AnchorPoint, ElementRegistry, LayoutUpdateTarget, Position, TextAlign, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection, VerticalListBuilder, WidgetUpdateTarget, Width}, Color}, input::ButtonAction, log, ResourceId};
+use crate::{error::{LazuliError, UiError}, asset_manager::AssetManager, graphics::{ui::{bounds_2d::Bounds2d, element::InputEvent, interface::{self, is_valid_z_index, LayoutRegistry, WidgetRegistry, MAX_Z_INDEX}, widget::{ButtonBuilder, IconBuilder, UiWidget}, AnchorPoint, ElementRegistry, LayoutUpdateTarget, Position, TextAlign, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection, VerticalListBuilder, WidgetUpdateTarget, Width}, Color}, input::ButtonAction, log, ResourceId};
 
 struct DropdownOptionButton<T: Debug + Clone> {
     button_id: ResourceId<UiWidgetId>,
@@ -188,6 +188,11 @@ impl<T: Debug + Clone> Dropdown<T> {
     }
 
     pub fn is_open(&self) -> bool { self.is_open }
+
+    /// Only the button that opens the dropdown counts, the option buttons get their own hover events
+    pub fn is_hovered(&self, element_registry: &ElementRegistry) -> bool {
+        element_registry.is_element_hovered(&self.button_main_element_id)
+    }
 }
 
 pub struct DropdownOption<T: Debug + Clone> {
@@ -232,7 +237,7 @@ impl<T: Debug + Clone> DropdownBuilder<T> {
         }
     }
 
-    pub fn build(&self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, layout_registry: &mut LayoutRegistry, asset_manager: &mut dyn AssetManager) -> Result<(Dropdown<T>, Vec<UpdateTargetCollection>), String> {
+    pub fn build(&self, element_registry: &mut ElementRegistry, widget_registry: &mut WidgetRegistry, layout_registry: &mut LayoutRegistry, asset_manager: &mut dyn AssetManager) -> Result<(Dropdown<T>, Vec<UpdateTargetCollection>), LazuliError> {
         self.validate()?;
 
         let selected_value: Option<T>;
@@ -323,12 +328,12 @@ impl<T: Debug + Clone> DropdownBuilder<T> {
         self
     }
 
-    pub fn validate(&self) -> Result<(), String> {
+    pub fn validate(&self) -> Result<(), LazuliError> {
         if self.initially_selected_index.is_some() && self.options.len() <= self.initially_selected_index.unwrap() as usize {
-            return Err(format!(
-                "Initially selected index ({}) is higher than the number of options ({})"
+            return Err(UiError::InvalidBuilder(format!(
+                "initially selected index ({}) is higher than the number of options ({})"
                 , self.initially_selected_index.unwrap(), self.options.len()
-            ));
+            )).into());
         }
 
         Ok(())
@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, graphics::{shader::GlShaderBuilder, texture::Texture, ui::{bounds_2d::Bounds2d, element::InputEvent, interface::{self, is_valid_z_index}, shapes::{Rectangle, RectangleBuilder}, widget::UiWidget, ElementRegistry, Position, UiElementId, UiLayoutId, UiTexture, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, log, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, graphics::{shader::GlShaderBuilder, texture::Texture, ui::{bounds_2d::Bounds2d, element::InputEvent, interface::{self, is_valid_z_index}, shapes::{Rectangle, RectangleBuilder}, widget::UiWidget, ElementRegistry, Position, UiElementId, UiLayoutId, UiTexture, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, log, ResourceId};
 
 pub struct Icon {
     rectangle_element_id: ResourceId<UiElementId>,
@@ -69,6 +69,12 @@ impl UiWidget for Icon {
     }
 }
 
+impl Icon {
+    pub fn is_hovered(&self, element_registry: &ElementRegistry) -> bool {
+        element_registry.is_element_hovered(&self.rectangle_element_id)
+    }
+}
+
 enum IconSize {
     /// Scale height based on the given width
     Width(f32),
@@ -122,7 +128,7 @@ impl IconBuilder {
         }
     }
 
-    pub fn build(&self, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<Icon, String> {
+    pub fn build(&self, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<Icon, LazuliError> {
         // We need to upload the texture before building the rectangle because we need to know the texture size so we can
         // auto scale the size
         let shader_builder = match self.shader_builder.clone() {
@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, graphics::{font::PlainBitmapBuilder, ui::{self, bounds_2d::Bounds2d, element::{ui_element::UiElement, AnchorPoint}, interface::{self, is_valid_z_index}, widget::UiWidget, ElementRegistry, Position, TextBuilder, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, input::Input, log, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, graphics::{font::PlainBitmapBuilder, ui::{self, bounds_2d::Bounds2d, element::{ui_element::UiElement, AnchorPoint}, interface::{self, is_valid_z_index}, widget::UiWidget, ElementRegistry, Position, TextBuilder, UiElementId, UiLayoutId, UiUpdateTargets, UiWidgetId, UpdateTargetCollection}, Color}, input::Input, log, ResourceId};
 
 #[derive(Clone, Copy, Debug)]
 pub enum SliderProgressBarAlignment {
@@ -217,7 +217,7 @@ impl Slider {
         element_registry.get_element_size(&self.background_element_id).unwrap()
     }
 
-    pub fn set_scale(&mut self, scale: Vec2, element_registry: &mut ElementRegistry) -> Result<(), String> {
+    pub fn set_scale(&mut self, scale: Vec2, element_registry: &mut ElementRegistry) -> Result<(), LazuliError> {
         self.scale = scale;
 
         element_registry.set_element_scale(&self.background_element_id, scale)?;
@@ -273,7 +273,7 @@ impl SliderBuilder {
         }
     }
 
-    pub fn build(&self, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<Slider, String> {
+    pub fn build(&self, element_registry: &mut ElementRegistry, asset_manager: &mut dyn AssetManager) -> Result<Slider, LazuliError> {
         let font_id = match &self.font_path {
             Some(font_path) => asset_manager.load_font(&PlainBitmapBuilder::new()
                 .with_font_file_path(font_path.clone())
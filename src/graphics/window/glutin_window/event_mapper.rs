@@ -1,6 +1,6 @@
 /// Functions to map input from the "glutin" crate to that of our own
 
-use crate::{input::{ButtonState, Key, MouseButton}, log};
+use crate::{input::{ButtonState, ImeEvent, Key, Modifiers, MouseButton, TouchPhase}, log};
 
 pub fn map_glutin_keycode(glutin_key: glutin::event::VirtualKeyCode) -> Key {
     match glutin_key {
@@ -112,3 +112,30 @@ pub fn map_glutin_mouse_button_state(state: glutin::event::ElementState) -> Butt
         glutin::event::ElementState::Released => ButtonState::Up,
     }
 }
+
+pub fn map_glutin_ime(glutin_ime: glutin::event::Ime) -> ImeEvent {
+    match glutin_ime {
+        glutin::event::Ime::Enabled => ImeEvent::Enabled,
+        glutin::event::Ime::Preedit(text, cursor) => ImeEvent::Preedit { text, cursor },
+        glutin::event::Ime::Commit(text) => ImeEvent::Commit(text),
+        glutin::event::Ime::Disabled => ImeEvent::Disabled,
+    }
+}
+
+pub fn map_glutin_modifiers(glutin_modifiers: glutin::event::ModifiersState) -> Modifiers {
+    Modifiers {
+        shift: glutin_modifiers.shift(),
+        ctrl: glutin_modifiers.ctrl(),
+        alt: glutin_modifiers.alt(),
+        super_key: glutin_modifiers.logo(),
+    }
+}
+
+pub fn map_glutin_touch_phase(glutin_phase: glutin::event::TouchPhase) -> TouchPhase {
+    match glutin_phase {
+        glutin::event::TouchPhase::Started => TouchPhase::Started,
+        glutin::event::TouchPhase::Moved => TouchPhase::Moved,
+        glutin::event::TouchPhase::Ended => TouchPhase::Ended,
+        glutin::event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+    }
+}
@@ -1,4 +1,5 @@
 mod event_mapper;
+mod swap_interval;
 mod window;
 
 pub use window::GlutinWindow;
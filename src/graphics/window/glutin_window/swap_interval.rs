@@ -0,0 +1,96 @@
+use std::ffi::c_void;
+
+use glutin::{ContextWrapper, PossiblyCurrent};
+
+/// Set the swap interval of the current context: 1 waits for the monitor to refresh before showing a frame
+/// (vsync), 0 shows frames right away. glutin only sets it when creating the context, so this looks up the
+/// swap interval function of the platform api. Returns false if the platform api does not support it.
+pub(super) fn set_swap_interval(context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, interval: i32) -> bool {
+    unsafe { platform::set_swap_interval(context, interval) }
+}
+
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "windows"))]
+fn load(context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, name: &str) -> Option<*const c_void> {
+    let address = context.get_proc_address(name);
+    if address.is_null() { None } else { Some(address) }
+}
+
+#[cfg(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd"))]
+mod platform {
+    use std::ffi::{c_ulong, c_void};
+
+    use glutin::{platform::{unix::RawHandle, ContextTraitExt}, ContextWrapper, PossiblyCurrent};
+
+    use super::load;
+
+    pub unsafe fn set_swap_interval(context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, interval: i32) -> bool {
+        match context.raw_handle() {
+            RawHandle::Egl(_) => set_egl_swap_interval(context, interval),
+            RawHandle::Glx(_) => set_glx_swap_interval(context, interval),
+        }
+    }
+
+    unsafe fn set_egl_swap_interval(context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, interval: i32) -> bool {
+        let (Some(display), Some(swap_interval)) = (context.get_egl_display(), load(context, "eglSwapInterval")) else {
+            return false;
+        };
+
+        let swap_interval: unsafe extern "C" fn(*const c_void, i32) -> u32 = std::mem::transmute(swap_interval);
+        swap_interval(display, interval) != 0
+    }
+
+    /// Tries the same extensions as glutin does when it creates the context
+    unsafe fn set_glx_swap_interval(context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, interval: i32) -> bool {
+        if let (Some(swap_interval), Some(current_display), Some(current_drawable)) = (
+            load(context, "glXSwapIntervalEXT"),
+            load(context, "glXGetCurrentDisplay"),
+            load(context, "glXGetCurrentDrawable"),
+        ) {
+            let swap_interval: unsafe extern "C" fn(*mut c_void, c_ulong, i32) = std::mem::transmute(swap_interval);
+            let current_display: unsafe extern "C" fn() -> *mut c_void = std::mem::transmute(current_display);
+            let current_drawable: unsafe extern "C" fn() -> c_ulong = std::mem::transmute(current_drawable);
+
+            swap_interval(current_display(), current_drawable(), interval);
+            return true;
+        }
+
+        if let Some(swap_interval) = load(context, "glXSwapIntervalMESA") {
+            let swap_interval: unsafe extern "C" fn(u32) -> i32 = std::mem::transmute(swap_interval);
+            return swap_interval(interval as u32) == 0;
+        }
+
+        // The SGI extension can not turn vsync off
+        if let Some(swap_interval) = load(context, "glXSwapIntervalSGI").filter(|_| interval > 0) {
+            let swap_interval: unsafe extern "C" fn(i32) -> i32 = std::mem::transmute(swap_interval);
+            return swap_interval(interval) == 0;
+        }
+
+        false
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use glutin::{ContextWrapper, PossiblyCurrent};
+
+    use super::load;
+
+    pub unsafe fn set_swap_interval(context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, interval: i32) -> bool {
+        match load(context, "wglSwapIntervalEXT") {
+            Some(swap_interval) => {
+                let swap_interval: unsafe extern "system" fn(i32) -> i32 = std::mem::transmute(swap_interval);
+                swap_interval(interval) != 0
+            },
+            None => false,
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "dragonfly", target_os = "freebsd", target_os = "netbsd", target_os = "openbsd", target_os = "windows")))]
+mod platform {
+    use glutin::{ContextWrapper, PossiblyCurrent};
+
+    pub unsafe fn set_swap_interval(_context: &ContextWrapper<PossiblyCurrent, glutin::window::Window>, _interval: i32) -> bool {
+        false
+    }
+}
@@ -1,10 +1,10 @@
-use std::time::Instant;
+use std::{cell::Cell, time::{Duration, Instant}};
 use glam::Vec2;
 use glutin::{event_loop::{EventLoop, ControlFlow}, window::WindowBuilder, GlRequest, ContextBuilder, Api, event::{Event, WindowEvent}, ContextWrapper, PossiblyCurrent, GlProfile, dpi::{PhysicalPosition, LogicalSize, LogicalPosition}};
 
-use crate::{event::{EventSystem, WindowResizeEvent, PixelDensityChangeEvent}, input::Input, time, graphics::{renderer::Renderer, window::window_listeners::WindowListeners, Window, ui::Interface}, asset_manager::AssetManager, log::{self}};
+use crate::{event::{EventSystem, WindowResizeEvent, PixelDensityChangeEvent, WindowFocusChanged, WindowMinimized, FileDropped}, input::{Input, InputEvent, Key, TouchEvent, TouchId, INPUT_RECORDING_PATH_ENV_VAR}, time, graphics::{capture, renderer::Renderer, window::window_listeners::WindowListeners, Window, ui::Interface}, asset_manager::AssetManager, error::{LazuliError, WindowError}, log::{self}, profiler::{self, ProfilerOverlay}};
 
-use super::event_mapper;
+use super::{event_mapper, swap_interval};
 
 /**
  * !! ISSUES !!
@@ -14,24 +14,61 @@ use super::event_mapper;
  */
 pub struct GlutinWindow {
     render_context: ContextWrapper<PossiblyCurrent, glutin::window::Window>,
-    event_loop: EventLoop<()>,
+    /// Taken out when the window starts running, because running the event loop consumes it
+    event_loop: Option<EventLoop<()>>,
     target_fps: u64,
-    event_listeners: WindowListeners,
+    show_profiler: bool,
+    profiler_trace_path: Option<String>,
+    shader_hot_reload: bool,
+    input_recording_path: Option<String>,
+    screenshot_key: Option<Key>,
+    /// Taken out when the window starts running, so that the listeners can be read while borrowing the window
+    event_listeners: Option<WindowListeners>,
+    vsync: Cell<bool>,
 }
 
 impl Window for GlutinWindow {
     fn run(self: Box<Self>, mut renderer: Renderer, mut event_system: EventSystem, mut lz_input: Input, mut asset_manager: Box<dyn AssetManager>, mut interface: Interface) {
-        let mut next_frame_time: u128 = 0;
+        // The window gets moved in to the function of event_loop.run, but the event loop itself and the listeners have
+        // to be taken out of it first: the event loop gets consumed by running it, and the listeners borrow the window
+        let mut window = *self;
+        let event_loop = window.event_loop.take().expect("GlutinWindow can only run once");
+        let mut event_listeners = window.event_listeners.take().expect("GlutinWindow can only run once");
+        let target_fps = window.target_fps;
+        let shader_hot_reload = window.shader_hot_reload;
+        let screenshot_key = window.screenshot_key;
+        let mut screenshot_paths: Vec<String> = vec![];
+
+        let profiler_trace_path = window.profiler_trace_path.take();
+        if let Some(path) = &profiler_trace_path {
+            log::engine_info(format!("recording profiler trace to {}", path));
+            profiler::enable();
+            profiler::start_recording();
+        }
+
+        let input_recording_path = window.input_recording_path.take();
+        if let Some(path) = &input_recording_path {
+            log::engine_info(format!("recording input to {}", path));
+            lz_input.start_recording();
+        }
+
+        let mut profiler_overlay = None;
+        if window.show_profiler {
+            profiler::enable();
 
-        // Move all properties of self in to their own variables because self will get moved by event_loop.run, and thus the properties
-        // can not be directly used in the function of event_loop.run
-        let render_context = self.render_context;
-        let mut event_listeners = self.event_listeners;
-        let target_fps = self.target_fps;
-        let event_loop = self.event_loop;
+            match ProfilerOverlay::new(&mut interface, &mut *asset_manager) {
+                Ok(overlay) => profiler_overlay = Some(overlay),
+                Err(err) => log::engine_err(format!("failed to create profiler overlay: {}", err)),
+            }
+        }
+
+        let mut fixed_timestep = time::FixedTimestep::default();
+        let frame_duration = Duration::from_nanos(1_000_000_000 / target_fps);
+        let mut last_frame_time = Instant::now();
+        let mut next_frame_time = last_frame_time;
 
         // Read events from Scene::new
-        Self::read_event_listeners(&mut event_listeners, &render_context.window());
+        event_listeners.read(&window);
 
         // Due to issue 1, we do not receive a correct initial size event. To fix it, we manually send one.
         event_system.send(WindowResizeEvent {
@@ -39,26 +76,45 @@ impl Window for GlutinWindow {
             height: interface.size().y as u32,
         });
 
+        let mut should_exit = false;
+        let mut is_minimized = false;
+
         event_loop.run(move |event, _, control_flow| {
-            let start_time = Instant::now();
+            let is_input_event = matches!(event, Event::WindowEvent { .. } | Event::DeviceEvent { .. });
+            if is_input_event { profiler::begin_scope(profiler::SCOPE_INPUT); }
 
             match event {
                 Event::WindowEvent { event, .. } => match event {
-                    WindowEvent::CloseRequested => *control_flow = ControlFlow::Exit,
+                    WindowEvent::CloseRequested => event_listeners.request_close(&mut event_system),
+                    WindowEvent::Focused(focused) => event_system.send(WindowFocusChanged { focused }),
+                    WindowEvent::DroppedFile(path) => event_system.send(FileDropped { path }),
+                    WindowEvent::Resized(physical_size) if physical_size.width == 0 || physical_size.height == 0 => {
+                        // Some platforms minimize a window by resizing it to nothing
+                        if !is_minimized {
+                            is_minimized = true;
+                            event_system.send(WindowMinimized { minimized: true });
+                        }
+                    },
                     WindowEvent::Resized(physical_size) => {
                         // Due to issue 1, we do a sanity check on the size
                         if physical_size.width > 100_000_000 || physical_size.height > 100_000_000 {
                             log::engine_warn(format!("preventing unusual window resize: {:?}", physical_size));
+                            profiler::end_scope(profiler::SCOPE_INPUT);
                             return;
                         }
 
-                        render_context.resize(physical_size);
+                        if is_minimized {
+                            is_minimized = false;
+                            event_system.send(WindowMinimized { minimized: false });
+                        }
+
+                        window.render_context.resize(physical_size);
 
-                        let dpi_factor = render_context.window().scale_factor();
+                        let dpi_factor = window.render_context.window().scale_factor();
                         let logical_size: LogicalSize<u32> = physical_size.to_logical(dpi_factor);
 
-                        let outer_size = render_context.window().outer_size();
-                        let inner_size = render_context.window().inner_size();
+                        let outer_size = window.render_context.window().outer_size();
+                        let inner_size = window.render_context.window().inner_size();
                         let frame_width = outer_size.width - inner_size.width;
                         let frame_height = outer_size.height - inner_size.height;
 
@@ -78,66 +134,162 @@ impl Window for GlutinWindow {
                     },
                     WindowEvent::KeyboardInput { device_id: _, input, is_synthetic: _ } => {
                         if let Some(key) = input.virtual_keycode {
-                            lz_input.keyboard.register_key_event(
-                                event_mapper::map_glutin_keycode(key), 
-                                event_mapper::map_glutin_key_state(input.state)
-                            );
+                            lz_input.register_event(InputEvent::Key {
+                                key: event_mapper::map_glutin_keycode(key),
+                                state: event_mapper::map_glutin_key_state(input.state),
+                            });
                         }
                     },
+                    WindowEvent::ModifiersChanged(modifiers) => {
+                        lz_input.register_event(InputEvent::Modifiers(event_mapper::map_glutin_modifiers(modifiers)));
+                    },
+                    WindowEvent::ReceivedCharacter(character) => {
+                        lz_input.register_event(InputEvent::Character(character));
+                    },
+                    WindowEvent::Ime(ime) => {
+                        lz_input.register_event(InputEvent::Ime(event_mapper::map_glutin_ime(ime)));
+                    },
                     WindowEvent::MouseInput { device_id: _, state, button, .. } => {
-                        lz_input.mouse.register_button_event(
-                            event_mapper::map_glutin_mouse_button(button), 
-                            event_mapper::map_glutin_mouse_button_state(state)
-                        );
+                        lz_input.register_event(InputEvent::MouseButton {
+                            button: event_mapper::map_glutin_mouse_button(button),
+                            state: event_mapper::map_glutin_mouse_button_state(state),
+                        });
                     },
                     WindowEvent::MouseWheel { device_id: _, delta, phase: _, .. } => {
                         match delta {
+                            // Most mouse wheels scroll in lines
                             glutin::event::MouseScrollDelta::LineDelta(x, y) => {
-                                // TODO how is this triggered? Can we register it the same as the other type?
-                                log::engine_warn(format!("TODO Untested MouseWheel event delta type: {:?}", delta));
-                                lz_input.mouse.register_scroll_x_event(x as f64);
-                                lz_input.mouse.register_scroll_y_event(y as f64);
+                                lz_input.register_event(InputEvent::ScrollLines { x: x as f64, y: y as f64 });
                             },
+                            // Touchpads scroll in physical pixels
                             glutin::event::MouseScrollDelta::PixelDelta(movement) => {
-                                lz_input.mouse.register_scroll_x_event(movement.x as f64);
-                                lz_input.mouse.register_scroll_y_event(movement.y as f64);
+                                let logical_movement: LogicalPosition<f64> = movement.to_logical(window.render_context.window().scale_factor());
+                                lz_input.register_event(InputEvent::ScrollX(logical_movement.x));
+                                lz_input.register_event(InputEvent::ScrollY(logical_movement.y));
                             },
                         }
                     },
                     WindowEvent::CursorMoved { device_id: _, position, .. } => {
-                        let logical_position: LogicalPosition<f64> = position.to_logical(render_context.window().scale_factor());
-                        lz_input.mouse.register_reposition_event(logical_position.x, logical_position.y);
+                        let logical_position: LogicalPosition<f64> = position.to_logical(window.render_context.window().scale_factor());
+                        lz_input.register_event(InputEvent::Reposition { x: logical_position.x, y: logical_position.y });
+                    },
+                    WindowEvent::Touch(touch) => {
+                        let logical_position: LogicalPosition<f32> = touch.location.to_logical(window.render_context.window().scale_factor());
+                        lz_input.register_event(InputEvent::Touch(TouchEvent {
+                            id: TouchId(touch.id),
+                            phase: event_mapper::map_glutin_touch_phase(touch.phase),
+                            x: logical_position.x,
+                            y: logical_position.y,
+                        }));
                     },
                     _ => (),
                 },
                 Event::DeviceEvent { device_id: _, event } => match event {
                     glutin::event::DeviceEvent::MouseMotion { delta } => {
-                        lz_input.mouse.register_move_event(delta.0, delta.1);
+                        lz_input.register_event(InputEvent::Move { x: delta.0, y: delta.1 });
                     },
                     _ => (),
                 }
+                Event::LoopDestroyed => {
+                    if let (Some(path), Some(recording)) = (&profiler_trace_path, profiler::stop_recording()) {
+                        if let Err(err) = recording.save(path) {
+                            log::engine_err(format!("failed to save profiler trace: {}", err));
+                        }
+                    }
+
+                    if let (Some(path), Some(recording)) = (&input_recording_path, lz_input.stop_recording()) {
+                        if let Err(err) = recording.save(path) {
+                            log::engine_err(format!("failed to save input recording: {}", err));
+                        }
+                    }
+                },
                 _ => ()
             }
 
+            if is_input_event { profiler::end_scope(profiler::SCOPE_INPUT); }
+
             match *control_flow {
                 ControlFlow::Exit => (),
                 _ => {
-                    if time::now_millis() < next_frame_time {
-                        *control_flow = ControlFlow::Poll;
+                    let now = Instant::now();
+                    if now < next_frame_time {
+                        *control_flow = ControlFlow::WaitUntil(next_frame_time);
                         return;
                     }
 
-                    interface.update(&mut *asset_manager, &lz_input);
-                    renderer.scene.update(&mut event_system, &lz_input, &mut *asset_manager, &mut interface);
-                    Self::read_event_listeners(&mut event_listeners, &render_context.window());
+                    // The scene gets updated at a fixed rate, independent of the frame rate. If we rendered faster
+                    // than the tick rate, no update is needed. If we rendered slower, we need to catch up.
+                    let update_steps = fixed_timestep.advance(now.duration_since(last_frame_time));
+                    last_frame_time = now;
+
+                    if shader_hot_reload {
+                        asset_manager.reload_changed_shaders();
+                    }
+
+                    lz_input.poll_gamepads();
+                    event_listeners.begin_updates();
+
+                    for _ in 0..update_steps {
+                        profiler::begin_scope(profiler::SCOPE_INTERFACE_UPDATE);
+                        interface.update(&mut event_system, &mut *asset_manager, &mut lz_input);
+                        profiler::end_scope(profiler::SCOPE_INTERFACE_UPDATE);
+
+                        profiler::begin_scope(profiler::SCOPE_SCENE_UPDATE);
+                        renderer.scene_manager.update(&mut event_system, &lz_input, &mut *asset_manager, &mut interface);
+                        profiler::end_scope(profiler::SCOPE_SCENE_UPDATE);
+
+                        event_listeners.read(&window);
+
+                        if screenshot_key.is_some_and(|key| lz_input.keyboard.is_key_down(key)) {
+                            screenshot_paths.push(capture::default_screenshot_path());
+                        }
+                        for event in event_listeners.take_screenshot_listener.read() {
+                            screenshot_paths.push(event.path.unwrap_or_else(capture::default_screenshot_path));
+                        }
+
+                        // Only reset the input after it has been used by an update, so that we don't lose any input
+                        // on frames without an update.
+                        lz_input.reset();
+                    }
+
+                    event_system.dispatch_queued();
+
+                    // Decide about closing after the queued events went out, so queued cancels count as well
+                    if update_steps > 0 && event_listeners.should_close() {
+                        should_exit = true;
+                    }
+
+                    if let Some(overlay) = &mut profiler_overlay {
+                        let result = profiler::with_profiler(|profiler| overlay.update(profiler, &mut interface, &mut *asset_manager));
+                        if let Err(err) = result {
+                            log::engine_err(format!("failed to update profiler overlay: {}", err));
+                        }
+                    }
+
+                    profiler::begin_scope(profiler::SCOPE_DRAW);
+                    renderer.draw(&mut *asset_manager, &mut interface, fixed_timestep.alpha());
+                    profiler::end_scope(profiler::SCOPE_DRAW);
+
+                    if !screenshot_paths.is_empty() {
+                        let size = window.render_context.window().inner_size();
+                        let screenshot = capture::read_pixels(size.width, size.height);
+
+                        for path in screenshot_paths.drain(..) {
+                            match capture::save_png(&screenshot, &path) {
+                                Ok(()) => log::engine_info(format!("saved screenshot to {}", path)),
+                                Err(err) => log::engine_err(format!("failed to save screenshot: {}", err)),
+                            }
+                        }
+                    }
 
-                    renderer.draw(&mut *asset_manager, &mut interface);
-                    render_context.swap_buffers().expect("Failed to swap buffers");
+                    profiler::begin_scope(profiler::SCOPE_SWAP_BUFFERS);
+                    window.render_context.swap_buffers().expect("Failed to swap buffers");
+                    profiler::end_scope(profiler::SCOPE_SWAP_BUFFERS);
 
-                    lz_input.reset();
+                    profiler::end_frame();
 
-                    next_frame_time = get_next_frame_time(start_time, target_fps);
-                    *control_flow = ControlFlow::Poll;
+                    next_frame_time = now + frame_duration;
+                    *control_flow = if should_exit { ControlFlow::Exit } else { ControlFlow::WaitUntil(next_frame_time) };
                 }
             }
         });
@@ -165,10 +317,98 @@ impl Window for GlutinWindow {
     fn get_pixel_density(&self) -> f64 {
         self.render_context.window().scale_factor()
     }
+
+    fn lock_cursor(&self) {
+        match self.render_context.window().set_cursor_grab(glutin::window::CursorGrabMode::Locked) {
+            Ok(_) => (),
+            Err(err) => {
+                log::engine_warn(format!("could not lock cursor: {}",  err));
+            },
+        }
+    }
+
+    fn unlock_cursor(&self) {
+        match self.render_context.window().set_cursor_grab(glutin::window::CursorGrabMode::None) {
+            Ok(_) => (),
+            Err(err) => {
+                log::engine_warn(format!("could not unlock cursor: {}",  err));
+            },
+        }
+    }
+
+    fn confine_cursor(&self) {
+        match self.render_context.window().set_cursor_grab(glutin::window::CursorGrabMode::Confined) {
+            Ok(_) => (),
+            Err(err) => {
+                log::engine_warn(format!("could not confine cursor: {}",  err));
+            },
+        }
+    }
+
+    fn hide_cursor(&self) {
+        self.render_context.window().set_cursor_visible(false);
+    }
+
+    fn show_cursor(&self) {
+        self.render_context.window().set_cursor_visible(true);
+    }
+
+    fn set_cursor_position(&self, x: f32, y: f32) {
+        match self.render_context.window().set_cursor_position(PhysicalPosition{x, y}) {
+            Ok(_) => (),
+            Err(err) => {
+                log::engine_warn(format!("could not set cursor position: {}",  err));
+            },
+        }
+    }
+
+    fn set_title(&self, title: &str) {
+        self.render_context.window().set_title(title);
+    }
+
+    fn set_fullscreen(&self, fullscreen: bool) {
+        if fullscreen {
+            self.render_context.window().set_fullscreen(Some(glutin::window::Fullscreen::Borderless(None)));
+        } else {
+            self.render_context.window().set_fullscreen(None);
+        }
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        self.render_context.window().fullscreen().is_some()
+    }
+
+    fn set_size(&self, width: u32, height: u32) {
+        self.render_context.window().set_inner_size(LogicalSize::new(width, height));
+    }
+
+    fn set_vsync(&self, vsync: bool) {
+        if vsync == self.vsync.get() {
+            return;
+        }
+
+        if swap_interval::set_swap_interval(&self.render_context, if vsync { 1 } else { 0 }) {
+            self.vsync.set(vsync);
+        } else {
+            log::engine_err(format!("failed to turn vsync {}, the graphics api does not support changing it", if vsync { "on" } else { "off" }));
+        }
+    }
+
+    fn is_vsync(&self) -> bool {
+        self.vsync.get()
+    }
+
+    fn set_ime_allowed(&self, allowed: bool) {
+        self.render_context.window().set_ime_allowed(allowed);
+    }
+
+    fn set_ime_position(&self, x: f32, y: f32) {
+        self.render_context.window().set_ime_position(LogicalPosition{x, y});
+    }
 }
 
 impl GlutinWindow {
-    pub fn new(window_builder: &crate::graphics::window::WindowBuilder, event_system: &mut EventSystem) -> Self {
+    pub fn new(window_builder: &crate::graphics::window::WindowBuilder, event_system: &mut EventSystem) -> Result<Self, LazuliError> {
         let mut glutin_window_builder = WindowBuilder::new()
             .with_title(window_builder.name.clone())
             .with_resizable(window_builder.resizable)
@@ -192,84 +432,29 @@ impl GlutinWindow {
         let gl_context = ContextBuilder::new()
             .with_gl(GlRequest::Specific(Api::OpenGl, (3, 3)))
             .with_gl_profile(GlProfile::Core)
+            .with_vsync(window_builder.vsync)
             .build_windowed(glutin_window_builder, &event_loop)
-            .expect("Cannot create window context");
+            .map_err(WindowError::Creation)?;
 
         let render_context = unsafe {
             gl_context
                 .make_current()
-                .expect("Failed to make context current")
+                .map_err(|(_, err)| WindowError::Context(err))?
         };
 
         gl::load_with(|ptr| render_context.get_proc_address(ptr) as *const _);
 
-        return Self {
+        return Ok(Self {
             render_context,
-            event_loop,
-            target_fps: 60,
-            event_listeners: WindowListeners::new(event_system),
-        }
-    }
-
-    fn lock_cursor(window: &glutin::window::Window) {
-        match window.set_cursor_grab(glutin::window::CursorGrabMode::Locked) {
-            Ok(_) => (),
-            Err(err) => {
-                log::engine_warn(format!("could not lock cursor: {}",  err.to_string()));
-            },
-        }
-    }
-
-    fn unlock_cursor(window: &glutin::window::Window) {
-        match window.set_cursor_grab(glutin::window::CursorGrabMode::None) {
-            Ok(_) => (),
-            Err(err) => {
-                log::engine_warn(format!("could not unlock cursor: {}",  err.to_string()));
-            },
-        }
-    }
-
-    /// Confine the cursor the the window area
-    fn confine_cursor(window: &glutin::window::Window) {
-        match window.set_cursor_grab(glutin::window::CursorGrabMode::Confined) {
-            Ok(_) => (),
-            Err(err) => {
-                log::engine_warn(format!("could not confine cursor: {}",  err.to_string()));
-            },
-        }
-    }
-
-    fn hide_cursor(window: &glutin::window::Window) {
-        window.set_cursor_visible(false);
-    }
-
-    fn show_cursor(window: &glutin::window::Window) {
-        window.set_cursor_visible(true);
-    }
-
-    fn set_cursor_position(window: &glutin::window::Window, x: f32, y: f32) {
-        match window.set_cursor_position(PhysicalPosition{x, y}) {
-            Ok(_) => (),
-            Err(err) => {
-                log::engine_warn(format!("could not set cursor position: {}",  err.to_string()));
-            },
-        }
-    }
-
-    fn read_event_listeners(event_listeners: &mut WindowListeners, window: &glutin::window::Window) {
-        if event_listeners.lock_cursor_listener.read().len() > 0 { Self::lock_cursor(window) }
-        if event_listeners.unlock_cursor_listener.read().len() > 0 { Self::unlock_cursor(window) }
-        if event_listeners.confine_cursor_listener.read().len() > 0 { Self::confine_cursor(window) }
-        if event_listeners.hide_cursor_listener.read().len() > 0 { Self::hide_cursor(window) }
-        if event_listeners.show_cursor_listener.read().len() > 0 { Self::show_cursor(window) }
-        if let Some(event) = event_listeners.set_cursor_position_listener.read().last() {
-            Self::set_cursor_position(window, event.x, event.y);
-        }
+            event_loop: Some(event_loop),
+            target_fps: window_builder.target_fps,
+            show_profiler: window_builder.show_profiler,
+            profiler_trace_path: window_builder.profiler_trace_path.clone().or_else(|| std::env::var(profiler::TRACE_PATH_ENV_VAR).ok().filter(|path| !path.is_empty())),
+            shader_hot_reload: window_builder.shader_hot_reload,
+            input_recording_path: window_builder.input_recording_path.clone().or_else(|| std::env::var(INPUT_RECORDING_PATH_ENV_VAR).ok().filter(|path| !path.is_empty())),
+            screenshot_key: window_builder.screenshot_key,
+            event_listeners: Some(WindowListeners::new(event_system)),
+            vsync: Cell::new(window_builder.vsync),
+        })
     }
 }
-
-fn get_next_frame_time(start_time: Instant, target_fps: u64) -> u128 {
-    let elapsed_time = Instant::now().duration_since(start_time).as_millis() as u128;
-    let frame_time_ms = 1_000 / target_fps as u128;
-    return time::now_millis() + frame_time_ms - elapsed_time;
-}
@@ -12,3 +12,8 @@ pub use window_builder::WindowSize;
 
 mod window_api;
 pub use window_api::WindowApi;
+
+#[cfg(test)]
+mod window_listeners_test;
+#[cfg(test)]
+mod window_builder_test;
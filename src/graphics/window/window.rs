@@ -13,12 +13,30 @@ pub trait Window {
 
     /// dpi
     fn get_pixel_density(&self) -> f64;
-    
-    // TODO
-    // fn lock_cursor(&self);
-    // fn unlock_cursor(&self);
-    // fn confine_cursor(&self);
-    // fn hide_cursor(&self);
-    // fn show_cursor(&self);
-    // fn set_cursor_position(&self, x: f32, y: f32);
+
+    /// Keep the cursor in place and hand out mouse movement only, like in a first person game
+    fn lock_cursor(&self);
+    fn unlock_cursor(&self);
+    /// Keep the cursor within the window area
+    fn confine_cursor(&self);
+    fn hide_cursor(&self);
+    fn show_cursor(&self);
+    fn set_cursor_position(&self, x: f32, y: f32);
+
+    fn set_title(&self, title: &str);
+    /// Borderless fullscreen on the current monitor
+    fn set_fullscreen(&self, fullscreen: bool);
+    fn is_fullscreen(&self) -> bool;
+    /// in logical units
+    fn set_size(&self, width: u32, height: u32);
+    /// Not every platform supports changing vsync after the window has been created. If it can not be changed,
+    /// an error gets logged and `is_vsync` keeps returning the old value.
+    fn set_vsync(&self, vsync: bool);
+    fn is_vsync(&self) -> bool;
+
+    /// Allow input method editors (IME) to compose text. Should only be enabled while a text field has focus,
+    /// because an IME can take over keyboard input.
+    fn set_ime_allowed(&self, allowed: bool);
+    /// Where the IME should show its candidate box, in logical units. This usually is the text cursor.
+    fn set_ime_position(&self, x: f32, y: f32);
 }
@@ -1,4 +1,4 @@
-use crate::event::EventSystem;
+use crate::{error::LazuliError, event::EventSystem};
 
 use super::{Window, GlutinWindow, WindowBuilder};
 
@@ -7,11 +7,11 @@ pub enum WindowApi {
 }
 
 impl WindowApi {
-    pub fn build(&self, window_builder: &WindowBuilder, event_system: &mut EventSystem) -> Box<dyn Window> {
+    pub fn build(&self, window_builder: &WindowBuilder, event_system: &mut EventSystem) -> Result<Box<dyn Window>, LazuliError> {
         match self {
             WindowApi::Glutin => {
-                let window = GlutinWindow::new(window_builder, event_system);
-                Box::new(window)
+                let window = GlutinWindow::new(window_builder, event_system)?;
+                Ok(Box::new(window))
             },
         }
     }
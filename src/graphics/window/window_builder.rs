@@ -1,4 +1,4 @@
-use crate::event::EventSystem;
+use crate::{error::LazuliError, event::EventSystem, input::Key};
 
 use super::{WindowApi, Window};
 
@@ -7,6 +7,23 @@ pub struct WindowBuilder {
     pub size: WindowSize,
     pub resizable: bool,
     pub window_api: WindowApi,
+    /// Maximum number of frames that get drawn per second. This is independent of the rate at which the
+    /// scene gets updated, which is `time::TICK_RATE`. Has to be at least 1.
+    pub target_fps: u64,
+    /// Measure how long each phase of the main loop takes and show the results on screen
+    pub show_profiler: bool,
+    /// Record all profiler measurements and save them as `<path>.json` (Chrome trace) and `<path>.csv` when the
+    /// window closes. When not set, the `LAZULI_PROFILER_TRACE` environment variable gets used.
+    pub profiler_trace_path: Option<String>,
+    /// Rebuild shaders when their source files change on disk, so they can be edited while the app is running
+    pub shader_hot_reload: bool,
+    /// Record all input and save it to this path when the window closes, so the session can be replayed with
+    /// `HeadlessApp::replay_input`. When not set, the `LAZULI_INPUT_RECORDING` environment variable gets used.
+    pub input_recording_path: Option<String>,
+    /// Pressing this key saves the next frame as a png file in the working directory
+    pub screenshot_key: Option<Key>,
+    /// Wait for the monitor to refresh before showing a frame. Can be changed later with the `SetVsync` event.
+    pub vsync: bool,
 }
 
 pub enum WindowSize {
@@ -22,10 +39,17 @@ impl WindowBuilder {
             size: WindowSize::Pixels(800, 600),
             resizable: true,
             window_api: WindowApi::Glutin,
+            target_fps: 60,
+            show_profiler: false,
+            profiler_trace_path: None,
+            shader_hot_reload: false,
+            input_recording_path: None,
+            screenshot_key: None,
+            vsync: false,
         }
     }
 
-    pub fn build(&self, event_system: &mut EventSystem) -> Box<dyn Window> {
+    pub fn build(&self, event_system: &mut EventSystem) -> Result<Box<dyn Window>, LazuliError> {
         self.window_api.build(&self, event_system)
     }
 
@@ -56,6 +80,44 @@ impl WindowBuilder {
         self
     }
 
+    /// Panics if `target_fps` is 0.
+    pub fn with_target_fps(mut self, target_fps: u64) -> Self {
+        assert!(target_fps > 0, "WindowBuilder target fps must be at least 1");
+        self.target_fps = target_fps;
+        self
+    }
+
+    pub fn with_profiler(mut self, show_profiler: bool) -> Self {
+        self.show_profiler = show_profiler;
+        self
+    }
+
+    /// `path` should not have an extension, because both a `.json` and a `.csv` file get saved
+    pub fn with_profiler_trace(mut self, path: impl Into<String>) -> Self {
+        self.profiler_trace_path = Some(path.into());
+        self
+    }
+
+    pub fn with_shader_hot_reload(mut self, shader_hot_reload: bool) -> Self {
+        self.shader_hot_reload = shader_hot_reload;
+        self
+    }
+
+    pub fn with_input_recording(mut self, path: impl Into<String>) -> Self {
+        self.input_recording_path = Some(path.into());
+        self
+    }
+
+    pub fn with_screenshot_key(mut self, key: Key) -> Self {
+        self.screenshot_key = Some(key);
+        self
+    }
+
+    pub fn with_vsync(mut self, vsync: bool) -> Self {
+        self.vsync = vsync;
+        self
+    }
+
     pub fn with_window_api(mut self, window_api: WindowApi) -> Self {
         self.window_api = window_api;
         self
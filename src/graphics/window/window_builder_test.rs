@@ -0,0 +1,12 @@
+use super::WindowBuilder;
+
+#[test]
+fn test_target_fps() {
+    assert_eq!(144, WindowBuilder::new().with_target_fps(144).target_fps);
+}
+
+#[test]
+#[should_panic(expected = "target fps must be at least 1")]
+fn test_zero_target_fps() {
+    let _ = WindowBuilder::new().with_target_fps(0);
+}
@@ -1,4 +1,4 @@
-use crate::event::{EventReader, self, EventSystem};
+use crate::{event::{EventReader, self, EventSystem}, graphics::Window};
 
 pub struct WindowListeners {
     pub lock_cursor_listener: EventReader<event::LockCursor>,
@@ -7,6 +7,19 @@ pub struct WindowListeners {
     pub show_cursor_listener: EventReader<event::ShowCursor>,
     pub hide_cursor_listener: EventReader<event::HideCursor>,
     pub set_cursor_position_listener: EventReader<event::SetCursorPosition>,
+    pub set_window_title_listener: EventReader<event::SetWindowTitle>,
+    pub set_fullscreen_listener: EventReader<event::SetFullscreen>,
+    pub toggle_fullscreen_listener: EventReader<event::ToggleFullscreen>,
+    pub set_window_size_listener: EventReader<event::SetWindowSize>,
+    pub set_vsync_listener: EventReader<event::SetVsync>,
+    pub set_ime_allowed_listener: EventReader<event::SetImeAllowed>,
+    pub set_ime_position_listener: EventReader<event::SetImePosition>,
+    pub take_screenshot_listener: EventReader<event::TakeScreenshot>,
+    pub cancel_close_listener: EventReader<event::CancelClose>,
+    pub close_window_listener: EventReader<event::CloseWindow>,
+    close_requested_listener: EventReader<event::CloseRequested>,
+    /// Whether a `CloseRequested` reached the scenes and still needs a decision at the end of the frame
+    is_close_pending: bool,
 }
 
 impl WindowListeners {
@@ -18,18 +31,82 @@ impl WindowListeners {
             show_cursor_listener: event_system.register::<event::ShowCursor>(),
             hide_cursor_listener: event_system.register::<event::HideCursor>(),
             set_cursor_position_listener: event_system.register::<event::SetCursorPosition>(),
+            set_window_title_listener: event_system.register::<event::SetWindowTitle>(),
+            set_fullscreen_listener: event_system.register::<event::SetFullscreen>(),
+            toggle_fullscreen_listener: event_system.register::<event::ToggleFullscreen>(),
+            set_window_size_listener: event_system.register::<event::SetWindowSize>(),
+            set_vsync_listener: event_system.register::<event::SetVsync>(),
+            set_ime_allowed_listener: event_system.register::<event::SetImeAllowed>(),
+            set_ime_position_listener: event_system.register::<event::SetImePosition>(),
+            take_screenshot_listener: event_system.register::<event::TakeScreenshot>(),
+            cancel_close_listener: event_system.register::<event::CancelClose>(),
+            close_window_listener: event_system.register::<event::CloseWindow>(),
+            close_requested_listener: event_system.register::<event::CloseRequested>(),
+            is_close_pending: false,
         }
     }
 
-    // TODO
-    // pub fn read(&mut self, window: &dyn Window) {
-    //     if self.lock_cursor_listener.read().len() > 0 { window.lock_cursor() }
-    //     if self.unlock_cursor_listener.read().len() > 0 { window.unlock_cursor() }
-    //     if self.confine_cursor_listener.read().len() > 0 { window.confine_cursor() }
-    //     if self.hide_cursor_listener.read().len() > 0 { window.hide_cursor() }
-    //     if self.show_cursor_listener.read().len() > 0 { window.show_cursor() }
-    //     if let Some(event) = self.set_cursor_position_listener.read().last() {
-    //         window.set_cursor_position(event.x, event.y);
-    //     }
-    // }
+    /// Apply the window control events that were sent since the last read. Only the last event of a kind
+    /// matters for events that set a value, and toggles that cancel each other out are skipped.
+    pub fn read(&mut self, window: &dyn Window) {
+        if !self.lock_cursor_listener.read().is_empty() { window.lock_cursor() }
+        if !self.unlock_cursor_listener.read().is_empty() { window.unlock_cursor() }
+        if !self.confine_cursor_listener.read().is_empty() { window.confine_cursor() }
+        if !self.hide_cursor_listener.read().is_empty() { window.hide_cursor() }
+        if !self.show_cursor_listener.read().is_empty() { window.show_cursor() }
+        if let Some(event) = self.set_cursor_position_listener.read().last() {
+            window.set_cursor_position(event.x, event.y);
+        }
+
+        if let Some(event) = self.set_window_title_listener.read().last() {
+            window.set_title(&event.title);
+        }
+        if let Some(event) = self.set_fullscreen_listener.read().last() {
+            window.set_fullscreen(event.fullscreen);
+        }
+        if self.toggle_fullscreen_listener.read().len() % 2 == 1 {
+            window.set_fullscreen(!window.is_fullscreen());
+        }
+        if let Some(event) = self.set_window_size_listener.read().last() {
+            window.set_size(event.width, event.height);
+        }
+        if let Some(event) = self.set_vsync_listener.read().last() {
+            window.set_vsync(event.vsync);
+        }
+
+        if let Some(event) = self.set_ime_allowed_listener.read().last() {
+            window.set_ime_allowed(event.allowed);
+        }
+        if let Some(event) = self.set_ime_position_listener.read().last() {
+            window.set_ime_position(event.x, event.y);
+        }
+    }
+
+    /// Let the scenes know that the user tried to close the window. Cancels that were sent before get forgotten.
+    pub fn request_close(&mut self, event_system: &mut EventSystem) {
+        self.cancel_close_listener.read();
+        event_system.send(event::CloseRequested {});
+    }
+
+    /// Call before the updates of a frame. A close request that reached the scenes by now gets decided at the end
+    /// of the frame, so the updates get a chance to cancel it. With the `Queued` dispatch mode the request only
+    /// arrives at the end of a frame, so the decision happens one frame later.
+    pub fn begin_updates(&mut self) {
+        if !self.close_requested_listener.read().is_empty() {
+            self.is_close_pending = true;
+        }
+    }
+
+    /// Whether the window should close. Call at the end of a frame with updates, after
+    /// `EventSystem::dispatch_queued`, so that queued cancels count as well.
+    pub fn should_close(&mut self) -> bool {
+        let mut should_close = !self.close_window_listener.read().is_empty();
+
+        if self.is_close_pending {
+            self.is_close_pending = false;
+            should_close |= self.cancel_close_listener.read().is_empty();
+        }
+
+        should_close
+    }
 }
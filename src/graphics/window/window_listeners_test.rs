@@ -0,0 +1,183 @@
+use std::cell::RefCell;
+
+use glam::Vec2;
+
+use crate::{asset_manager::AssetManager, event::{CancelClose, CloseRequested, CloseWindow, DispatchMode, EventSystem, LockCursor, SetCursorPosition, SetFullscreen, SetImeAllowed, SetImePosition, SetVsync, SetWindowSize, SetWindowTitle, ToggleFullscreen}, graphics::{renderer::Renderer, ui::Interface, Window}, input::Input};
+
+use super::window_listeners::WindowListeners;
+
+/// Keeps track of the calls that a `WindowListeners` makes
+#[derive(Default)]
+struct MockWindow {
+    calls: RefCell<Vec<String>>,
+    fullscreen: RefCell<bool>,
+}
+
+impl MockWindow {
+    fn call(&self, call: impl Into<String>) {
+        self.calls.borrow_mut().push(call.into());
+    }
+}
+
+impl Window for MockWindow {
+    fn run(self: Box<Self>, _renderer: Renderer, _event_system: EventSystem, _lz_input: Input, _asset_manager: Box<dyn AssetManager>, _interface: Interface) {}
+    fn get_size(&self) -> Vec2 { Vec2::new(800.0, 600.0) }
+    fn frame_size(&self) -> Vec2 { Vec2::ZERO }
+    fn get_pixel_density(&self) -> f64 { 1.0 }
+
+    fn lock_cursor(&self) { self.call("lock_cursor") }
+    fn unlock_cursor(&self) { self.call("unlock_cursor") }
+    fn confine_cursor(&self) { self.call("confine_cursor") }
+    fn hide_cursor(&self) { self.call("hide_cursor") }
+    fn show_cursor(&self) { self.call("show_cursor") }
+    fn set_cursor_position(&self, x: f32, y: f32) { self.call(format!("set_cursor_position {} {}", x, y)) }
+    fn set_title(&self, title: &str) { self.call(format!("set_title {}", title)) }
+    fn set_fullscreen(&self, fullscreen: bool) {
+        *self.fullscreen.borrow_mut() = fullscreen;
+        self.call(format!("set_fullscreen {}", fullscreen));
+    }
+    fn is_fullscreen(&self) -> bool { *self.fullscreen.borrow() }
+    fn set_size(&self, width: u32, height: u32) { self.call(format!("set_size {} {}", width, height)) }
+    fn set_vsync(&self, vsync: bool) { self.call(format!("set_vsync {}", vsync)) }
+    fn is_vsync(&self) -> bool { false }
+    fn set_ime_allowed(&self, allowed: bool) { self.call(format!("set_ime_allowed {}", allowed)) }
+    fn set_ime_position(&self, x: f32, y: f32) { self.call(format!("set_ime_position {} {}", x, y)) }
+}
+
+#[test]
+fn test_read_applies_events_to_window() {
+    let mut event_system = EventSystem::new();
+    let mut listeners = WindowListeners::new(&mut event_system);
+    let window = MockWindow::default();
+
+    event_system.send(LockCursor {});
+    event_system.send(SetCursorPosition { x: 1.0, y: 2.0 });
+    event_system.send(SetWindowTitle { title: "first".to_string() });
+    event_system.send(SetWindowTitle { title: "second".to_string() });
+    event_system.send(SetWindowSize { width: 640, height: 480 });
+    event_system.send(SetVsync { vsync: false });
+    event_system.send(SetImeAllowed { allowed: true });
+    event_system.send(SetImePosition { x: 3.0, y: 4.0 });
+
+    listeners.read(&window);
+
+    assert_eq!(vec![
+        "lock_cursor",
+        "set_cursor_position 1 2",
+        "set_title second",
+        "set_size 640 480",
+        "set_vsync false",
+        "set_ime_allowed true",
+        "set_ime_position 3 4",
+    ], *window.calls.borrow());
+}
+
+#[test]
+fn test_toggle_fullscreen() {
+    let mut event_system = EventSystem::new();
+    let mut listeners = WindowListeners::new(&mut event_system);
+    let window = MockWindow::default();
+
+    event_system.send(ToggleFullscreen {});
+    listeners.read(&window);
+    assert!(window.is_fullscreen());
+
+    event_system.send(ToggleFullscreen {});
+    event_system.send(ToggleFullscreen {});
+    listeners.read(&window);
+    assert!(window.is_fullscreen());
+
+    event_system.send(SetFullscreen { fullscreen: false });
+    listeners.read(&window);
+    assert!(!window.is_fullscreen());
+
+    assert_eq!(vec!["set_fullscreen true", "set_fullscreen false"], *window.calls.borrow());
+}
+
+#[test]
+fn test_close_without_cancel() {
+    let mut event_system = EventSystem::new();
+    let mut listeners = WindowListeners::new(&mut event_system);
+
+    assert!(!listeners.should_close());
+
+    listeners.request_close(&mut event_system);
+    listeners.begin_updates();
+    event_system.dispatch_queued();
+    assert!(listeners.should_close());
+}
+
+#[test]
+fn test_close_window() {
+    let mut event_system = EventSystem::new();
+    let mut listeners = WindowListeners::new(&mut event_system);
+
+    event_system.send(CloseWindow {});
+    assert!(listeners.should_close());
+    assert!(!listeners.should_close());
+}
+
+#[test]
+fn test_cancel_close() {
+    let mut event_system = EventSystem::new();
+    let mut listeners = WindowListeners::new(&mut event_system);
+    let mut close_requested_listener = event_system.register::<CloseRequested>();
+
+    // A cancel from before the request does not count
+    event_system.send(CancelClose {});
+    listeners.request_close(&mut event_system);
+    listeners.begin_updates();
+    assert_eq!(close_requested_listener.read().len(), 1);
+    event_system.send(CancelClose {});
+    event_system.dispatch_queued();
+    assert!(!listeners.should_close());
+
+    // The request is handled, so the window stays open afterwards
+    listeners.begin_updates();
+    event_system.dispatch_queued();
+    assert!(!listeners.should_close());
+}
+
+#[test]
+fn test_queued_cancel_close() {
+    let mut event_system = EventSystem::new();
+    event_system.set_dispatch_mode::<CancelClose>(DispatchMode::Queued);
+    let mut listeners = WindowListeners::new(&mut event_system);
+
+    listeners.request_close(&mut event_system);
+    listeners.begin_updates();
+    event_system.send(CancelClose {});
+    event_system.dispatch_queued();
+    assert!(!listeners.should_close());
+}
+
+#[test]
+fn test_queued_close_requested() {
+    let mut event_system = EventSystem::new();
+    event_system.set_dispatch_mode::<CloseRequested>(DispatchMode::Queued);
+    let mut listeners = WindowListeners::new(&mut event_system);
+    let mut close_requested_listener = event_system.register::<CloseRequested>();
+
+    // The request only reaches the scenes at the end of the frame, so the window has to stay open
+    listeners.request_close(&mut event_system);
+    listeners.begin_updates();
+    assert!(close_requested_listener.read().is_empty());
+    event_system.dispatch_queued();
+    assert!(!listeners.should_close());
+
+    // The next frame, the scene gets the request and cancels it
+    listeners.begin_updates();
+    assert_eq!(close_requested_listener.read().len(), 1);
+    event_system.send(CancelClose {});
+    event_system.dispatch_queued();
+    assert!(!listeners.should_close());
+
+    // Without a cancel, the window closes one frame after the request
+    listeners.request_close(&mut event_system);
+    listeners.begin_updates();
+    event_system.dispatch_queued();
+    assert!(!listeners.should_close());
+    listeners.begin_updates();
+    event_system.dispatch_queued();
+    assert!(listeners.should_close());
+}
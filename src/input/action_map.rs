@@ -0,0 +1,325 @@
+use std::{cell::RefCell, collections::{BTreeMap, BTreeSet}, fs, path::Path};
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::{error::{AssetError, LazuliError}, log};
+
+use super::{GamepadAxis, GamepadButton, Input, Key, MouseButton, Shortcut};
+
+/// Something the player can press to trigger an action
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(Key),
+    MouseButton(MouseButton),
+    /// Only counts while exactly the modifiers of the shortcut are held
+    Shortcut(Shortcut),
+    /// Counts for every connected gamepad
+    GamepadButton(GamepadButton),
+    /// Counts as pressed and held during every frame the mouse scrolled in this direction
+    ScrollUp,
+    ScrollDown,
+    ScrollLeft,
+    ScrollRight,
+}
+
+impl InputBinding {
+    fn is_pressed(&self, input: &Input) -> bool {
+        if self.is_consumed_by_ui(input) {
+            return false;
+        }
+
+        match *self {
+            InputBinding::Key(key) => input.keyboard.is_key_down(key),
+            InputBinding::MouseButton(button) => input.mouse.is_button_down(button),
+            InputBinding::Shortcut(shortcut) => input.keyboard.is_shortcut_triggered(&shortcut),
+            InputBinding::GamepadButton(button) => input.gamepads.connected_gamepads().into_iter().any(|id| input.gamepads.is_button_down(id, button)),
+            _ => self.is_scrolling(input),
+        }
+    }
+
+    fn is_released(&self, input: &Input) -> bool {
+        if self.is_consumed_by_ui(input) {
+            return false;
+        }
+
+        match *self {
+            InputBinding::Key(key) => input.keyboard.is_key_up(key),
+            InputBinding::MouseButton(button) => input.mouse.is_button_up(button),
+            InputBinding::Shortcut(shortcut) => input.keyboard.is_key_up(shortcut.key),
+            InputBinding::GamepadButton(button) => input.gamepads.connected_gamepads().into_iter().any(|id| input.gamepads.is_button_up(id, button)),
+            _ => false,
+        }
+    }
+
+    fn is_held(&self, input: &Input) -> bool {
+        if self.is_consumed_by_ui(input) {
+            return false;
+        }
+
+        match *self {
+            InputBinding::Key(key) => input.keyboard.is_key_held(key),
+            InputBinding::MouseButton(button) => input.mouse.is_button_held(button),
+            InputBinding::Shortcut(shortcut) => input.keyboard.is_shortcut_held(&shortcut),
+            InputBinding::GamepadButton(button) => input.gamepads.connected_gamepads().into_iter().any(|id| input.gamepads.is_button_held(id, button)),
+            _ => self.is_scrolling(input),
+        }
+    }
+
+    /// Input the interface used shouldn't also trigger actions in the scene
+    fn is_consumed_by_ui(&self, input: &Input) -> bool {
+        match self {
+            InputBinding::Key(_) | InputBinding::Shortcut(_) => input.keyboard.is_consumed_by_ui(),
+            InputBinding::GamepadButton(_) => false,
+            _ => input.mouse.is_consumed_by_ui(),
+        }
+    }
+
+    fn is_scrolling(&self, input: &Input) -> bool {
+        match *self {
+            InputBinding::ScrollUp => input.mouse.get_scroll_y() > 0.0,
+            InputBinding::ScrollDown => input.mouse.get_scroll_y() < 0.0,
+            InputBinding::ScrollLeft => input.mouse.get_scroll_x() < 0.0,
+            InputBinding::ScrollRight => input.mouse.get_scroll_x() > 0.0,
+            _ => false,
+        }
+    }
+}
+
+/// A source of a value along a single axis
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AxisBinding {
+    /// -1 while `negative` is held, 1 while `positive` is held and 0 when both or neither are held
+    Buttons { negative: InputBinding, positive: InputBinding },
+    ScrollX,
+    ScrollY,
+    MouseMoveX,
+    MouseMoveY,
+    /// Read from the primary gamepad, with dead zones applied
+    GamepadAxis(GamepadAxis),
+}
+
+impl AxisBinding {
+    pub fn keys(negative: Key, positive: Key) -> Self {
+        AxisBinding::Buttons { negative: InputBinding::Key(negative), positive: InputBinding::Key(positive) }
+    }
+
+    fn value(&self, input: &Input) -> f32 {
+        let is_mouse_axis = matches!(self, AxisBinding::ScrollX | AxisBinding::ScrollY | AxisBinding::MouseMoveX | AxisBinding::MouseMoveY);
+        if is_mouse_axis && input.mouse.is_consumed_by_ui() {
+            return 0.0;
+        }
+
+        match self {
+            AxisBinding::Buttons { negative, positive } => {
+                let mut value = 0.0;
+                if negative.is_held(input) { value -= 1.0; }
+                if positive.is_held(input) { value += 1.0; }
+                value
+            },
+            AxisBinding::ScrollX => input.mouse.get_scroll_x() as f32,
+            AxisBinding::ScrollY => input.mouse.get_scroll_y() as f32,
+            AxisBinding::MouseMoveX => input.mouse.get_moved_x() as f32,
+            AxisBinding::MouseMoveY => input.mouse.get_moved_y() as f32,
+            AxisBinding::GamepadAxis(axis) => input.gamepads.primary().map(|id| input.gamepads.axis(id, *axis)).unwrap_or(0.0),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Axis2dBinding {
+    pub x: Vec<AxisBinding>,
+    pub y: Vec<AxisBinding>,
+}
+
+impl Axis2dBinding {
+    pub fn new(x: AxisBinding, y: AxisBinding) -> Self {
+        Self { x: vec![x], y: vec![y] }
+    }
+
+    /// For example WASD: `Axis2dBinding::keys(Key::A, Key::D, Key::S, Key::W)`
+    pub fn keys(left: Key, right: Key, down: Key, up: Key) -> Self {
+        Self::new(AxisBinding::keys(left, right), AxisBinding::keys(down, up))
+    }
+}
+
+/// Maps named actions and axes to the inputs that trigger them, so controls can be remapped without changing
+/// game code. The bindings can be changed at runtime and saved as JSON. Use `read` to query the actions for
+/// the input of the current update. Input that was consumed by the interface doesn't trigger any bindings.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ActionMap {
+    actions: BTreeMap<String, Vec<InputBinding>>,
+    axes: BTreeMap<String, Vec<AxisBinding>>,
+    axes_2d: BTreeMap<String, Axis2dBinding>,
+    /// Unknown names that were already warned about, so polling a misspelled action only warns once
+    #[serde(skip)]
+    warned_names: RefCell<BTreeSet<String>>,
+}
+
+impl PartialEq for ActionMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.actions == other.actions && self.axes == other.axes && self.axes_2d == other.axes_2d
+    }
+}
+
+impl ActionMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_action(mut self, action: impl Into<String>, binding: InputBinding) -> Self {
+        self.bind_action(action, binding);
+        self
+    }
+
+    pub fn with_axis(mut self, axis: impl Into<String>, binding: AxisBinding) -> Self {
+        self.bind_axis(axis, binding);
+        self
+    }
+
+    pub fn with_axis_2d(mut self, axis: impl Into<String>, binding: Axis2dBinding) -> Self {
+        self.bind_axis_2d(axis, binding);
+        self
+    }
+
+    /// Adds a binding to the action, keeping the bindings it already had
+    pub fn bind_action(&mut self, action: impl Into<String>, binding: InputBinding) {
+        let bindings = self.actions.entry(action.into()).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Replaces all bindings of the action, for example after the player picked a new key in a settings menu
+    pub fn rebind_action(&mut self, action: impl Into<String>, bindings: Vec<InputBinding>) {
+        self.actions.insert(action.into(), bindings);
+    }
+
+    pub fn unbind_action(&mut self, action: &str, binding: InputBinding) {
+        if let Some(bindings) = self.actions.get_mut(action) {
+            bindings.retain(|b| *b != binding);
+        }
+    }
+
+    pub fn action_bindings(&self, action: &str) -> &[InputBinding] {
+        self.actions.get(action).map(|bindings| bindings.as_slice()).unwrap_or(&[])
+    }
+
+    /// Adds a binding to the axis. The values of all bindings get added up.
+    pub fn bind_axis(&mut self, axis: impl Into<String>, binding: AxisBinding) {
+        self.axes.entry(axis.into()).or_default().push(binding);
+    }
+
+    pub fn rebind_axis(&mut self, axis: impl Into<String>, bindings: Vec<AxisBinding>) {
+        self.axes.insert(axis.into(), bindings);
+    }
+
+    pub fn axis_bindings(&self, axis: &str) -> &[AxisBinding] {
+        self.axes.get(axis).map(|bindings| bindings.as_slice()).unwrap_or(&[])
+    }
+
+    /// Replaces the bindings of the 2d axis
+    pub fn bind_axis_2d(&mut self, axis: impl Into<String>, binding: Axis2dBinding) {
+        self.axes_2d.insert(axis.into(), binding);
+    }
+
+    pub fn axis_2d_binding(&self, axis: &str) -> Option<&Axis2dBinding> {
+        self.axes_2d.get(axis)
+    }
+
+    /// Removes the action or axis with this name, together with all of its bindings
+    pub fn remove(&mut self, name: &str) {
+        self.actions.remove(name);
+        self.axes.remove(name);
+        self.axes_2d.remove(name);
+    }
+
+    pub fn read<'a>(&'a self, input: &'a Input) -> Actions<'a> {
+        Actions { action_map: self, input }
+    }
+
+    pub fn to_json(&self) -> Result<String, LazuliError> {
+        serde_json::to_string_pretty(self).map_err(|err| AssetError::Json { what: "action map", source: err }.into())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, LazuliError> {
+        serde_json::from_str(json).map_err(|err| AssetError::Json { what: "action map", source: err }.into())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), LazuliError> {
+        fs::write(path.as_ref(), self.to_json()?).map_err(|err| {
+            AssetError::Io { path: path.as_ref().to_string_lossy().to_string(), source: err }.into()
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LazuliError> {
+        let json = fs::read_to_string(path.as_ref()).map_err(|err| {
+            LazuliError::from(AssetError::Io { path: path.as_ref().to_string_lossy().to_string(), source: err })
+        })?;
+
+        Self::from_json(&json)
+    }
+
+    fn get_action(&self, action: &str) -> &[InputBinding] {
+        match self.actions.get(action) {
+            Some(bindings) => bindings,
+            None => {
+                self.warn_unknown("action", action);
+                &[]
+            },
+        }
+    }
+
+    fn warn_unknown(&self, kind: &str, name: &str) {
+        if self.warned_names.borrow_mut().insert(format!("{} {}", kind, name)) {
+            log::engine_warn(format!("ActionMap has no {} named {:?}", kind, name));
+        }
+    }
+}
+
+/// The state of the actions of an `ActionMap` during the current update
+pub struct Actions<'a> {
+    action_map: &'a ActionMap,
+    input: &'a Input,
+}
+
+impl<'a> Actions<'a> {
+    /// Whether any of the bindings of the action got pressed during this update
+    pub fn is_pressed(&self, action: &str) -> bool {
+        self.action_map.get_action(action).iter().any(|binding| binding.is_pressed(self.input))
+    }
+
+    /// Whether any of the bindings of the action got released during this update
+    pub fn is_released(&self, action: &str) -> bool {
+        self.action_map.get_action(action).iter().any(|binding| binding.is_released(self.input))
+    }
+
+    pub fn is_held(&self, action: &str) -> bool {
+        self.action_map.get_action(action).iter().any(|binding| binding.is_held(self.input))
+    }
+
+    /// The sum of the values of all bindings of the axis
+    pub fn axis(&self, axis: &str) -> f32 {
+        match self.action_map.axes.get(axis) {
+            Some(bindings) => bindings.iter().map(|binding| binding.value(self.input)).sum(),
+            None => {
+                self.action_map.warn_unknown("axis", axis);
+                0.0
+            },
+        }
+    }
+
+    /// Not normalized, so use `clamp_length_max(1.0)` on the result to prevent moving faster diagonally
+    pub fn axis_2d(&self, axis: &str) -> Vec2 {
+        match self.action_map.axes_2d.get(axis) {
+            Some(binding) => Vec2::new(
+                binding.x.iter().map(|b| b.value(self.input)).sum(),
+                binding.y.iter().map(|b| b.value(self.input)).sum(),
+            ),
+            None => {
+                self.action_map.warn_unknown("2d axis", axis);
+                Vec2::ZERO
+            },
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use glam::Vec2;
+
+use crate::input::{ActionMap, Axis2dBinding, AxisBinding, ButtonState, Input, InputBinding, Key, MouseButton};
+
+fn action_map() -> ActionMap {
+    ActionMap::new()
+        .with_action("jump", InputBinding::Key(Key::Space))
+        .with_action("jump", InputBinding::MouseButton(MouseButton::Right))
+        .with_action("zoom in", InputBinding::ScrollUp)
+        .with_axis("turn", AxisBinding::keys(Key::Q, Key::E))
+        .with_axis("turn", AxisBinding::MouseMoveX)
+        .with_axis_2d("move", Axis2dBinding::keys(Key::A, Key::D, Key::S, Key::W))
+}
+
+#[test]
+fn test_actions() {
+    let action_map = action_map();
+    let mut input = Input::new();
+
+    input.mouse.register_button_event(MouseButton::Right, ButtonState::Down);
+    input.mouse.register_scroll_y_event(1.0);
+    {
+        let actions = action_map.read(&input);
+        assert!(actions.is_pressed("jump"));
+        assert!(actions.is_held("jump"));
+        assert!(!actions.is_released("jump"));
+        assert!(actions.is_pressed("zoom in"));
+        assert!(!actions.is_pressed("unknown action"));
+    }
+
+    // next frame
+    input.reset();
+    input.mouse.register_button_event(MouseButton::Right, ButtonState::Up);
+    let actions = action_map.read(&input);
+    assert!(!actions.is_pressed("jump"));
+    assert!(!actions.is_held("jump"));
+    assert!(actions.is_released("jump"));
+    assert!(!actions.is_pressed("zoom in"));
+}
+
+#[test]
+fn test_axes() {
+    let action_map = action_map();
+    let mut input = Input::new();
+
+    input.keyboard.register_key_event(Key::E, ButtonState::Down);
+    input.mouse.register_move_event(0.5, 0.0);
+    input.keyboard.register_key_event(Key::W, ButtonState::Down);
+    input.keyboard.register_key_event(Key::A, ButtonState::Down);
+    input.keyboard.register_key_event(Key::D, ButtonState::Down);
+
+    let actions = action_map.read(&input);
+    assert_eq!(1.5, actions.axis("turn"));
+    assert_eq!(Vec2::new(0.0, 1.0), actions.axis_2d("move"));
+    assert_eq!(Vec2::ZERO, actions.axis_2d("unknown axis"));
+}
+
+#[test]
+fn test_rebind() {
+    let mut action_map = action_map();
+    let mut input = Input::new();
+    input.keyboard.register_key_event(Key::J, ButtonState::Down);
+
+    assert!(!action_map.read(&input).is_pressed("jump"));
+
+    action_map.rebind_action("jump", vec![InputBinding::Key(Key::J)]);
+    assert!(action_map.read(&input).is_pressed("jump"));
+    assert_eq!(&[InputBinding::Key(Key::J)], action_map.action_bindings("jump"));
+
+    action_map.unbind_action("jump", InputBinding::Key(Key::J));
+    assert!(!action_map.read(&input).is_pressed("jump"));
+}
+
+#[test]
+fn test_json_round_trip() {
+    let action_map = action_map();
+
+    let json = action_map.to_json().unwrap();
+    assert_eq!(action_map, ActionMap::from_json(&json).unwrap());
+    assert!(ActionMap::from_json("not json").is_err());
+}
+
+#[test]
+fn test_input_consumed_by_ui() {
+    let action_map = action_map();
+    let mut input = Input::new();
+
+    input.mouse.register_button_event(MouseButton::Right, ButtonState::Down);
+    input.mouse.register_move_event(5.0, 0.0);
+    input.keyboard.register_key_event(Key::W, ButtonState::Down);
+    input.mouse.set_consumed_by_ui(true);
+    {
+        let actions = action_map.read(&input);
+        assert!(!actions.is_pressed("jump"));
+        assert!(!actions.is_held("jump"));
+        assert_eq!(0.0, actions.axis("turn"));
+        // the keyboard is still available to the scene
+        assert_eq!(Vec2::new(0.0, 1.0), actions.axis_2d("move"));
+    }
+
+    input.mouse.set_consumed_by_ui(false);
+    input.keyboard.set_consumed_by_ui(true);
+    {
+        let actions = action_map.read(&input);
+        assert!(actions.is_pressed("jump"));
+        assert_eq!(5.0, actions.axis("turn"));
+        assert_eq!(Vec2::ZERO, actions.axis_2d("move"));
+    }
+
+    // consumption only lasts for a single update
+    input.reset();
+    assert!(!input.mouse.is_consumed_by_ui());
+    assert!(!input.keyboard.is_consumed_by_ui());
+    assert_eq!(Vec2::new(0.0, 1.0), action_map.read(&input).axis_2d("move"));
+}
+
+#[test]
+fn test_invalid_json() {
+    let err = ActionMap::from_json("{ \"actions\": { \"jump\": [\"Space\"] } }").unwrap_err();
+    assert!(err.to_string().starts_with("failed to convert action map to or from json:"), "{}", err);
+}
+
+#[test]
+fn test_unknown_names() {
+    let polled_action_map = action_map();
+    let input = Input::new();
+
+    // Polling a misspelled name every update only warns once, and does not change the bindings
+    for _ in 0..3 {
+        let actions = polled_action_map.read(&input);
+        assert!(!actions.is_held("jumpp"));
+        assert_eq!(0.0, actions.axis("trun"));
+        assert_eq!(Vec2::ZERO, actions.axis_2d("mvoe"));
+    }
+
+    assert_eq!(action_map(), polled_action_map);
+    assert_eq!(action_map().to_json().unwrap(), polled_action_map.to_json().unwrap());
+}
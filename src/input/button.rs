@@ -1,3 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+use crate::time;
+
 use super::input::InputElement;
 
 pub struct ButtonRegistry {
@@ -5,6 +9,9 @@ pub struct ButtonRegistry {
     registered_button_events: Vec<ButtonEvents>,
     /// The most recent recorded state of buttons
     current_button_states: Vec<ButtonState>,
+    /// The number of resets (updates) that passed since a button got pressed. Kept after releasing the button,
+    /// until it gets pressed again.
+    held_updates: Vec<u32>,
 }
 
 impl ButtonRegistry {
@@ -12,6 +19,7 @@ impl ButtonRegistry {
         Self {
             registered_button_events: Vec::with_capacity(capacity),
             current_button_states: Vec::with_capacity(capacity),
+            held_updates: Vec::with_capacity(capacity),
         }
     }
 
@@ -19,12 +27,23 @@ impl ButtonRegistry {
         for button_events in &mut self.registered_button_events {
             button_events.reset();
         }
+
+        for (held_updates, state) in self.held_updates.iter_mut().zip(&self.current_button_states) {
+            if *state == ButtonState::Down {
+                *held_updates += 1;
+            }
+        }
     }
 
     pub fn register_button_event(&mut self, button: &dyn InputElement, state: ButtonState) {
         self.ensure_current_button_states_length(button);
         self.ensure_registered_button_events_length(button);
 
+        // Holding a key makes the OS repeat its down event, which should not restart the duration
+        if state == ButtonState::Down && self.current_button_states[button.as_number()] == ButtonState::Up {
+            self.held_updates[button.as_number()] = 0;
+        }
+
         self.current_button_states[button.as_number()] = state;
         self.registered_button_events[button.as_number()].register(state);
     }
@@ -32,7 +51,8 @@ impl ButtonRegistry {
     fn ensure_current_button_states_length(&mut self, button: &dyn InputElement) {
         if button.as_number() >= self.current_button_states.len() {
             for _ in self.current_button_states.len()..=button.as_number() {
-                self.current_button_states.push(ButtonState::Up)
+                self.current_button_states.push(ButtonState::Up);
+                self.held_updates.push(0);
             }
         }
     }
@@ -69,6 +89,21 @@ impl ButtonRegistry {
         self.current_button_states[button.as_number()] == ButtonState::Down
     }
 
+    /// The number of updates that passed since the button got pressed, while it is held or during the update
+    /// it got released. Otherwise 0.
+    pub fn held_updates(&self, button: &dyn InputElement) -> u32 {
+        if !self.is_button_held(button) && !self.is_button_up(button) {
+            return 0;
+        }
+
+        self.held_updates[button.as_number()]
+    }
+
+    /// In seconds, see `held_updates`
+    pub fn held_duration(&self, button: &dyn InputElement) -> f32 {
+        self.held_updates(button) as f32 * time::DELTA
+    }
+
     pub fn is_button_action(&self, button: &dyn InputElement, action: &ButtonAction) -> bool {
         match action {
             ButtonAction::Down => self.is_button_down(button),
@@ -78,7 +113,7 @@ impl ButtonRegistry {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ButtonState {
     Up,
     Down,
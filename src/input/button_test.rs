@@ -76,3 +76,28 @@ fn test_is_button_held() {
     assert!(button_registry.is_button_held(&MockInputElement::Valid));
     assert_eq!(false, button_registry.is_button_held(&MockInputElement::Invalid));
 }
+
+#[test]
+fn test_held_updates() {
+    let mut button_registry = ButtonRegistry::new(NUMBER_OF_BUTTONS);
+    let button = MockInputElement::Valid;
+
+    assert_eq!(0, button_registry.held_updates(&button));
+
+    button_registry.register_button_event(&button, ButtonState::Down);
+    assert_eq!(0, button_registry.held_updates(&button));
+
+    button_registry.reset();
+    button_registry.reset();
+    // repeated down events while holding don't restart the count
+    button_registry.register_button_event(&button, ButtonState::Down);
+    assert_eq!(2, button_registry.held_updates(&button));
+
+    button_registry.reset();
+    button_registry.register_button_event(&button, ButtonState::Up);
+    assert_eq!(3, button_registry.held_updates(&button));
+
+    // next frame
+    button_registry.reset();
+    assert_eq!(0, button_registry.held_updates(&button));
+}
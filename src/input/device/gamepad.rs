@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::input::{button::ButtonRegistry, input::InputElement, ButtonAction, ButtonState};
+
+pub const DEFAULT_STICK_DEAD_ZONE: f32 = 0.15;
+pub const DEFAULT_TRIGGER_DEAD_ZONE: f32 = 0.05;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct GamepadId(pub u32);
+
+/// Buttons are named after their position, because the labels differ per brand. `South` is A on an Xbox
+/// controller and cross on a PlayStation controller.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamepadButton {
+    Uknown = 0,
+    South = 1,
+    East = 2,
+    West = 3,
+    North = 4,
+    LeftBumper = 5,
+    RightBumper = 6,
+    Select = 7,
+    Start = 8,
+    Guide = 9,
+    LeftStick = 10,
+    RightStick = 11,
+    DPadUp = 12,
+    DPadDown = 13,
+    DPadLeft = 14,
+    DPadRight = 15,
+}
+
+impl InputElement for GamepadButton {
+    fn as_number(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Sticks range from -1 to 1, with positive y pointing up. Triggers range from 0 to 1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamepadAxis {
+    LeftStickX = 0,
+    LeftStickY = 1,
+    RightStickX = 2,
+    RightStickY = 3,
+    LeftTrigger = 4,
+    RightTrigger = 5,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GamepadEvent {
+    Connected { id: GamepadId, name: String },
+    Disconnected { id: GamepadId },
+    Button { id: GamepadId, button: GamepadButton, state: ButtonState },
+    /// Raw value, before applying dead zones
+    Axis { id: GamepadId, axis: GamepadAxis, value: f32 },
+}
+
+/// Source of gamepad events, like a platform api or a `VirtualGamepad`. Gets polled once per frame.
+pub trait GamepadBackend {
+    /// Returns the events that happened since the last poll
+    fn poll(&mut self) -> Vec<GamepadEvent>;
+}
+
+struct Gamepad {
+    name: String,
+    button_registry: ButtonRegistry,
+    axes: [f32; 6],
+}
+
+impl Gamepad {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            button_registry: ButtonRegistry::new(16),
+            axes: [0.0; 6],
+        }
+    }
+}
+
+pub struct GamepadDevice {
+    gamepads: BTreeMap<GamepadId, Gamepad>,
+    backends: Vec<Box<dyn GamepadBackend>>,
+    connected_this_frame: Vec<GamepadId>,
+    disconnected_this_frame: Vec<GamepadId>,
+    stick_dead_zone: f32,
+    trigger_dead_zone: f32,
+}
+
+impl GamepadDevice {
+    pub fn new() -> Self {
+        Self {
+            gamepads: BTreeMap::new(),
+            backends: vec![],
+            connected_this_frame: vec![],
+            disconnected_this_frame: vec![],
+            stick_dead_zone: DEFAULT_STICK_DEAD_ZONE,
+            trigger_dead_zone: DEFAULT_TRIGGER_DEAD_ZONE,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for gamepad in self.gamepads.values_mut() {
+            gamepad.button_registry.reset();
+        }
+
+        self.connected_this_frame.clear();
+        self.disconnected_this_frame.clear();
+    }
+
+    pub fn add_backend(&mut self, backend: Box<dyn GamepadBackend>) {
+        self.backends.push(backend);
+    }
+
+    /// Collects the events of all backends. Use `Input::poll_gamepads` to also register them.
+    pub fn poll_backends(&mut self) -> Vec<GamepadEvent> {
+        self.backends.iter_mut().flat_map(|backend| backend.poll()).collect()
+    }
+
+    pub fn register_event(&mut self, event: GamepadEvent) {
+        match event {
+            GamepadEvent::Connected { id, name } => {
+                self.gamepads.insert(id, Gamepad::new(name));
+                self.connected_this_frame.push(id);
+            },
+            GamepadEvent::Disconnected { id } => {
+                if self.gamepads.remove(&id).is_some() {
+                    self.disconnected_this_frame.push(id);
+                }
+            },
+            GamepadEvent::Button { id, button, state } => {
+                if let Some(gamepad) = self.gamepads.get_mut(&id) {
+                    gamepad.button_registry.register_button_event(&button, state);
+                }
+            },
+            GamepadEvent::Axis { id, axis, value } => {
+                if let Some(gamepad) = self.gamepads.get_mut(&id) {
+                    gamepad.axes[axis as usize] = value;
+                }
+            },
+        }
+    }
+
+    /// Ordered by id
+    pub fn connected_gamepads(&self) -> Vec<GamepadId> {
+        self.gamepads.keys().copied().collect()
+    }
+
+    /// The connected gamepad with the lowest id, which is usually the one that got connected first
+    pub fn primary(&self) -> Option<GamepadId> {
+        self.gamepads.keys().next().copied()
+    }
+
+    pub fn is_connected(&self, id: GamepadId) -> bool {
+        self.gamepads.contains_key(&id)
+    }
+
+    pub fn name(&self, id: GamepadId) -> Option<&str> {
+        self.gamepads.get(&id).map(|gamepad| gamepad.name.as_str())
+    }
+
+    pub fn connected_this_frame(&self) -> &[GamepadId] {
+        &self.connected_this_frame
+    }
+
+    pub fn disconnected_this_frame(&self) -> &[GamepadId] {
+        &self.disconnected_this_frame
+    }
+
+    pub fn is_button_down(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads.get(&id).is_some_and(|gamepad| gamepad.button_registry.is_button_down(&button))
+    }
+
+    pub fn is_button_up(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads.get(&id).is_some_and(|gamepad| gamepad.button_registry.is_button_up(&button))
+    }
+
+    pub fn is_button_held(&self, id: GamepadId, button: GamepadButton) -> bool {
+        self.gamepads.get(&id).is_some_and(|gamepad| gamepad.button_registry.is_button_held(&button))
+    }
+
+    pub fn is_button_action(&self, id: GamepadId, button: GamepadButton, action: &ButtonAction) -> bool {
+        self.gamepads.get(&id).is_some_and(|gamepad| gamepad.button_registry.is_button_action(&button, action))
+    }
+
+    /// The value of the axis with dead zones applied. Sticks use a radial dead zone, so a stick axis depends
+    /// on the other axis of the same stick too.
+    pub fn axis(&self, id: GamepadId, axis: GamepadAxis) -> f32 {
+        match axis {
+            GamepadAxis::LeftStickX => self.left_stick(id).x,
+            GamepadAxis::LeftStickY => self.left_stick(id).y,
+            GamepadAxis::RightStickX => self.right_stick(id).x,
+            GamepadAxis::RightStickY => self.right_stick(id).y,
+            GamepadAxis::LeftTrigger | GamepadAxis::RightTrigger => {
+                apply_dead_zone(self.raw_axis(id, axis), self.trigger_dead_zone)
+            },
+        }
+    }
+
+    /// The value of the axis as reported by the backend
+    pub fn raw_axis(&self, id: GamepadId, axis: GamepadAxis) -> f32 {
+        self.gamepads.get(&id).map(|gamepad| gamepad.axes[axis as usize]).unwrap_or(0.0)
+    }
+
+    pub fn left_stick(&self, id: GamepadId) -> Vec2 {
+        self.stick(id, GamepadAxis::LeftStickX, GamepadAxis::LeftStickY)
+    }
+
+    pub fn right_stick(&self, id: GamepadId) -> Vec2 {
+        self.stick(id, GamepadAxis::RightStickX, GamepadAxis::RightStickY)
+    }
+
+    pub fn left_trigger(&self, id: GamepadId) -> f32 {
+        self.axis(id, GamepadAxis::LeftTrigger)
+    }
+
+    pub fn right_trigger(&self, id: GamepadId) -> f32 {
+        self.axis(id, GamepadAxis::RightTrigger)
+    }
+
+    /// Stick input with a length below `dead_zone` gets ignored, and the rest gets rescaled to still reach 1
+    pub fn set_stick_dead_zone(&mut self, dead_zone: f32) {
+        self.stick_dead_zone = dead_zone.clamp(0.0, 0.99);
+    }
+
+    pub fn set_trigger_dead_zone(&mut self, dead_zone: f32) {
+        self.trigger_dead_zone = dead_zone.clamp(0.0, 0.99);
+    }
+
+    pub fn stick_dead_zone(&self) -> f32 {
+        self.stick_dead_zone
+    }
+
+    pub fn trigger_dead_zone(&self) -> f32 {
+        self.trigger_dead_zone
+    }
+
+    fn stick(&self, id: GamepadId, x_axis: GamepadAxis, y_axis: GamepadAxis) -> Vec2 {
+        let raw = Vec2::new(self.raw_axis(id, x_axis), self.raw_axis(id, y_axis));
+        let length = raw.length();
+
+        if length <= self.stick_dead_zone {
+            return Vec2::ZERO;
+        }
+
+        raw / length * apply_dead_zone(length, self.stick_dead_zone)
+    }
+}
+
+/// Maps values from `dead_zone..1` to `0..1`, and everything below `dead_zone` to 0
+fn apply_dead_zone(value: f32, dead_zone: f32) -> f32 {
+    if value.abs() <= dead_zone {
+        return 0.0;
+    }
+
+    (value.signum() * (value.abs() - dead_zone) / (1.0 - dead_zone)).clamp(-1.0, 1.0)
+}
@@ -0,0 +1,115 @@
+use glam::Vec2;
+
+use crate::input::{ActionMap, Axis2dBinding, AxisBinding, ButtonState, GamepadAxis, GamepadButton, GamepadDevice, GamepadEvent, GamepadId, Input, InputBinding, VirtualGamepad};
+
+#[test]
+fn test_connect_and_disconnect() {
+    let mut gamepads = GamepadDevice::new();
+    let id = GamepadId(3);
+
+    gamepads.register_event(GamepadEvent::Connected { id, name: "pad".to_string() });
+    assert!(gamepads.is_connected(id));
+    assert_eq!(Some(id), gamepads.primary());
+    assert_eq!(Some("pad"), gamepads.name(id));
+    assert_eq!(&[id], gamepads.connected_this_frame());
+
+    // next frame
+    gamepads.reset();
+    assert!(gamepads.connected_this_frame().is_empty());
+
+    gamepads.register_event(GamepadEvent::Disconnected { id });
+    assert!(!gamepads.is_connected(id));
+    assert_eq!(&[id], gamepads.disconnected_this_frame());
+    assert_eq!(None, gamepads.primary());
+}
+
+#[test]
+fn test_buttons() {
+    let mut gamepads = GamepadDevice::new();
+    let id = GamepadId(0);
+    gamepads.register_event(GamepadEvent::Connected { id, name: "pad".to_string() });
+
+    gamepads.register_event(GamepadEvent::Button { id, button: GamepadButton::South, state: ButtonState::Down });
+    assert!(gamepads.is_button_down(id, GamepadButton::South));
+    assert!(gamepads.is_button_held(id, GamepadButton::South));
+    assert!(!gamepads.is_button_down(GamepadId(1), GamepadButton::South));
+
+    // next frame
+    gamepads.reset();
+    assert!(!gamepads.is_button_down(id, GamepadButton::South));
+    assert!(gamepads.is_button_held(id, GamepadButton::South));
+
+    // events of gamepads that are not connected get ignored
+    gamepads.register_event(GamepadEvent::Button { id: GamepadId(1), button: GamepadButton::North, state: ButtonState::Down });
+    assert!(!gamepads.is_button_held(GamepadId(1), GamepadButton::North));
+}
+
+#[test]
+fn test_dead_zones() {
+    let mut gamepads = GamepadDevice::new();
+    gamepads.set_stick_dead_zone(0.2);
+    gamepads.set_trigger_dead_zone(0.1);
+    let id = GamepadId(0);
+    gamepads.register_event(GamepadEvent::Connected { id, name: "pad".to_string() });
+
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::LeftStickX, value: 0.1 });
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::LeftStickY, value: 0.1 });
+    assert_eq!(Vec2::ZERO, gamepads.left_stick(id));
+    assert_eq!(0.1, gamepads.raw_axis(id, GamepadAxis::LeftStickX));
+
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::LeftStickY, value: 0.6 });
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::LeftStickX, value: 0.0 });
+    assert!((gamepads.left_stick(id).y - 0.5).abs() < 0.0001);
+
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::RightStickX, value: -1.0 });
+    assert_eq!(Vec2::new(-1.0, 0.0), gamepads.right_stick(id));
+
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::LeftTrigger, value: 0.05 });
+    assert_eq!(0.0, gamepads.left_trigger(id));
+    gamepads.register_event(GamepadEvent::Axis { id, axis: GamepadAxis::LeftTrigger, value: 1.0 });
+    assert_eq!(1.0, gamepads.left_trigger(id));
+}
+
+#[test]
+fn test_virtual_gamepad_script() {
+    let gamepad = VirtualGamepad::new(GamepadId(0));
+    let mut input = Input::new();
+    input.gamepads.add_backend(Box::new(gamepad.clone()));
+
+    gamepad.connect().press(GamepadButton::South).wait(2).release(GamepadButton::South).wait(1).disconnect();
+
+    input.poll_gamepads();
+    assert!(input.gamepads.is_connected(gamepad.id()));
+    assert!(input.gamepads.is_button_down(gamepad.id(), GamepadButton::South));
+    input.reset();
+
+    input.poll_gamepads();
+    assert!(input.gamepads.is_button_held(gamepad.id(), GamepadButton::South));
+    input.reset();
+
+    input.poll_gamepads();
+    assert!(input.gamepads.is_button_up(gamepad.id(), GamepadButton::South));
+    assert!(!gamepad.is_finished());
+    input.reset();
+
+    input.poll_gamepads();
+    assert!(!input.gamepads.is_connected(gamepad.id()));
+    assert!(gamepad.is_finished());
+}
+
+#[test]
+fn test_gamepad_actions() {
+    let gamepad = VirtualGamepad::new(GamepadId(0));
+    let mut input = Input::new();
+    input.gamepads.add_backend(Box::new(gamepad.clone()));
+    let action_map = ActionMap::new()
+        .with_action("jump", InputBinding::GamepadButton(GamepadButton::South))
+        .with_axis_2d("move", Axis2dBinding::new(AxisBinding::GamepadAxis(GamepadAxis::LeftStickX), AxisBinding::GamepadAxis(GamepadAxis::LeftStickY)));
+
+    gamepad.connect().press(GamepadButton::South).set_left_stick(1.0, 0.0);
+    input.poll_gamepads();
+
+    let actions = action_map.read(&input);
+    assert!(actions.is_pressed("jump"));
+    assert_eq!(Vec2::new(1.0, 0.0), actions.axis_2d("move"));
+}
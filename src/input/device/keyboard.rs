@@ -1,26 +1,50 @@
 use button::ButtonAction;
+use serde::{Deserialize, Serialize};
 
-use crate::input::{button::{self, ButtonRegistry}, input::InputElement, ButtonState};
+use crate::input::{button::{self, ButtonRegistry}, input::InputElement, ButtonState, Shortcut};
+
+use super::Modifiers;
 
 pub struct KeyboardDevice {
     key_registry: ButtonRegistry,
+    /// The modifier state as reported by the window, which unlike key events also covers the right-hand keys
+    registered_modifiers: Modifiers,
+    is_consumed_by_ui: bool,
 }
 
 impl KeyboardDevice {
     pub fn new () -> Self {
         Self { 
             key_registry: ButtonRegistry::new(256),
+            registered_modifiers: Modifiers::NONE,
+            is_consumed_by_ui: false,
         }
     }
 
     pub fn reset(&mut self) {
         self.key_registry.reset();
+        self.is_consumed_by_ui = false;
+    }
+
+    /// Whether a UI widget has keyboard focus during this update, see `Interface::set_keyboard_focus`.
+    /// Scenes should ignore key presses while this is true.
+    pub fn is_consumed_by_ui(&self) -> bool {
+        self.is_consumed_by_ui
+    }
+
+    /// Gets set by `Interface::update`
+    pub fn set_consumed_by_ui(&mut self, is_consumed: bool) {
+        self.is_consumed_by_ui = is_consumed;
     }
 
     pub fn register_key_event(&mut self, key: Key, state: ButtonState) {
         self.key_registry.register_button_event(&key, state);
     }
 
+    pub fn register_modifiers_event(&mut self, modifiers: Modifiers) {
+        self.registered_modifiers = modifiers;
+    }
+
     pub fn is_key_down(&self, key: Key) -> bool {
         self.key_registry.is_button_down(&key)
     }
@@ -36,9 +60,32 @@ impl KeyboardDevice {
     pub fn is_key_action(&self, key: Key, action: &ButtonAction) -> bool {
         self.key_registry.is_button_action(&key, action)
     }
+
+    /// Number of seconds the key has been held, or was held when it got released during this update
+    pub fn key_held_duration(&self, key: Key) -> f32 {
+        self.key_registry.held_duration(&key)
+    }
+
+    /// The modifiers that are held down, either reported by the window or registered as key events
+    pub fn modifiers(&self) -> Modifiers {
+        [Key::Shift, Key::Cntrl, Key::Alt, Key::Cmd].iter()
+            .filter(|key| self.is_key_held(**key))
+            .fold(self.registered_modifiers, |modifiers, key| modifiers.union(Modifiers::from_key(*key)))
+    }
+
+    /// Whether the key of the shortcut got pressed during this update, while exactly the modifiers of the
+    /// shortcut were held. So `Ctrl+S` does not trigger when `Ctrl+Shift+S` gets pressed.
+    pub fn is_shortcut_triggered(&self, shortcut: &Shortcut) -> bool {
+        self.is_key_down(shortcut.key) && self.modifiers() == shortcut.modifiers
+    }
+
+    /// Whether the key and exactly the modifiers of the shortcut are held down
+    pub fn is_shortcut_held(&self, shortcut: &Shortcut) -> bool {
+        self.is_key_held(shortcut.key) && self.modifiers() == shortcut.modifiers
+    }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Key {
     Uknown = 0,
     A = 1,
@@ -114,6 +161,98 @@ pub enum Key {
     Caret = 66,
 }
 
+/// The names that are used for keys in shortcuts, like `Ctrl+Shift+S`
+const KEY_NAMES: &[(Key, &str)] = &[
+    (Key::A, "A"),
+    (Key::B, "B"),
+    (Key::C, "C"),
+    (Key::D, "D"),
+    (Key::E, "E"),
+    (Key::F, "F"),
+    (Key::G, "G"),
+    (Key::H, "H"),
+    (Key::I, "I"),
+    (Key::J, "J"),
+    (Key::K, "K"),
+    (Key::L, "L"),
+    (Key::M, "M"),
+    (Key::N, "N"),
+    (Key::O, "O"),
+    (Key::P, "P"),
+    (Key::Q, "Q"),
+    (Key::R, "R"),
+    (Key::S, "S"),
+    (Key::T, "T"),
+    (Key::U, "U"),
+    (Key::V, "V"),
+    (Key::W, "W"),
+    (Key::X, "X"),
+    (Key::Y, "Y"),
+    (Key::Z, "Z"),
+    (Key::One, "1"),
+    (Key::Two, "2"),
+    (Key::Three, "3"),
+    (Key::Four, "4"),
+    (Key::Five, "5"),
+    (Key::Six, "6"),
+    (Key::Seven, "7"),
+    (Key::Eight, "8"),
+    (Key::Nine, "9"),
+    (Key::Zero, "0"),
+    (Key::F1, "F1"),
+    (Key::F2, "F2"),
+    (Key::F3, "F3"),
+    (Key::F4, "F4"),
+    (Key::F5, "F5"),
+    (Key::F6, "F6"),
+    (Key::F7, "F7"),
+    (Key::F8, "F8"),
+    (Key::F9, "F9"),
+    (Key::F10, "F10"),
+    (Key::F11, "F11"),
+    (Key::F12, "F12"),
+    (Key::Shift, "Shift"),
+    (Key::Escape, "Escape"),
+    (Key::Tab, "Tab"),
+    (Key::Cntrl, "Ctrl"),
+    (Key::Alt, "Alt"),
+    (Key::Space, "Space"),
+    (Key::ArrowLeft, "Left"),
+    (Key::ArrowRight, "Right"),
+    (Key::ArrowUp, "Up"),
+    (Key::ArrowDown, "Down"),
+    (Key::Cmd, "Super"),
+    (Key::BackTick, "`"),
+    (Key::Enter, "Enter"),
+    (Key::Backspace, "Backspace"),
+    (Key::Equals, "="),
+    (Key::Minus, "-"),
+    (Key::Plus, "Plus"),
+    (Key::Caret, "^"),
+];
+
+impl Key {
+    /// The name that is used for this key in shortcuts, like "S", "F5" or "Escape"
+    pub fn name(&self) -> &'static str {
+        KEY_NAMES.iter().find(|(key, _)| key == self).map(|(_, name)| *name).unwrap_or("Unknown")
+    }
+
+    /// Looks up a key by the name that `name` returns. Ignores case, and also accepts common aliases like "Esc",
+    /// "Control", "Cmd" and "Return".
+    pub fn from_name(name: &str) -> Option<Key> {
+        let alias = match name.to_lowercase().as_str() {
+            "control" | "cntrl" => "Ctrl",
+            "esc" => "Escape",
+            "cmd" | "win" | "meta" => "Super",
+            "return" => "Enter",
+            "+" => "Plus",
+            _ => name,
+        };
+
+        KEY_NAMES.iter().find(|(_, key_name)| key_name.eq_ignore_ascii_case(alias)).map(|(key, _)| *key)
+    }
+}
+
 impl InputElement for Key {
     fn as_number(&self) -> usize {
         *self as usize
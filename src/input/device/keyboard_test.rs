@@ -1,4 +1,4 @@
-use crate::input::{ButtonState, Key, KeyboardDevice};
+use crate::{input::{ButtonState, Key, KeyboardDevice}, time};
 
 #[test]
 fn test_is_key_down() {
@@ -76,3 +76,14 @@ fn test_is_key_held() {
     keyboard.register_key_event(key, ButtonState::Up);
     assert_eq!(false, keyboard.is_key_held(key));
 }
+
+#[test]
+fn test_key_held_duration() {
+    let mut keyboard = KeyboardDevice::new();
+
+    keyboard.register_key_event(Key::Space, ButtonState::Down);
+    keyboard.reset();
+    keyboard.reset();
+    assert!((keyboard.key_held_duration(Key::Space) - 2.0 * time::DELTA).abs() < 0.0001);
+    assert_eq!(0.0, keyboard.key_held_duration(Key::Enter));
+}
@@ -2,11 +2,48 @@ mod keyboard;
 pub use keyboard::KeyboardDevice;
 pub use keyboard::Key;
 
+mod modifiers;
+pub use modifiers::Modifiers;
+
 mod mouse;
 pub use mouse::MouseDevice;
 pub use mouse::MouseButton;
+pub use mouse::ClickSettings;
+pub use mouse::DEFAULT_PIXELS_PER_LINE;
+
+mod gamepad;
+pub use gamepad::GamepadDevice;
+pub use gamepad::GamepadBackend;
+pub use gamepad::GamepadEvent;
+pub use gamepad::GamepadId;
+pub use gamepad::GamepadButton;
+pub use gamepad::GamepadAxis;
+pub use gamepad::DEFAULT_STICK_DEAD_ZONE;
+pub use gamepad::DEFAULT_TRIGGER_DEAD_ZONE;
+
+mod virtual_gamepad;
+pub use virtual_gamepad::VirtualGamepad;
+
+mod text;
+pub use text::TextDevice;
+pub use text::ImeEvent;
+
+mod touch;
+pub use touch::TouchDevice;
+pub use touch::TouchEvent;
+pub use touch::TouchId;
+pub use touch::TouchPhase;
+pub use touch::TouchPoint;
+pub use touch::Pinch;
+pub use touch::GestureSettings;
 
 #[cfg(test)]
 pub mod keyboard_test;
 #[cfg(test)]
 pub mod mouse_test;
+#[cfg(test)]
+pub mod text_test;
+#[cfg(test)]
+pub mod gamepad_test;
+#[cfg(test)]
+pub mod touch_test;
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use super::Key;
+
+/// The modifier keys that are held down
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+    /// The Windows key on Windows, Cmd on macOS
+    pub super_key: bool,
+}
+
+impl Modifiers {
+    pub const NONE: Modifiers = Modifiers { shift: false, ctrl: false, alt: false, super_key: false };
+
+    /// Returns the modifier that `key` is, or `Modifiers::NONE` if the key is not a modifier
+    pub fn from_key(key: Key) -> Self {
+        Self {
+            shift: key == Key::Shift,
+            ctrl: key == Key::Cntrl,
+            alt: key == Key::Alt,
+            super_key: key == Key::Cmd,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Self::NONE
+    }
+
+    /// The modifiers that are set in either `self` or `other`
+    pub fn union(&self, other: Modifiers) -> Self {
+        Self {
+            shift: self.shift || other.shift,
+            ctrl: self.ctrl || other.ctrl,
+            alt: self.alt || other.alt,
+            super_key: self.super_key || other.super_key,
+        }
+    }
+}
@@ -1,12 +1,53 @@
 use glam::Vec2;
+use serde::{Deserialize, Serialize};
 
-use crate::input::{button::ButtonRegistry, input::InputElement, ButtonAction, ButtonState};
+use crate::{input::{button::ButtonRegistry, input::InputElement, ButtonAction, ButtonState}, time};
+
+/// Thresholds for recognizing multi-clicks and long presses
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClickSettings {
+    /// Maximum number of seconds between two presses for them to count as a double click
+    pub multi_click_time: f32,
+    /// Maximum distance (in logical pixels) the mouse may move between two presses for them to count as a
+    /// double click
+    pub multi_click_distance: f32,
+    /// Number of seconds a button has to be held to count as a long press
+    pub long_press_time: f32,
+}
+
+impl Default for ClickSettings {
+    fn default() -> Self {
+        Self {
+            multi_click_time: 0.4,
+            multi_click_distance: 4.0,
+            long_press_time: 0.5,
+        }
+    }
+}
+
+/// Number of logical pixels one line of scrolling covers, for mice that scroll in lines instead of pixels
+pub const DEFAULT_PIXELS_PER_LINE: f32 = 40.0;
+
+#[derive(Clone, Copy)]
+struct Click {
+    count: u32,
+    update: u64,
+    position: Vec2,
+}
 
 pub struct MouseDevice {
     button_registry: ButtonRegistry,
     
     current_state: State,
     last_state: State,
+
+    click_settings: ClickSettings,
+    /// The last press of every button
+    clicks: Vec<Option<Click>>,
+    /// The number of resets (updates) so far
+    update_count: u64,
+    pixels_per_line: f32,
+    is_consumed_by_ui: bool,
 }
 
 impl MouseDevice {
@@ -15,6 +56,11 @@ impl MouseDevice {
             button_registry: ButtonRegistry::new(16),
             current_state: State::new(),
             last_state: State::new(),
+            click_settings: ClickSettings::default(),
+            clicks: vec![],
+            update_count: 0,
+            pixels_per_line: DEFAULT_PIXELS_PER_LINE,
+            is_consumed_by_ui: false,
         }
     }
 
@@ -23,20 +69,80 @@ impl MouseDevice {
         self.current_state.reset();
 
         self.button_registry.reset();
+        self.update_count += 1;
+        self.is_consumed_by_ui = false;
+    }
+
+    /// Whether the interface handled the mouse during this update, for example because the cursor is over a
+    /// button or a slider is being dragged. Scenes should ignore clicks, scrolling and dragging while this is
+    /// true, so they don't react to input that was meant for the UI.
+    pub fn is_consumed_by_ui(&self) -> bool {
+        self.is_consumed_by_ui
+    }
+
+    /// Gets set by `Interface::update`
+    pub fn set_consumed_by_ui(&mut self, is_consumed: bool) {
+        self.is_consumed_by_ui = is_consumed;
     }
 
     pub fn register_button_event(&mut self, button: MouseButton, state: ButtonState) {
+        if state == ButtonState::Down {
+            self.register_click(button);
+        }
+
         self.button_registry.register_button_event(&button, state);
     }
 
+    fn register_click(&mut self, button: MouseButton) {
+        if button.as_number() >= self.clicks.len() {
+            self.clicks.resize(button.as_number() + 1, None);
+        }
+
+        let position = self.get_position();
+        let max_updates = (self.click_settings.multi_click_time / time::DELTA) as u64;
+
+        let count = match self.clicks[button.as_number()] {
+            Some(last) if self.update_count - last.update <= max_updates
+                && last.position.distance(position) <= self.click_settings.multi_click_distance => last.count + 1,
+            _ => 1,
+        };
+
+        self.clicks[button.as_number()] = Some(Click { count, update: self.update_count, position });
+    }
+
+    pub fn click_settings(&self) -> ClickSettings {
+        self.click_settings
+    }
+
+    pub fn set_click_settings(&mut self, click_settings: ClickSettings) {
+        self.click_settings = click_settings;
+    }
+
+    /// `scroll` is in logical pixels, see `get_scroll_x`
     pub fn register_scroll_x_event(&mut self, scroll: f64) {
         self.current_state.scroll_x += scroll;
     }
 
+    /// `scroll` is in logical pixels, see `get_scroll_y`
     pub fn register_scroll_y_event(&mut self, scroll: f64) {
         self.current_state.scroll_y += scroll;
     }
 
+    /// For mice that scroll in lines (or "clicks" of the wheel) instead of pixels. Gets converted to logical
+    /// pixels using `pixels_per_line`.
+    pub fn register_scroll_lines_event(&mut self, lines_x: f64, lines_y: f64) {
+        self.current_state.scroll_x += lines_x * self.pixels_per_line as f64;
+        self.current_state.scroll_y += lines_y * self.pixels_per_line as f64;
+    }
+
+    pub fn pixels_per_line(&self) -> f32 {
+        self.pixels_per_line
+    }
+
+    pub fn set_pixels_per_line(&mut self, pixels_per_line: f32) {
+        self.pixels_per_line = pixels_per_line;
+    }
+
     pub fn register_reposition_event(&mut self, position_x: f64, position_y: f64) {
         self.current_state.position_x = position_x;
         self.current_state.position_y = position_y;
@@ -63,6 +169,36 @@ impl MouseDevice {
         self.button_registry.is_button_action(&mouse_button, action)
     }
 
+    /// How many times in a row the button got pressed within the multi-click thresholds, counting the last
+    /// press. Returns 0 if the button was never pressed.
+    pub fn click_count(&self, button: MouseButton) -> u32 {
+        match self.clicks.get(button.as_number()) {
+            Some(Some(click)) => click.count,
+            _ => 0,
+        }
+    }
+
+    /// Whether the button got pressed for the second time in a row during this update
+    pub fn is_double_click(&self, button: MouseButton) -> bool {
+        self.is_button_down(button) && self.click_count(button) == 2
+    }
+
+    /// Whether the button got pressed for the third time in a row during this update
+    pub fn is_triple_click(&self, button: MouseButton) -> bool {
+        self.is_button_down(button) && self.click_count(button) == 3
+    }
+
+    /// True during the single update in which the button has been held for `ClickSettings::long_press_time`
+    pub fn is_long_press(&self, button: MouseButton) -> bool {
+        let long_press_updates = (self.click_settings.long_press_time / time::DELTA).ceil() as u32;
+        self.is_button_held(button) && self.button_registry.held_updates(&button) == long_press_updates
+    }
+
+    /// Number of seconds the button has been held, or was held when it got released during this update
+    pub fn button_held_duration(&self, button: MouseButton) -> f32 {
+        self.button_registry.held_duration(&button)
+    }
+
     pub fn get_position_x(&self) -> f64 {
         self.current_state.position_x
     }
@@ -91,16 +227,20 @@ impl MouseDevice {
         self.get_moved_x() != 0.0 || self.get_moved_y() != 0.0
     }
 
+    /// Horizontal scroll during this update in logical pixels, positive when scrolling to the right. Scroll
+    /// from both mice and touchpads is in this unit.
     pub fn get_scroll_x(&self) -> f64 {
         self.current_state.scroll_x
     }
 
+    /// Vertical scroll during this update in logical pixels, positive when scrolling up (away from the user).
+    /// Scroll from both mice and touchpads is in this unit.
     pub fn get_scroll_y(&self) -> f64 {
         self.current_state.scroll_y
     }
 
     pub fn has_scroll(&self) -> bool {
-        self.current_state.scroll_x != 0.0 && self.current_state.scroll_y != 0.0
+        self.current_state.scroll_x != 0.0 || self.current_state.scroll_y != 0.0
     }
 }
 
@@ -135,7 +275,7 @@ impl State {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum MouseButton {
     Uknown = 0,
     Left = 1,
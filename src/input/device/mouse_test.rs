@@ -1,4 +1,4 @@
-use crate::input::{ButtonState, MouseButton, MouseDevice};
+use crate::{input::{ButtonState, ClickSettings, MouseButton, MouseDevice}, time};
 
 #[test]
 fn test_is_button_down() {
@@ -90,3 +90,88 @@ fn test_is_button_held() {
     mouse.register_button_event(button, ButtonState::Up);
     assert_eq!(false, mouse.is_button_held(button));
 }
+
+fn click(mouse: &mut MouseDevice, button: MouseButton) {
+    mouse.register_button_event(button, ButtonState::Down);
+    mouse.register_button_event(button, ButtonState::Up);
+}
+
+#[test]
+fn test_click_count() {
+    let mut mouse = MouseDevice::new();
+    let button = MouseButton::Left;
+    assert_eq!(0, mouse.click_count(button));
+
+    click(&mut mouse, button);
+    assert_eq!(1, mouse.click_count(button));
+    assert!(!mouse.is_double_click(button));
+
+    mouse.reset();
+    click(&mut mouse, button);
+    assert!(mouse.is_double_click(button));
+
+    mouse.reset();
+    assert!(!mouse.is_double_click(button));
+    click(&mut mouse, button);
+    assert!(mouse.is_triple_click(button));
+
+    // wait longer than the multi click time
+    let updates = (mouse.click_settings().multi_click_time / time::DELTA) as usize + 1;
+    for _ in 0..updates {
+        mouse.reset();
+    }
+    click(&mut mouse, button);
+    assert_eq!(1, mouse.click_count(button));
+}
+
+#[test]
+fn test_click_count_distance() {
+    let mut mouse = MouseDevice::new();
+    let button = MouseButton::Right;
+
+    click(&mut mouse, button);
+    mouse.reset();
+    mouse.register_reposition_event(100.0, 0.0);
+    click(&mut mouse, button);
+    assert_eq!(1, mouse.click_count(button));
+}
+
+#[test]
+fn test_long_press() {
+    let mut mouse = MouseDevice::new();
+    mouse.set_click_settings(ClickSettings { long_press_time: 3.0 * time::DELTA, ..Default::default() });
+    let button = MouseButton::Left;
+
+    mouse.register_button_event(button, ButtonState::Down);
+    let mut long_presses = 0;
+    for _ in 0..10 {
+        if mouse.is_long_press(button) {
+            long_presses += 1;
+        }
+        mouse.reset();
+    }
+    assert_eq!(1, long_presses);
+
+    mouse.register_button_event(button, ButtonState::Up);
+    assert!((mouse.button_held_duration(button) - 10.0 * time::DELTA).abs() < 0.0001);
+}
+
+#[test]
+fn test_scroll_units() {
+    let mut mouse = MouseDevice::new();
+    assert!(!mouse.has_scroll());
+
+    mouse.set_pixels_per_line(20.0);
+    mouse.register_scroll_lines_event(0.0, 2.0);
+    mouse.register_scroll_y_event(5.0);
+    assert!(mouse.has_scroll());
+    assert_eq!(0.0, mouse.get_scroll_x());
+    assert_eq!(45.0, mouse.get_scroll_y());
+
+    // next frame
+    mouse.reset();
+    assert!(!mouse.has_scroll());
+
+    mouse.register_scroll_x_event(-1.0);
+    assert!(mouse.has_scroll());
+}
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// Composition events of an input method editor (IME), used to type characters that are not on the keyboard,
+/// like Chinese or Japanese text. The window only sends these after IME got allowed with the `SetImeAllowed`
+/// event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ImeEvent {
+    Enabled,
+    /// The text that is still being composed. `cursor` is the byte range of the cursor within `text`, or None
+    /// when the cursor should be hidden. An empty `text` clears the composition.
+    Preedit { text: String, cursor: Option<(usize, usize)> },
+    /// The composition is done, and `text` should be inserted
+    Commit(String),
+    Disabled,
+}
+
+/// Buffers the text that got typed since the last reset. Unlike key events, this takes the keyboard layout,
+/// dead keys and IME composition into account, so it should be used for text fields, consoles and chat boxes.
+pub struct TextDevice {
+    characters: Vec<char>,
+    ime_events: Vec<ImeEvent>,
+    composition: Option<String>,
+}
+
+impl TextDevice {
+    pub fn new() -> Self {
+        Self {
+            characters: vec![],
+            ime_events: vec![],
+            composition: None,
+        }
+    }
+
+    /// Clears the characters and IME events of the last frame. The current composition is kept until the IME
+    /// commits or clears it.
+    pub fn reset(&mut self) {
+        self.characters.clear();
+        self.ime_events.clear();
+    }
+
+    /// Control characters (like backspace, enter and escape) are ignored, as those are available as key events
+    pub fn register_character_event(&mut self, character: char) {
+        if !character.is_control() {
+            self.characters.push(character);
+        }
+    }
+
+    pub fn register_ime_event(&mut self, event: ImeEvent) {
+        match &event {
+            ImeEvent::Preedit { text, .. } if !text.is_empty() => self.composition = Some(text.clone()),
+            ImeEvent::Preedit { .. } | ImeEvent::Commit(_) | ImeEvent::Disabled => self.composition = None,
+            ImeEvent::Enabled => (),
+        }
+
+        self.ime_events.push(event);
+    }
+
+    /// The characters that were typed since the last reset, in the order they were typed
+    pub fn characters(&self) -> &[char] {
+        &self.characters
+    }
+
+    /// The characters that were typed since the last reset, followed by the text that IMEs committed
+    pub fn text(&self) -> String {
+        let mut text: String = self.characters.iter().collect();
+
+        for event in &self.ime_events {
+            if let ImeEvent::Commit(committed) = event {
+                text.push_str(committed);
+            }
+        }
+
+        text
+    }
+
+    pub fn ime_events(&self) -> &[ImeEvent] {
+        &self.ime_events
+    }
+
+    /// The text that the IME is composing right now, which should be shown (often underlined) at the cursor
+    /// of the focused text field, but is not part of it yet
+    pub fn composition(&self) -> Option<&str> {
+        self.composition.as_deref()
+    }
+}
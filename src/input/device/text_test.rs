@@ -0,0 +1,53 @@
+use crate::input::{ImeEvent, Input, InputEvent, TextDevice};
+
+#[test]
+fn test_characters_are_buffered_per_frame() {
+    let mut text = TextDevice::new();
+
+    text.register_character_event('h');
+    text.register_character_event('é');
+    text.register_character_event('\u{8}'); // backspace
+    assert_eq!(&['h', 'é'], text.characters());
+    assert_eq!("hé", text.text());
+
+    // next frame
+    text.reset();
+    assert!(text.characters().is_empty());
+    assert_eq!("", text.text());
+}
+
+#[test]
+fn test_ime_composition() {
+    let mut text = TextDevice::new();
+
+    text.register_ime_event(ImeEvent::Enabled);
+    text.register_ime_event(ImeEvent::Preedit { text: "a".to_string(), cursor: Some((1, 1)) });
+    assert_eq!(Some("a"), text.composition());
+
+    // the composition outlives the frame
+    text.reset();
+    assert!(text.ime_events().is_empty());
+    assert_eq!(Some("a"), text.composition());
+
+    text.register_ime_event(ImeEvent::Preedit { text: "".to_string(), cursor: None });
+    text.register_ime_event(ImeEvent::Commit("啊".to_string()));
+    assert_eq!(None, text.composition());
+    assert_eq!("啊", text.text());
+    assert_eq!(2, text.ime_events().len());
+}
+
+#[test]
+fn test_text_events_through_input() {
+    let mut input = Input::new();
+    input.start_recording();
+
+    input.register_event(InputEvent::Character('x'));
+    input.register_event(InputEvent::Ime(ImeEvent::Commit("y".to_string())));
+    assert_eq!("xy", input.text.text());
+
+    input.reset();
+    assert_eq!("", input.text.text());
+
+    let recording = input.stop_recording().unwrap();
+    assert_eq!(vec![InputEvent::Character('x'), InputEvent::Ime(ImeEvent::Commit("y".to_string()))], recording.frames[0]);
+}
@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+
+use glam::Vec2;
+use serde::{Deserialize, Serialize};
+
+use crate::{input::{ButtonState, MouseButton}, time};
+
+use super::MouseDevice;
+
+/// Thresholds for recognizing gestures
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GestureSettings {
+    /// Maximum number of seconds a finger may touch the screen for it to count as a tap
+    pub tap_time: f32,
+    /// Distance (in logical pixels) a finger has to move before it counts as panning instead of a tap
+    pub tap_distance: f32,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        Self {
+            tap_time: 0.3,
+            tap_distance: 10.0,
+        }
+    }
+}
+
+/// Identifies a finger for as long as it touches the screen. Ids can get reused afterwards.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TouchId(pub u64);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TouchPhase {
+    Started,
+    Moved,
+    /// The finger got lifted
+    Ended,
+    /// The system took over the touch, for example because the app lost focus
+    Cancelled,
+    /// The finger touches the screen, but didn't move during this update. Never part of a `TouchEvent`.
+    Stationary,
+}
+
+/// A touch event with its position in logical pixels, from the top left of the window
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TouchEvent {
+    pub id: TouchId,
+    pub phase: TouchPhase,
+    pub x: f32,
+    pub y: f32,
+}
+
+impl TouchEvent {
+    pub fn started(id: u64, x: f32, y: f32) -> Self {
+        Self { id: TouchId(id), phase: TouchPhase::Started, x, y }
+    }
+
+    pub fn moved(id: u64, x: f32, y: f32) -> Self {
+        Self { id: TouchId(id), phase: TouchPhase::Moved, x, y }
+    }
+
+    pub fn ended(id: u64, x: f32, y: f32) -> Self {
+        Self { id: TouchId(id), phase: TouchPhase::Ended, x, y }
+    }
+
+    pub fn cancelled(id: u64, x: f32, y: f32) -> Self {
+        Self { id: TouchId(id), phase: TouchPhase::Cancelled, x, y }
+    }
+
+    pub fn position(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TouchPoint {
+    pub id: TouchId,
+    /// The last phase of the touch during this update
+    pub phase: TouchPhase,
+    pub position: Vec2,
+    /// The position at the end of the previous update
+    pub previous_position: Vec2,
+    pub start_position: Vec2,
+    /// Number of updates since the finger touched the screen
+    pub held_updates: u32,
+    /// Whether the finger moved further than `GestureSettings::tap_distance` from where it started
+    pub did_exceed_tap_distance: bool,
+    /// Whether another finger touched the screen at the same time, which rules out a tap
+    pub was_multi_touch: bool,
+}
+
+impl TouchPoint {
+    pub fn is_active(&self) -> bool {
+        phase_is_active(self.phase)
+    }
+
+    pub fn held_duration(&self) -> f32 {
+        self.held_updates as f32 * time::DELTA
+    }
+}
+
+/// Two fingers moving towards or away from each other
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pinch {
+    /// The distance between the fingers relative to the previous update. Above 1 when spreading the fingers.
+    pub scale: f32,
+    pub center: Vec2,
+}
+
+pub struct TouchDevice {
+    /// The touches that are active
+    points: BTreeMap<TouchId, TouchPoint>,
+    /// The touches that ended during this update. Kept apart from `points`, because their id can already be
+    /// reused by a new touch during the same update.
+    ended_points: Vec<TouchPoint>,
+    /// The ids of the active touches, in the order they touched the screen
+    touch_order: Vec<TouchId>,
+    /// The touch that drives mouse emulation, which is the first finger that touched the screen
+    primary: Option<TouchId>,
+    gesture_settings: GestureSettings,
+    emulate_mouse: bool,
+}
+
+impl TouchDevice {
+    pub fn new() -> Self {
+        Self {
+            points: BTreeMap::new(),
+            ended_points: Vec::new(),
+            touch_order: Vec::new(),
+            primary: None,
+            gesture_settings: GestureSettings::default(),
+            emulate_mouse: false,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.ended_points.clear();
+
+        for point in self.points.values_mut() {
+            point.phase = TouchPhase::Stationary;
+            point.previous_position = point.position;
+            point.held_updates += 1;
+        }
+
+        if self.primary.is_some_and(|id| !self.points.contains_key(&id)) {
+            self.primary = None;
+        }
+    }
+
+    pub fn register_event(&mut self, event: TouchEvent) {
+        let position = event.position();
+
+        match event.phase {
+            TouchPhase::Started => {
+                let was_multi_touch = self.active_touch_count() > 0;
+                for point in self.points.values_mut() {
+                    point.was_multi_touch = true;
+                }
+
+                if self.primary.is_none() {
+                    self.primary = Some(event.id);
+                }
+
+                // A touch that did not end before its id got reused is lost
+                if let Some(point) = self.points.remove(&event.id) {
+                    self.end_point(point);
+                }
+                self.touch_order.push(event.id);
+
+                self.points.insert(event.id, TouchPoint {
+                    id: event.id,
+                    phase: TouchPhase::Started,
+                    position,
+                    previous_position: position,
+                    start_position: position,
+                    held_updates: 0,
+                    did_exceed_tap_distance: false,
+                    was_multi_touch,
+                });
+            },
+            TouchPhase::Stationary => (),
+            phase => {
+                let tap_distance = self.gesture_settings.tap_distance;
+
+                if let Some(point) = self.points.get_mut(&event.id) {
+                    // A touch that starts and ends during the same update should still count as started
+                    if point.phase != TouchPhase::Started || phase != TouchPhase::Moved {
+                        point.phase = phase;
+                    }
+                    point.position = position;
+                    point.did_exceed_tap_distance |= point.start_position.distance(position) > tap_distance;
+                }
+
+                if !phase_is_active(phase) {
+                    if let Some(point) = self.points.remove(&event.id) {
+                        self.end_point(point);
+                    }
+                }
+            },
+        }
+    }
+
+    fn end_point(&mut self, point: TouchPoint) {
+        self.touch_order.retain(|&id| id != point.id);
+        self.ended_points.push(point);
+    }
+
+    /// Applies a touch event of the primary touch to the mouse, if mouse emulation is enabled. The touch event
+    /// should already have been registered.
+    pub(crate) fn emulate_mouse_event(&self, event: &TouchEvent, mouse: &mut MouseDevice) {
+        if !self.emulate_mouse || self.primary != Some(event.id) {
+            return;
+        }
+
+        let moved = event.position() - mouse.get_position();
+        mouse.register_reposition_event(event.x as f64, event.y as f64);
+
+        match event.phase {
+            TouchPhase::Started => mouse.register_button_event(MouseButton::Left, ButtonState::Down),
+            TouchPhase::Moved => mouse.register_move_event(moved.x as f64, moved.y as f64),
+            TouchPhase::Ended | TouchPhase::Cancelled => mouse.register_button_event(MouseButton::Left, ButtonState::Up),
+            TouchPhase::Stationary => (),
+        }
+    }
+
+    /// When enabled, the first finger that touches the screen moves the mouse and holds its left button, so
+    /// code that only handles the mouse (like the interface) also works on touch screens
+    pub fn set_mouse_emulation(&mut self, enabled: bool) {
+        self.emulate_mouse = enabled;
+    }
+
+    pub fn is_mouse_emulation_enabled(&self) -> bool {
+        self.emulate_mouse
+    }
+
+    pub fn gesture_settings(&self) -> GestureSettings {
+        self.gesture_settings
+    }
+
+    pub fn set_gesture_settings(&mut self, gesture_settings: GestureSettings) {
+        self.gesture_settings = gesture_settings;
+    }
+
+    /// All active touches ordered by id, followed by the ones that ended during this update
+    pub fn touches(&self) -> Vec<&TouchPoint> {
+        self.points.values().chain(&self.ended_points).collect()
+    }
+
+    /// The active touch with this id, or else the last touch with this id that ended during this update
+    pub fn touch(&self, id: TouchId) -> Option<&TouchPoint> {
+        self.points.get(&id).or_else(|| self.ended_points.iter().rev().find(|point| point.id == id))
+    }
+
+    pub fn active_touch_count(&self) -> usize {
+        self.active_touches().count()
+    }
+
+    pub fn is_touching(&self) -> bool {
+        self.active_touch_count() > 0
+    }
+
+    /// The position of a finger that touched the screen briefly without moving, and got lifted during this
+    /// update
+    pub fn tap(&self) -> Option<Vec2> {
+        let max_updates = (self.gesture_settings.tap_time / time::DELTA) as u32;
+
+        self.ended_points.iter()
+            .find(|point| point.phase == TouchPhase::Ended
+                && !point.did_exceed_tap_distance
+                && !point.was_multi_touch
+                && point.held_updates <= max_updates)
+            .map(|point| point.position)
+    }
+
+    /// How far the fingers moved during this update, while dragging a single finger or moving multiple fingers
+    /// together. Uses the average position of all fingers.
+    pub fn pan(&self) -> Option<Vec2> {
+        let active_touches: Vec<&TouchPoint> = self.active_touches().collect();
+
+        match active_touches.len() {
+            0 => None,
+            1 if !active_touches[0].did_exceed_tap_distance => None,
+            count => {
+                let moved: Vec2 = active_touches.iter().map(|point| point.position - point.previous_position).sum();
+                Some(moved / count as f32)
+            },
+        }
+    }
+
+    /// The pinch of the first two fingers that touch the screen
+    pub fn pinch(&self) -> Option<Pinch> {
+        let (a, b) = (self.points.get(self.touch_order.first()?)?, self.points.get(self.touch_order.get(1)?)?);
+
+        let previous_distance = a.previous_position.distance(b.previous_position);
+        let distance = a.position.distance(b.position);
+        let scale = if previous_distance > 0.0 { distance / previous_distance } else { 1.0 };
+
+        Some(Pinch { scale, center: (a.position + b.position) / 2.0 })
+    }
+
+    fn active_touches(&self) -> impl Iterator<Item = &TouchPoint> {
+        self.points.values()
+    }
+}
+
+fn phase_is_active(phase: TouchPhase) -> bool {
+    phase != TouchPhase::Ended && phase != TouchPhase::Cancelled
+}
@@ -0,0 +1,171 @@
+use glam::Vec2;
+
+use crate::input::{GestureSettings, Input, InputEvent, MouseButton, TouchDevice, TouchEvent, TouchId, TouchPhase};
+
+#[test]
+fn test_touch_points() {
+    let mut touch = TouchDevice::new();
+
+    touch.register_event(TouchEvent::started(1, 10.0, 20.0));
+    assert!(touch.is_touching());
+    assert_eq!(TouchPhase::Started, touch.touch(TouchId(1)).unwrap().phase);
+
+    // next frame
+    touch.reset();
+    assert_eq!(TouchPhase::Stationary, touch.touch(TouchId(1)).unwrap().phase);
+
+    touch.register_event(TouchEvent::moved(1, 15.0, 20.0));
+    let point = touch.touch(TouchId(1)).unwrap();
+    assert_eq!(TouchPhase::Moved, point.phase);
+    assert_eq!(Vec2::new(15.0, 20.0), point.position);
+    assert_eq!(Vec2::new(10.0, 20.0), point.previous_position);
+    assert_eq!(Vec2::new(10.0, 20.0), point.start_position);
+
+    // next frame
+    touch.reset();
+    touch.register_event(TouchEvent::ended(1, 15.0, 20.0));
+    assert!(!touch.is_touching());
+    assert_eq!(1, touch.touches().len());
+
+    // next frame
+    touch.reset();
+    assert!(touch.touches().is_empty());
+}
+
+#[test]
+fn test_tap() {
+    let mut touch = TouchDevice::new();
+
+    touch.register_event(TouchEvent::started(1, 10.0, 10.0));
+    touch.reset();
+    touch.register_event(TouchEvent::ended(1, 12.0, 10.0));
+    assert_eq!(Some(Vec2::new(12.0, 10.0)), touch.tap());
+    assert_eq!(None, touch.pan());
+
+    // moving too far is a pan instead of a tap
+    touch.reset();
+    touch.register_event(TouchEvent::started(2, 10.0, 10.0));
+    touch.register_event(TouchEvent::moved(2, 40.0, 10.0));
+    touch.register_event(TouchEvent::ended(2, 40.0, 10.0));
+    assert_eq!(None, touch.tap());
+
+    // holding too long is not a tap
+    touch.reset();
+    touch.set_gesture_settings(GestureSettings { tap_time: 0.1, ..GestureSettings::default() });
+    touch.register_event(TouchEvent::started(3, 10.0, 10.0));
+    for _ in 0..10 {
+        touch.reset();
+    }
+    touch.register_event(TouchEvent::ended(3, 10.0, 10.0));
+    assert_eq!(None, touch.tap());
+}
+
+#[test]
+fn test_pan() {
+    let mut touch = TouchDevice::new();
+
+    touch.register_event(TouchEvent::started(1, 0.0, 0.0));
+    touch.reset();
+    touch.register_event(TouchEvent::moved(1, 5.0, 0.0));
+    // still within the tap distance
+    assert_eq!(None, touch.pan());
+
+    touch.reset();
+    touch.register_event(TouchEvent::moved(1, 25.0, 5.0));
+    assert_eq!(Some(Vec2::new(20.0, 5.0)), touch.pan());
+
+    // keeps panning at a standstill
+    touch.reset();
+    assert_eq!(Some(Vec2::ZERO), touch.pan());
+}
+
+#[test]
+fn test_pinch() {
+    let mut touch = TouchDevice::new();
+
+    touch.register_event(TouchEvent::started(1, 100.0, 100.0));
+    assert_eq!(None, touch.pinch());
+    touch.register_event(TouchEvent::started(2, 200.0, 100.0));
+
+    touch.reset();
+    touch.register_event(TouchEvent::moved(1, 50.0, 100.0));
+    touch.register_event(TouchEvent::moved(2, 250.0, 100.0));
+
+    let pinch = touch.pinch().unwrap();
+    assert_eq!(2.0, pinch.scale);
+    assert_eq!(Vec2::new(150.0, 100.0), pinch.center);
+    assert_eq!(Some(Vec2::ZERO), touch.pan());
+
+    // lifting a finger after a pinch is not a tap
+    touch.reset();
+    touch.register_event(TouchEvent::ended(2, 250.0, 100.0));
+    assert_eq!(None, touch.tap());
+    assert_eq!(None, touch.pinch());
+}
+
+#[test]
+fn test_pinch_uses_first_fingers() {
+    let mut touch = TouchDevice::new();
+
+    touch.register_event(TouchEvent::started(5, 100.0, 100.0));
+    touch.register_event(TouchEvent::started(3, 200.0, 100.0));
+    touch.register_event(TouchEvent::started(1, 0.0, 0.0));
+
+    touch.reset();
+    touch.register_event(TouchEvent::moved(5, 50.0, 100.0));
+    touch.register_event(TouchEvent::moved(3, 250.0, 100.0));
+
+    // the third finger has the lowest id, but did not touch the screen first
+    let pinch = touch.pinch().unwrap();
+    assert_eq!(2.0, pinch.scale);
+    assert_eq!(Vec2::new(150.0, 100.0), pinch.center);
+}
+
+#[test]
+fn test_reused_touch_id() {
+    let mut touch = TouchDevice::new();
+
+    touch.register_event(TouchEvent::started(1, 10.0, 10.0));
+    touch.reset();
+    touch.register_event(TouchEvent::ended(1, 10.0, 10.0));
+    touch.register_event(TouchEvent::started(1, 50.0, 50.0));
+
+    // the new touch with the same id does not replace the tap
+    assert_eq!(Some(Vec2::new(10.0, 10.0)), touch.tap());
+    assert_eq!(2, touch.touches().len());
+    assert_eq!(TouchPhase::Started, touch.touch(TouchId(1)).unwrap().phase);
+    assert_eq!(1, touch.active_touch_count());
+
+    // next frame
+    touch.reset();
+    assert_eq!(None, touch.tap());
+    assert_eq!(Vec2::new(50.0, 50.0), touch.touch(TouchId(1)).unwrap().position);
+}
+
+#[test]
+fn test_mouse_emulation() {
+    let mut input = Input::new();
+
+    input.register_event(InputEvent::Touch(TouchEvent::started(1, 10.0, 10.0)));
+    assert!(!input.mouse.is_button_down(MouseButton::Left));
+
+    input.reset();
+    input.touch.set_mouse_emulation(true);
+    input.register_event(InputEvent::Touch(TouchEvent::started(2, 10.0, 10.0)));
+    input.register_event(InputEvent::Touch(TouchEvent::ended(1, 10.0, 10.0)));
+    // only the first finger drives the mouse
+    assert!(!input.mouse.is_button_down(MouseButton::Left));
+
+    input.reset();
+    input.register_event(InputEvent::Touch(TouchEvent::started(3, 30.0, 40.0)));
+    assert!(input.mouse.is_button_down(MouseButton::Left));
+    assert_eq!(Vec2::new(30.0, 40.0), input.mouse.get_position());
+
+    input.reset();
+    input.register_event(InputEvent::Touch(TouchEvent::moved(3, 35.0, 50.0)));
+    assert_eq!(Vec2::new(5.0, 10.0), input.mouse.get_moved());
+
+    input.reset();
+    input.register_event(InputEvent::Touch(TouchEvent::ended(3, 35.0, 50.0)));
+    assert!(input.mouse.is_button_up(MouseButton::Left));
+}
@@ -0,0 +1,105 @@
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+use crate::input::ButtonState;
+
+use super::{GamepadAxis, GamepadBackend, GamepadButton, GamepadEvent, GamepadId};
+
+struct Script {
+    /// Events per poll, starting at the next poll
+    frames: VecDeque<Vec<GamepadEvent>>,
+    /// Index in `frames` that new events get added to
+    current_frame: usize,
+}
+
+/// A gamepad that is controlled from code, so controller-driven logic can be tested without a controller.
+/// Clones share the same script, so keep a clone after handing one to `GamepadDevice::add_backend`.
+///
+/// Events get delivered at the next poll, unless `wait` was used to schedule them for a later one:
+/// `gamepad.connect().press(GamepadButton::South).wait(3).release(GamepadButton::South)` holds the button for
+/// three frames.
+#[derive(Clone)]
+pub struct VirtualGamepad {
+    id: GamepadId,
+    name: String,
+    script: Rc<RefCell<Script>>,
+}
+
+impl VirtualGamepad {
+    pub fn new(id: GamepadId) -> Self {
+        Self {
+            id,
+            name: "Virtual gamepad".to_string(),
+            script: Rc::new(RefCell::new(Script { frames: VecDeque::new(), current_frame: 0 })),
+        }
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn id(&self) -> GamepadId {
+        self.id
+    }
+
+    pub fn connect(&self) -> &Self {
+        self.push(GamepadEvent::Connected { id: self.id, name: self.name.clone() })
+    }
+
+    pub fn disconnect(&self) -> &Self {
+        self.push(GamepadEvent::Disconnected { id: self.id })
+    }
+
+    pub fn press(&self, button: GamepadButton) -> &Self {
+        self.push(GamepadEvent::Button { id: self.id, button, state: ButtonState::Down })
+    }
+
+    pub fn release(&self, button: GamepadButton) -> &Self {
+        self.push(GamepadEvent::Button { id: self.id, button, state: ButtonState::Up })
+    }
+
+    pub fn set_axis(&self, axis: GamepadAxis, value: f32) -> &Self {
+        self.push(GamepadEvent::Axis { id: self.id, axis, value })
+    }
+
+    pub fn set_left_stick(&self, x: f32, y: f32) -> &Self {
+        self.set_axis(GamepadAxis::LeftStickX, x).set_axis(GamepadAxis::LeftStickY, y)
+    }
+
+    pub fn set_right_stick(&self, x: f32, y: f32) -> &Self {
+        self.set_axis(GamepadAxis::RightStickX, x).set_axis(GamepadAxis::RightStickY, y)
+    }
+
+    /// Events that get added after this are delivered `polls` polls later
+    pub fn wait(&self, polls: usize) -> &Self {
+        self.script.borrow_mut().current_frame += polls;
+        self
+    }
+
+    /// Whether all scripted events have been delivered
+    pub fn is_finished(&self) -> bool {
+        self.script.borrow().frames.iter().all(|events| events.is_empty())
+    }
+
+    fn push(&self, event: GamepadEvent) -> &Self {
+        let mut script = self.script.borrow_mut();
+        let current_frame = script.current_frame;
+
+        while script.frames.len() <= current_frame {
+            script.frames.push_back(vec![]);
+        }
+        script.frames[current_frame].push(event);
+
+        drop(script);
+        self
+    }
+}
+
+impl GamepadBackend for VirtualGamepad {
+    fn poll(&mut self) -> Vec<GamepadEvent> {
+        let mut script = self.script.borrow_mut();
+        script.current_frame = script.current_frame.saturating_sub(1);
+
+        script.frames.pop_front().unwrap_or_default()
+    }
+}
@@ -1,8 +1,13 @@
-use super::{KeyboardDevice, MouseDevice};
+use super::{GamepadDevice, InputEvent, InputRecording, KeyboardDevice, MouseDevice, TextDevice, TouchDevice};
 
 pub struct Input {
     pub mouse: MouseDevice,
     pub keyboard: KeyboardDevice,
+    pub text: TextDevice,
+    pub gamepads: GamepadDevice,
+    pub touch: TouchDevice,
+    recording: Option<InputRecording>,
+    current_frame_events: Vec<InputEvent>,
 }
 
 impl Input {
@@ -10,12 +15,63 @@ impl Input {
         return Input { 
             mouse: MouseDevice::new(),
             keyboard: KeyboardDevice::new(),
+            text: TextDevice::new(),
+            gamepads: GamepadDevice::new(),
+            touch: TouchDevice::new(),
+            recording: None,
+            current_frame_events: vec![],
+        }
+    }
+
+    /// Register an event on the device it belongs to. Unlike registering it on the device directly, this
+    /// makes the event part of the recording, if one is running.
+    pub fn register_event(&mut self, event: InputEvent) {
+        event.apply(self);
+
+        if self.recording.is_some() {
+            self.current_frame_events.push(event);
+        }
+    }
+
+    /// Register the events of all gamepad backends. The window does this once per frame.
+    pub fn poll_gamepads(&mut self) {
+        for event in self.gamepads.poll_backends() {
+            self.register_event(InputEvent::Gamepad(event));
         }
     }
 
     pub fn reset(&mut self) {
         self.mouse.reset();
         self.keyboard.reset();
+        self.text.reset();
+        self.gamepads.reset();
+        self.touch.reset();
+
+        if let Some(recording) = &mut self.recording {
+            recording.frames.push(std::mem::take(&mut self.current_frame_events));
+        }
+    }
+
+    /// Start recording every event that gets registered through `register_event`. Every call to `reset`
+    /// closes a frame of the recording.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(InputRecording::new());
+        self.current_frame_events.clear();
+    }
+
+    /// Stop recording. Events that were registered after the last reset are part of the last frame.
+    pub fn stop_recording(&mut self) -> Option<InputRecording> {
+        let mut recording = self.recording.take()?;
+
+        if !self.current_frame_events.is_empty() {
+            recording.frames.push(std::mem::take(&mut self.current_frame_events));
+        }
+
+        Some(recording)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
     }
 }
 
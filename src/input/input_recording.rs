@@ -0,0 +1,129 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AssetError, LazuliError};
+
+use super::{ButtonState, GamepadEvent, ImeEvent, Input, Key, Modifiers, MouseButton, TouchEvent};
+
+/// Name of the environment variable that can be used to record input without changing the code. Its value is
+/// the path to save the recording to.
+pub const INPUT_RECORDING_PATH_ENV_VAR: &str = "LAZULI_INPUT_RECORDING";
+
+/// A single event that the window registered on `Input`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum InputEvent {
+    Key { key: Key, state: ButtonState },
+    Modifiers(Modifiers),
+    MouseButton { button: MouseButton, state: ButtonState },
+    /// In logical pixels
+    ScrollX(f64),
+    /// In logical pixels
+    ScrollY(f64),
+    /// In lines, which get converted to pixels when the event gets applied
+    ScrollLines { x: f64, y: f64 },
+    Reposition { x: f64, y: f64 },
+    Move { x: f64, y: f64 },
+    Character(char),
+    Ime(ImeEvent),
+    Gamepad(GamepadEvent),
+    Touch(TouchEvent),
+}
+
+impl InputEvent {
+    pub fn apply(&self, input: &mut Input) {
+        match self.clone() {
+            InputEvent::Key { key, state } => input.keyboard.register_key_event(key, state),
+            InputEvent::Modifiers(modifiers) => input.keyboard.register_modifiers_event(modifiers),
+            InputEvent::MouseButton { button, state } => input.mouse.register_button_event(button, state),
+            InputEvent::ScrollX(scroll) => input.mouse.register_scroll_x_event(scroll),
+            InputEvent::ScrollY(scroll) => input.mouse.register_scroll_y_event(scroll),
+            InputEvent::ScrollLines { x, y } => input.mouse.register_scroll_lines_event(x, y),
+            InputEvent::Reposition { x, y } => input.mouse.register_reposition_event(x, y),
+            InputEvent::Move { x, y } => input.mouse.register_move_event(x, y),
+            InputEvent::Character(character) => input.text.register_character_event(character),
+            InputEvent::Ime(event) => input.text.register_ime_event(event),
+            InputEvent::Gamepad(event) => input.gamepads.register_event(event),
+            InputEvent::Touch(event) => {
+                input.touch.register_event(event);
+                input.touch.emulate_mouse_event(&event, &mut input.mouse);
+            },
+        }
+    }
+}
+
+/// The input events that were registered between `Input::start_recording` and `Input::stop_recording`,
+/// grouped per update. Every update consumes the input of exactly one entry in `frames`, so feeding them back
+/// one update at a time reproduces the session exactly.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct InputRecording {
+    pub frames: Vec<Vec<InputEvent>>,
+}
+
+impl InputRecording {
+    pub fn new() -> Self {
+        Self { frames: vec![] }
+    }
+
+    pub fn number_of_frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn to_json(&self) -> Result<String, LazuliError> {
+        serde_json::to_string(self).map_err(|err| AssetError::Json { what: "input recording", source: err }.into())
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, LazuliError> {
+        serde_json::from_str(json).map_err(|err| AssetError::Json { what: "input recording", source: err }.into())
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), LazuliError> {
+        fs::write(path.as_ref(), self.to_json()?).map_err(|err| {
+            AssetError::Io { path: path.as_ref().to_string_lossy().to_string(), source: err }.into()
+        })
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LazuliError> {
+        let json = fs::read_to_string(path.as_ref()).map_err(|err| {
+            LazuliError::from(AssetError::Io { path: path.as_ref().to_string_lossy().to_string(), source: err })
+        })?;
+
+        Self::from_json(&json)
+    }
+}
+
+/// Feeds the frames of an `InputRecording` back into `Input`, one frame per update
+pub struct InputReplay {
+    recording: InputRecording,
+    next_frame: usize,
+}
+
+impl InputReplay {
+    pub fn new(recording: InputRecording) -> Self {
+        Self { recording, next_frame: 0 }
+    }
+
+    /// Register the events of the next frame on `input`. This should be done right before an update. Returns
+    /// false if the replay already finished.
+    pub fn apply_next_frame(&mut self, input: &mut Input) -> bool {
+        let events = match self.recording.frames.get(self.next_frame) {
+            Some(events) => events,
+            None => return false,
+        };
+
+        for event in events {
+            event.apply(input);
+        }
+
+        self.next_frame += 1;
+        true
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_frame >= self.recording.frames.len()
+    }
+
+    pub fn frames_left(&self) -> usize {
+        self.recording.frames.len().saturating_sub(self.next_frame)
+    }
+}
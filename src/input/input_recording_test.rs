@@ -0,0 +1,85 @@
+use crate::input::{ButtonState, Input, InputEvent, InputRecording, InputReplay, Key, MouseButton};
+
+#[test]
+fn test_recording_groups_events_per_reset() {
+    let mut input = Input::new();
+    input.start_recording();
+
+    input.register_event(InputEvent::Key { key: Key::A, state: ButtonState::Down });
+    input.register_event(InputEvent::Move { x: 1.0, y: 2.0 });
+    input.reset();
+    input.reset();
+    input.register_event(InputEvent::Key { key: Key::A, state: ButtonState::Up });
+
+    let recording = input.stop_recording().unwrap();
+
+    assert_eq!(3, recording.number_of_frames());
+    assert_eq!(vec![
+        InputEvent::Key { key: Key::A, state: ButtonState::Down },
+        InputEvent::Move { x: 1.0, y: 2.0 },
+    ], recording.frames[0]);
+    assert!(recording.frames[1].is_empty());
+    assert_eq!(vec![InputEvent::Key { key: Key::A, state: ButtonState::Up }], recording.frames[2]);
+    assert!(!input.is_recording());
+}
+
+#[test]
+fn test_events_are_not_kept_without_recording() {
+    let mut input = Input::new();
+
+    input.register_event(InputEvent::Key { key: Key::A, state: ButtonState::Down });
+    assert!(input.keyboard.is_key_down(Key::A));
+
+    input.reset();
+    assert!(input.stop_recording().is_none());
+}
+
+#[test]
+fn test_recording_json_round_trip() {
+    let recording = InputRecording {
+        frames: vec![
+            vec![
+                InputEvent::MouseButton { button: MouseButton::Left, state: ButtonState::Down },
+                InputEvent::Reposition { x: 10.5, y: 20.0 },
+            ],
+            vec![],
+            vec![InputEvent::ScrollX(-3.0), InputEvent::ScrollY(1.25)],
+        ],
+    };
+
+    let json = recording.to_json().unwrap();
+
+    assert_eq!(recording, InputRecording::from_json(&json).unwrap());
+}
+
+#[test]
+fn test_replay_applies_one_frame_per_update() {
+    let recording = InputRecording {
+        frames: vec![
+            vec![InputEvent::Key { key: Key::Space, state: ButtonState::Down }],
+            vec![InputEvent::Move { x: 3.0, y: 0.0 }],
+        ],
+    };
+
+    let mut input = Input::new();
+    let mut replay = InputReplay::new(recording);
+
+    assert!(replay.apply_next_frame(&mut input));
+    assert!(input.keyboard.is_key_down(Key::Space));
+    assert!(!input.mouse.did_move());
+    input.reset();
+
+    assert!(replay.apply_next_frame(&mut input));
+    assert!(input.keyboard.is_key_held(Key::Space));
+    assert_eq!(3.0, input.mouse.get_moved_x());
+    input.reset();
+
+    assert!(replay.is_finished());
+    assert!(!replay.apply_next_frame(&mut input));
+}
+
+#[test]
+fn test_invalid_recording_json() {
+    let err = InputRecording::from_json("{ \"frames\": 3 }").unwrap_err();
+    assert!(err.to_string().starts_with("failed to convert input recording to or from json: invalid type"), "{}", err);
+}
@@ -1,6 +1,26 @@
 mod input;
 pub use input::Input;
 
+mod input_recording;
+pub use input_recording::InputEvent;
+pub use input_recording::InputRecording;
+pub use input_recording::InputReplay;
+pub use input_recording::INPUT_RECORDING_PATH_ENV_VAR;
+
+mod action_map;
+pub use action_map::ActionMap;
+pub use action_map::Actions;
+pub use action_map::InputBinding;
+pub use action_map::AxisBinding;
+pub use action_map::Axis2dBinding;
+
+mod smooth_scroll;
+pub use smooth_scroll::SmoothScroll;
+pub use smooth_scroll::ScrollMode;
+
+mod shortcut;
+pub use shortcut::Shortcut;
+
 mod device;
 pub use device::*;
 
@@ -10,3 +30,11 @@ pub use button::ButtonState;
 
 #[cfg(test)]
 pub mod button_test;
+#[cfg(test)]
+pub mod input_recording_test;
+#[cfg(test)]
+pub mod action_map_test;
+#[cfg(test)]
+pub mod shortcut_test;
+#[cfg(test)]
+pub mod smooth_scroll_test;
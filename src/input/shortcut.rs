@@ -0,0 +1,109 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{InputError, LazuliError};
+
+use super::{Key, Modifiers};
+
+/// A key combined with the modifiers that have to be held, like `Ctrl+Shift+S`. Gets (de)serialized as the
+/// string it displays as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Shortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl Shortcut {
+    pub fn new(key: Key) -> Self {
+        Self { modifiers: Modifiers::NONE, key }
+    }
+
+    pub fn with_shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    pub fn with_ctrl(mut self) -> Self {
+        self.modifiers.ctrl = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> Self {
+        self.modifiers.alt = true;
+        self
+    }
+
+    pub fn with_super(mut self) -> Self {
+        self.modifiers.super_key = true;
+        self
+    }
+}
+
+/// Parses shortcuts like "Ctrl+Shift+S", "alt+F4" or "Ctrl++". Modifiers may come in any order, but there has
+/// to be exactly one key that is not a modifier.
+impl FromStr for Shortcut {
+    type Err = LazuliError;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        // A trailing "++" means the plus key, which would otherwise get lost when splitting
+        let (body, ends_with_plus) = match shortcut.strip_suffix("++") {
+            Some(body) => (body, true),
+            None => (shortcut, false),
+        };
+
+        let mut names: Vec<&str> = body.split('+').map(|name| name.trim()).collect();
+        if ends_with_plus {
+            names.push("+");
+        }
+
+        let mut modifiers = Modifiers::NONE;
+        let mut key: Option<Key> = None;
+
+        for name in names {
+            let named_key = Key::from_name(name).ok_or_else(|| {
+                InputError::UnknownKey { shortcut: shortcut.to_string(), key: name.to_string() }
+            })?;
+
+            let modifier = Modifiers::from_key(named_key);
+            if !modifier.is_empty() {
+                modifiers = modifiers.union(modifier);
+            } else if key.is_none() {
+                key = Some(named_key);
+            } else {
+                return Err(InputError::MultipleKeys(shortcut.to_string()).into());
+            }
+        }
+
+        match key {
+            Some(key) => Ok(Self { modifiers, key }),
+            None => Err(InputError::MissingKey(shortcut.to_string()).into()),
+        }
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.ctrl { f.write_str("Ctrl+")?; }
+        if self.modifiers.shift { f.write_str("Shift+")?; }
+        if self.modifiers.alt { f.write_str("Alt+")?; }
+        if self.modifiers.super_key { f.write_str("Super+")?; }
+
+        f.write_str(self.key.name())
+    }
+}
+
+impl TryFrom<String> for Shortcut {
+    type Error = LazuliError;
+
+    fn try_from(shortcut: String) -> Result<Self, Self::Error> {
+        shortcut.parse()
+    }
+}
+
+impl From<Shortcut> for String {
+    fn from(shortcut: Shortcut) -> Self {
+        shortcut.to_string()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{error::{InputError, LazuliError}, input::{ActionMap, ButtonState, Input, InputBinding, InputEvent, Key, KeyboardDevice, Modifiers, Shortcut}};
+
+#[test]
+fn test_parse_shortcut() {
+    let shortcut: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+    assert_eq!(Shortcut::new(Key::S).with_ctrl().with_shift(), shortcut);
+
+    let shortcut: Shortcut = " alt + f4 ".parse().unwrap();
+    assert_eq!(Shortcut::new(Key::F4).with_alt(), shortcut);
+
+    let shortcut: Shortcut = "Cmd++".parse().unwrap();
+    assert_eq!(Shortcut::new(Key::Plus).with_super(), shortcut);
+
+    let shortcut: Shortcut = "Esc".parse().unwrap();
+    assert_eq!(Shortcut::new(Key::Escape), shortcut);
+}
+
+#[test]
+fn test_parse_invalid_shortcut() {
+    assert!(matches!("Ctrl+Foo".parse::<Shortcut>(), Err(LazuliError::Input(InputError::UnknownKey { .. }))));
+    assert!(matches!("Ctrl+Shift".parse::<Shortcut>(), Err(LazuliError::Input(InputError::MissingKey(_)))));
+    assert!(matches!("A+B".parse::<Shortcut>(), Err(LazuliError::Input(InputError::MultipleKeys(_)))));
+}
+
+#[test]
+fn test_display_round_trip() {
+    let shortcut = Shortcut::new(Key::Z).with_shift().with_ctrl();
+    assert_eq!("Ctrl+Shift+Z", shortcut.to_string());
+    assert_eq!(shortcut, shortcut.to_string().parse().unwrap());
+
+    let json = serde_json::to_string(&shortcut).unwrap();
+    assert_eq!("\"Ctrl+Shift+Z\"", json);
+    assert_eq!(shortcut, serde_json::from_str::<Shortcut>(&json).unwrap());
+}
+
+#[test]
+fn test_is_shortcut_triggered() {
+    let save: Shortcut = "Ctrl+S".parse().unwrap();
+    let save_as: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+    let mut keyboard = KeyboardDevice::new();
+
+    keyboard.register_key_event(Key::Cntrl, ButtonState::Down);
+    keyboard.register_key_event(Key::S, ButtonState::Down);
+    assert!(keyboard.is_shortcut_triggered(&save));
+    assert!(!keyboard.is_shortcut_triggered(&save_as));
+
+    // next frame, S is still held but was not pressed again
+    keyboard.reset();
+    assert!(!keyboard.is_shortcut_triggered(&save));
+    assert!(keyboard.is_shortcut_held(&save));
+}
+
+#[test]
+fn test_modifiers_reported_by_window() {
+    let save_as: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+    let mut input = Input::new();
+
+    // for example right shift, which has no key of its own
+    input.register_event(InputEvent::Modifiers(Modifiers { shift: true, ..Modifiers::NONE }));
+    input.register_event(InputEvent::Key { key: Key::Cntrl, state: ButtonState::Down });
+    input.register_event(InputEvent::Key { key: Key::S, state: ButtonState::Down });
+
+    assert_eq!(Modifiers { shift: true, ctrl: true, ..Modifiers::NONE }, input.keyboard.modifiers());
+    assert!(input.keyboard.is_shortcut_triggered(&save_as));
+}
+
+#[test]
+fn test_shortcut_action() {
+    let action_map = ActionMap::new().with_action("save", InputBinding::Shortcut("Ctrl+S".parse().unwrap()));
+    let mut input = Input::new();
+
+    input.keyboard.register_key_event(Key::S, ButtonState::Down);
+    assert!(!action_map.read(&input).is_pressed("save"));
+
+    input.reset();
+    input.keyboard.register_key_event(Key::S, ButtonState::Up);
+    input.keyboard.register_key_event(Key::Cntrl, ButtonState::Down);
+    input.keyboard.register_key_event(Key::S, ButtonState::Down);
+    assert!(action_map.read(&input).is_pressed("save"));
+}
@@ -0,0 +1,79 @@
+use crate::{input::{ScrollMode, SmoothScroll}, time};
+
+#[test]
+fn test_instant_scroll() {
+    let mut scroll = SmoothScroll::new(ScrollMode::Instant);
+    scroll.set_bounds(0.0, 100.0);
+
+    scroll.scroll_by(30.0);
+    scroll.scroll_by(20.0);
+    assert_eq!(0.0, scroll.position());
+    assert_eq!(50.0, scroll.update(time::DELTA));
+
+    scroll.scroll_by(80.0);
+    assert_eq!(100.0, scroll.update(time::DELTA));
+    assert!(!scroll.is_moving());
+}
+
+#[test]
+fn test_smooth_scroll() {
+    let mut scroll = SmoothScroll::new(ScrollMode::Smooth { speed: 10.0 });
+    scroll.set_bounds(0.0, 100.0);
+
+    scroll.scroll_by(50.0);
+    let first = scroll.update(time::DELTA);
+    assert!(first > 0.0 && first < 50.0);
+    assert_eq!(50.0, scroll.target());
+
+    let mut last = first;
+    for _ in 0..time::TICK_RATE * 2 {
+        let position = scroll.update(time::DELTA);
+        assert!(position >= last);
+        last = position;
+    }
+    assert_eq!(50.0, last);
+    assert!(!scroll.is_moving());
+}
+
+#[test]
+fn test_kinetic_scroll() {
+    let mut scroll = SmoothScroll::new(ScrollMode::Kinetic { friction: 5.0 });
+    scroll.set_bounds(0.0, 1000.0);
+
+    scroll.scroll_by(10.0);
+    assert_eq!(10.0, scroll.update(time::DELTA));
+
+    // keeps moving after the input stopped
+    let after_input = scroll.update(time::DELTA);
+    assert!(after_input > 10.0);
+
+    for _ in 0..time::TICK_RATE * 5 {
+        scroll.update(time::DELTA);
+    }
+    assert!(!scroll.is_moving());
+    let resting_position = scroll.position();
+    assert_eq!(resting_position, scroll.update(time::DELTA));
+}
+
+#[test]
+fn test_kinetic_scroll_stops_at_bounds() {
+    let mut scroll = SmoothScroll::new(ScrollMode::Kinetic { friction: 1.0 });
+    scroll.set_bounds(0.0, 20.0);
+
+    scroll.scroll_by(15.0);
+    scroll.update(time::DELTA);
+    assert_eq!(20.0, scroll.update(time::DELTA));
+    assert!(!scroll.is_moving());
+}
+
+#[test]
+fn test_jump_to() {
+    let mut scroll = SmoothScroll::new(ScrollMode::Smooth { speed: 10.0 });
+    scroll.set_bounds(0.0, 100.0);
+
+    scroll.scroll_by(50.0);
+    scroll.update(time::DELTA);
+    scroll.jump_to(200.0);
+    assert_eq!(100.0, scroll.position());
+    assert_eq!(100.0, scroll.update(time::DELTA));
+}
@@ -1,4 +1,5 @@
 use app::App;
+use error::LazuliError;
 use graphics::{scene::Scene, window::WindowBuilder};
 
 pub mod graphics;
@@ -9,6 +10,8 @@ pub mod input;
 pub mod time;
 pub mod math;
 pub mod asset_manager;
+pub mod profiler;
+pub mod world;
 
 // TODO only expose the 3 commented out functions from `mod log`
 pub mod log;
@@ -28,11 +31,28 @@ mod resource_id;
 pub use resource_id::ResourceId;
 
 mod app;
+pub use app::HeadlessApp;
+pub use app::OffscreenApp;
 
 pub fn run_scene<T: Scene + 'static>(window_builder: WindowBuilder) {
     App::new::<T>(window_builder);
 }
 
+pub fn run_scene_headless<T: Scene>(window_builder: WindowBuilder, frames: u64) -> Result<HeadlessApp, LazuliError> {
+    App::run_headless::<T>(&window_builder, frames)
+}
+
+/// Run a scene without a window, feeding it the input that was saved by `WindowBuilder::with_input_recording`
+pub fn replay_scene_headless<T: Scene>(window_builder: WindowBuilder, recording_path: &str) -> Result<HeadlessApp, LazuliError> {
+    let recording = input::InputRecording::load(recording_path)?;
+
+    let mut app = HeadlessApp::from_window_builder::<T>(&window_builder)?;
+    app.replay_input(recording);
+    app.run_replay();
+
+    Ok(app)
+}
+
 pub fn hello_triangle() {
     run_scene::<scenes::HelloTriangle>(WindowBuilder::new());
 }
@@ -0,0 +1,26 @@
+use std::cell::RefCell;
+
+use super::{profiler::DEFAULT_HISTORY_SIZE, Profiler, ProfilerRecording};
+
+// The engine runs on a single thread, so a thread local profiler is available from anywhere in the main loop,
+// including scenes, without having to pass it around.
+thread_local! {
+    static PROFILER: RefCell<Profiler> = RefCell::new(Profiler::new(DEFAULT_HISTORY_SIZE));
+}
+
+pub fn enable() { PROFILER.with(|profiler| profiler.borrow_mut().set_enabled(true)); }
+pub fn disable() { PROFILER.with(|profiler| profiler.borrow_mut().set_enabled(false)); }
+pub fn is_enabled() -> bool { PROFILER.with(|profiler| profiler.borrow().is_enabled()) }
+
+/// Start measuring a scope of the current frame. Every call must be followed by an `end_scope` with the same name.
+pub fn begin_scope(name: &str) { PROFILER.with(|profiler| profiler.borrow_mut().begin_scope(name)); }
+pub fn end_scope(name: &str) { PROFILER.with(|profiler| profiler.borrow_mut().end_scope(name)); }
+pub fn end_frame() { PROFILER.with(|profiler| profiler.borrow_mut().end_frame()); }
+
+pub fn start_recording() { PROFILER.with(|profiler| profiler.borrow_mut().start_recording()); }
+pub fn stop_recording() -> Option<ProfilerRecording> { PROFILER.with(|profiler| profiler.borrow_mut().stop_recording()) }
+
+/// Read the measurements of the profiler
+pub fn with_profiler<R>(f: impl FnOnce(&Profiler) -> R) -> R {
+    PROFILER.with(|profiler| f(&profiler.borrow()))
+}
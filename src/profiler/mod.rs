@@ -0,0 +1,16 @@
+mod profiler;
+pub use profiler::*;
+
+mod global_profiler;
+pub use global_profiler::*;
+
+mod profiler_recording;
+pub use profiler_recording::*;
+
+mod profiler_overlay;
+pub use profiler_overlay::ProfilerOverlay;
+
+#[cfg(test)]
+mod profiler_test;
+#[cfg(test)]
+mod profiler_recording_test;
@@ -0,0 +1,280 @@
+use std::{collections::VecDeque, time::{Duration, Instant}};
+
+use crate::log;
+
+use super::{profiler_recording::{TraceEvent, FRAME_EVENT_NAME}, ProfilerRecording};
+
+pub const DEFAULT_HISTORY_SIZE: usize = 120;
+
+// Scopes of the phases of the main loop that get measured by the engine
+pub const SCOPE_INPUT: &str = "input";
+pub const SCOPE_INTERFACE_UPDATE: &str = "interface update";
+pub const SCOPE_SCENE_UPDATE: &str = "scene update";
+pub const SCOPE_DRAW: &str = "draw";
+pub const SCOPE_SWAP_BUFFERS: &str = "swap buffers";
+
+/// Timings of a single scope within a frame. When a scope gets measured multiple times in the same frame (for
+/// example because the scene got updated multiple times to catch up), the durations get added up.
+#[derive(Clone, Debug)]
+pub struct ScopeProfile {
+    pub name: String,
+    pub duration: Duration,
+    pub calls: u32,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FrameProfile {
+    /// Time between the end of the previous frame and the end of this frame
+    pub duration: Duration,
+    /// Scopes in the order in which they were first measured
+    pub scopes: Vec<ScopeProfile>,
+}
+
+impl FrameProfile {
+    pub fn scope(&self, name: &str) -> Option<&ScopeProfile> {
+        self.scopes.iter().find(|scope| scope.name == name)
+    }
+
+    fn add_to_scope(&mut self, name: &str, duration: Duration) {
+        match self.scopes.iter_mut().find(|scope| scope.name == name) {
+            Some(scope) => {
+                scope.duration += duration;
+                scope.calls += 1;
+            },
+            None => {
+                self.scopes.push(ScopeProfile { name: name.to_string(), duration, calls: 1 });
+            },
+        }
+    }
+}
+
+struct OpenScope {
+    name: String,
+    start: Instant,
+}
+
+struct ActiveRecording {
+    start: Instant,
+    recording: ProfilerRecording,
+}
+
+impl ActiveRecording {
+    fn add_event(&mut self, name: &str, frame: u64, start: Instant, duration: Duration) {
+        self.recording.events.push(TraceEvent {
+            name: name.to_string(),
+            frame,
+            start: start.saturating_duration_since(self.start),
+            duration,
+        });
+    }
+}
+
+/// Measures how long named scopes take within a frame and keeps a rolling history of the last frames.
+///
+/// A profiler does nothing until it gets enabled, so measurements can be left in place at (almost) no cost.
+pub struct Profiler {
+    enabled: bool,
+    history_size: usize,
+    history: VecDeque<FrameProfile>,
+    current_frame: FrameProfile,
+    open_scopes: Vec<OpenScope>,
+    last_frame_end: Option<Instant>,
+    frame_count: u64,
+    recording: Option<ActiveRecording>,
+}
+
+impl Profiler {
+    pub fn new(history_size: usize) -> Self {
+        Self {
+            enabled: false,
+            history_size: history_size.max(1),
+            history: VecDeque::with_capacity(history_size),
+            current_frame: Default::default(),
+            open_scopes: vec![],
+            last_frame_end: None,
+            frame_count: 0,
+            recording: None,
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+
+        if !enabled {
+            self.open_scopes.clear();
+            self.current_frame = Default::default();
+            self.last_frame_end = None;
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn begin_scope(&mut self, name: &str) {
+        if !self.enabled {
+            return;
+        }
+
+        self.open_scopes.push(OpenScope { name: name.to_string(), start: Instant::now() });
+    }
+
+    /// Scopes can be nested, but must be ended in the reverse order in which they were started
+    pub fn end_scope(&mut self, name: &str) {
+        if !self.enabled {
+            return;
+        }
+
+        match self.open_scopes.pop() {
+            Some(scope) if scope.name == name => {
+                let duration = scope.start.elapsed();
+                self.current_frame.add_to_scope(name, duration);
+
+                if let Some(recording) = &mut self.recording {
+                    recording.add_event(name, self.frame_count, scope.start, duration);
+                }
+            },
+            Some(scope) => {
+                log::engine_warn(format!("Profiler can not end scope '{}' because scope '{}' was started last", name, scope.name));
+                self.open_scopes.push(scope);
+            },
+            None => {
+                log::engine_warn(format!("Profiler can not end scope '{}' because it was never started", name));
+            },
+        }
+    }
+
+    /// Add a measurement that was taken elsewhere to the current frame
+    pub fn record_scope(&mut self, name: &str, duration: Duration) {
+        if !self.enabled {
+            return;
+        }
+
+        self.current_frame.add_to_scope(name, duration);
+
+        if let Some(recording) = &mut self.recording {
+            let now = Instant::now();
+            recording.add_event(name, self.frame_count, now.checked_sub(duration).unwrap_or(now), duration);
+        }
+    }
+
+    /// Finish the current frame, using the time since the previous call to `end_frame` as its duration
+    pub fn end_frame(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        let now = Instant::now();
+        let duration = match self.last_frame_end {
+            Some(last_frame_end) => now.duration_since(last_frame_end),
+            None => Duration::ZERO,
+        };
+        self.last_frame_end = Some(now);
+
+        self.record_frame(duration);
+    }
+
+    /// Finish the current frame with the given duration
+    pub fn record_frame(&mut self, duration: Duration) {
+        if !self.enabled {
+            return;
+        }
+
+        if !self.open_scopes.is_empty() {
+            log::engine_warn(format!("Profiler is ending a frame while {} scope(s) are still open", self.open_scopes.len()));
+        }
+
+        let mut frame = std::mem::take(&mut self.current_frame);
+        frame.duration = duration;
+
+        if let Some(recording) = &mut self.recording {
+            let now = Instant::now();
+            recording.add_event(FRAME_EVENT_NAME, self.frame_count, now.checked_sub(duration).unwrap_or(now), duration);
+            recording.recording.frames.push(frame.clone());
+        }
+        self.frame_count += 1;
+
+        if self.history.len() >= self.history_size {
+            self.history.pop_front();
+        }
+        self.history.push_back(frame);
+    }
+
+    /// Keep every measurement from now on, until `stop_recording` gets called. Measurements only get taken
+    /// while the profiler is enabled.
+    pub fn start_recording(&mut self) {
+        if self.recording.is_some() {
+            log::engine_warn("Profiler is already recording");
+            return;
+        }
+
+        self.recording = Some(ActiveRecording { start: Instant::now(), recording: Default::default() });
+    }
+
+    /// Returns `None` if the profiler was not recording
+    pub fn stop_recording(&mut self) -> Option<ProfilerRecording> {
+        self.recording.take().map(|active_recording| active_recording.recording)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// The oldest frame comes first
+    pub fn history(&self) -> &VecDeque<FrameProfile> {
+        &self.history
+    }
+
+    pub fn last_frame(&self) -> Option<&FrameProfile> {
+        self.history.back()
+    }
+
+    /// Names of all scopes in the history, in the order in which they were first measured
+    pub fn scope_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+
+        for frame in self.history.iter() {
+            for scope in frame.scopes.iter() {
+                if !names.contains(&scope.name) {
+                    names.push(scope.name.clone());
+                }
+            }
+        }
+
+        names
+    }
+
+    pub fn average_frame_duration(&self) -> Duration {
+        if self.history.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let total: Duration = self.history.iter().map(|frame| frame.duration).sum();
+        total / self.history.len() as u32
+    }
+
+    pub fn max_frame_duration(&self) -> Duration {
+        self.history.iter().map(|frame| frame.duration).max().unwrap_or(Duration::ZERO)
+    }
+
+    /// Average time spent in a scope per frame. Frames in which the scope was not measured count as zero.
+    pub fn average_scope_duration(&self, name: &str) -> Duration {
+        if self.history.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let total: Duration = self.history.iter()
+            .filter_map(|frame| frame.scope(name))
+            .map(|scope| scope.duration)
+            .sum();
+        total / self.history.len() as u32
+    }
+
+    pub fn max_scope_duration(&self, name: &str) -> Duration {
+        self.history.iter()
+            .filter_map(|frame| frame.scope(name))
+            .map(|scope| scope.duration)
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+}
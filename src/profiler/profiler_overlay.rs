@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use glam::Vec2;
+
+use crate::{error::LazuliError, asset_manager::AssetManager, graphics::{ui::{shapes::RectangleBuilder, AnchorPoint, Interface, Position, TextBuilder, UiElementId, MAX_Z_INDEX}, Color}, ResourceId};
+
+use super::Profiler;
+
+const PADDING: f32 = 8.0;
+const ROW_HEIGHT: f32 = 16.0;
+const WIDTH: f32 = 300.0;
+const FONT_SIZE: f32 = 12.0;
+
+/// Number of updates between refreshes of the text. Updating text is relatively expensive and would
+/// influence the measurements if it were done every update.
+const DEFAULT_REFRESH_INTERVAL: u32 = 30;
+
+/// Shows the measurements of a `Profiler` in the top left corner of the screen, using interface elements
+pub struct ProfilerOverlay {
+    background_id: ResourceId<UiElementId>,
+    row_ids: Vec<ResourceId<UiElementId>>,
+    refresh_interval: u32,
+    updates_until_refresh: u32,
+    is_visible: bool,
+}
+
+impl ProfilerOverlay {
+    pub fn new(interface: &mut Interface, asset_manager: &mut dyn AssetManager) -> Result<Self, LazuliError> {
+        let background_id = interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
+            .with_color(Color::Rgba(0, 0, 0, 0.6))
+            .with_size(Vec2::new(WIDTH, ROW_HEIGHT + PADDING * 2.0))
+            .with_position(Position::ScreenAnchor(AnchorPoint::TopLeftInside(0.0, 0.0)))
+            .with_z_index(MAX_Z_INDEX - 2.0)
+            .with_handle_input_events(false)
+        , asset_manager)?;
+
+        Ok(Self {
+            background_id,
+            row_ids: vec![],
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            updates_until_refresh: 0,
+            is_visible: true,
+        })
+    }
+
+    pub fn with_refresh_interval(mut self, updates: u32) -> Self {
+        self.refresh_interval = updates;
+        self
+    }
+
+    pub fn update(&mut self, profiler: &Profiler, interface: &mut Interface, asset_manager: &mut dyn AssetManager) -> Result<(), LazuliError> {
+        if !self.is_visible {
+            return Ok(());
+        }
+
+        if self.updates_until_refresh > 0 {
+            self.updates_until_refresh -= 1;
+            return Ok(());
+        }
+        self.updates_until_refresh = self.refresh_interval;
+
+        let rows = Self::create_rows(profiler);
+
+        for (i, row) in rows.iter().enumerate() {
+            if i >= self.row_ids.len() {
+                let row_id = interface.mut_element_registry().create_text(row.clone(), None, &TextBuilder::new()
+                    .with_font_size(FONT_SIZE)
+                    .with_position(Position::ScreenAnchor(AnchorPoint::TopLeftInside(PADDING, PADDING + i as f32 * ROW_HEIGHT)))
+                    .with_z_index(MAX_Z_INDEX - 1.0)
+                , asset_manager)?;
+                self.row_ids.push(row_id);
+            } else {
+                interface.mut_element_registry().set_text(&self.row_ids[i], row, asset_manager)?;
+            }
+        }
+
+        interface.mut_element_registry().set_rectangle_size(
+            &self.background_id,
+            Vec2::new(WIDTH, self.row_ids.len() as f32 * ROW_HEIGHT + PADDING * 2.0),
+        )
+    }
+
+    pub fn set_visibility(&mut self, visible: bool, interface: &mut Interface) -> Result<(), LazuliError> {
+        self.is_visible = visible;
+        self.updates_until_refresh = 0;
+
+        interface.mut_element_registry().set_element_visibility(&self.background_id, visible)?;
+        for row_id in self.row_ids.iter() {
+            interface.mut_element_registry().set_element_visibility(row_id, visible)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    fn create_rows(profiler: &Profiler) -> Vec<String> {
+        let average_frame_duration = profiler.average_frame_duration();
+        let fps = if average_frame_duration.is_zero() { 0.0 } else { 1.0 / average_frame_duration.as_secs_f32() };
+
+        let mut rows = vec![format!(
+            "frame: {} ms ({:.0} fps), max {} ms",
+            format_millis(average_frame_duration), fps, format_millis(profiler.max_frame_duration()),
+        )];
+
+        for scope_name in profiler.scope_names() {
+            rows.push(format!(
+                "{}: {} ms, max {} ms",
+                scope_name, format_millis(profiler.average_scope_duration(&scope_name)), format_millis(profiler.max_scope_duration(&scope_name)),
+            ));
+        }
+
+        rows
+    }
+}
+
+fn format_millis(duration: Duration) -> String {
+    format!("{:.2}", duration.as_secs_f64() * 1_000.0)
+}
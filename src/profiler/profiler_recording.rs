@@ -0,0 +1,132 @@
+use std::{fs, path::Path, time::Duration};
+
+use serde::Serialize;
+
+use crate::error::{AssetError, LazuliError};
+
+use super::FrameProfile;
+
+/// Name of the environment variable that can be used to record a trace without changing the code. Its value is
+/// the path to save the trace to, without extension.
+pub const TRACE_PATH_ENV_VAR: &str = "LAZULI_PROFILER_TRACE";
+
+/// Name of the event that spans a whole frame in the Chrome trace
+pub const FRAME_EVENT_NAME: &str = "frame";
+
+/// A single measurement, relative to the start of the recording
+#[derive(Clone, Debug)]
+pub struct TraceEvent {
+    pub name: String,
+    pub frame: u64,
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+/// Every measurement of a profiler between `Profiler::start_recording` and `Profiler::stop_recording`. Unlike
+/// the history of the profiler, a recording is not limited in size, so it should not be kept running forever.
+#[derive(Clone, Debug, Default)]
+pub struct ProfilerRecording {
+    pub events: Vec<TraceEvent>,
+    pub frames: Vec<FrameProfile>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace<'a> {
+    trace_events: Vec<ChromeTraceEvent<'a>>,
+    display_time_unit: &'a str,
+}
+
+#[derive(Serialize)]
+struct ChromeTraceEvent<'a> {
+    name: &'a str,
+    cat: &'a str,
+    ph: &'a str,
+    /// Start time in microseconds
+    ts: f64,
+    /// Duration in microseconds
+    dur: f64,
+    pid: u32,
+    tid: u32,
+    args: ChromeTraceEventArgs,
+}
+
+#[derive(Serialize)]
+struct ChromeTraceEventArgs {
+    frame: u64,
+}
+
+impl ProfilerRecording {
+    /// Create a json string in the Chrome `trace_event` format, which can be opened in `chrome://tracing` or Perfetto
+    pub fn to_chrome_trace(&self) -> Result<String, LazuliError> {
+        let trace_events = self.events.iter().map(|event| {
+            ChromeTraceEvent {
+                name: &event.name,
+                cat: if event.name == FRAME_EVENT_NAME { "frame" } else { "scope" },
+                ph: "X",
+                ts: event.start.as_secs_f64() * 1_000_000.0,
+                dur: event.duration.as_secs_f64() * 1_000_000.0,
+                pid: 1,
+                tid: 1,
+                args: ChromeTraceEventArgs { frame: event.frame },
+            }
+        }).collect();
+
+        serde_json::to_string(&ChromeTrace { trace_events, display_time_unit: "ms" }).map_err(|err| {
+            AssetError::Json { what: "profiler trace", source: err }.into()
+        })
+    }
+
+    /// Create a csv string with a row per frame and a column per scope. All durations are in milliseconds.
+    pub fn to_csv(&self) -> String {
+        let mut scope_names: Vec<&String> = vec![];
+        for frame in self.frames.iter() {
+            for scope in frame.scopes.iter() {
+                if !scope_names.contains(&&scope.name) {
+                    scope_names.push(&scope.name);
+                }
+            }
+        }
+
+        let mut csv = String::from("frame,frame_ms");
+        for scope_name in scope_names.iter() {
+            csv.push_str(&format!(",{}_ms", scope_name.replace(',', " ")));
+        }
+        csv.push('\n');
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            csv.push_str(&format!("{},{}", i, format_millis(frame.duration)));
+
+            for scope_name in scope_names.iter() {
+                let duration = frame.scope(scope_name).map(|scope| scope.duration).unwrap_or(Duration::ZERO);
+                csv.push_str(&format!(",{}", format_millis(duration)));
+            }
+
+            csv.push('\n');
+        }
+
+        csv
+    }
+
+    pub fn save_chrome_trace(&self, path: impl AsRef<Path>) -> Result<(), LazuliError> {
+        fs::write(path.as_ref(), self.to_chrome_trace()?).map_err(|err| {
+            AssetError::Io { path: path.as_ref().to_string_lossy().to_string(), source: err }.into()
+        })
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>) -> Result<(), LazuliError> {
+        fs::write(path.as_ref(), self.to_csv()).map_err(|err| {
+            AssetError::Io { path: path.as_ref().to_string_lossy().to_string(), source: err }.into()
+        })
+    }
+
+    /// Save both the Chrome trace and the csv, as `<path>.json` and `<path>.csv`
+    pub fn save(&self, path_without_extension: &str) -> Result<(), LazuliError> {
+        self.save_chrome_trace(format!("{}.json", path_without_extension))?;
+        self.save_csv(format!("{}.csv", path_without_extension))
+    }
+}
+
+fn format_millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1_000.0)
+}
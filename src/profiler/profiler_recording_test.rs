@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use super::{Profiler, FRAME_EVENT_NAME};
+
+fn millis(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+fn recording_profiler() -> Profiler {
+    let mut profiler = Profiler::new(10);
+    profiler.set_enabled(true);
+    profiler.start_recording();
+    profiler
+}
+
+#[test]
+fn test_recording_keeps_events_per_frame() {
+    let mut profiler = recording_profiler();
+
+    profiler.record_scope("draw", millis(4));
+    profiler.record_frame(millis(16));
+    profiler.record_scope("draw", millis(5));
+    profiler.record_frame(millis(17));
+
+    let recording = profiler.stop_recording().unwrap();
+    assert!(!profiler.is_recording());
+    assert_eq!(recording.frames.len(), 2);
+
+    let names: Vec<(&str, u64)> = recording.events.iter().map(|event| (event.name.as_str(), event.frame)).collect();
+    assert_eq!(names, vec![("draw", 0), (FRAME_EVENT_NAME, 0), ("draw", 1), (FRAME_EVENT_NAME, 1)]);
+    assert_eq!(recording.events[2].duration, millis(5));
+}
+
+#[test]
+fn test_recording_is_not_limited_by_history_size() {
+    let mut profiler = recording_profiler();
+
+    for _ in 0..25 {
+        profiler.record_frame(millis(16));
+    }
+
+    assert_eq!(profiler.history().len(), 10);
+    assert_eq!(profiler.stop_recording().unwrap().frames.len(), 25);
+}
+
+#[test]
+fn test_stop_recording_without_recording() {
+    let mut profiler = Profiler::new(10);
+    assert!(profiler.stop_recording().is_none());
+}
+
+#[test]
+fn test_to_chrome_trace() {
+    let mut profiler = recording_profiler();
+
+    profiler.record_scope("draw", millis(4));
+    profiler.record_frame(millis(16));
+
+    let trace = profiler.stop_recording().unwrap().to_chrome_trace().unwrap();
+    let json: serde_json::Value = serde_json::from_str(&trace).unwrap();
+    let events = json["traceEvents"].as_array().unwrap();
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0]["name"], "draw");
+    assert_eq!(events[0]["ph"], "X");
+    assert_eq!(events[0]["dur"], 4_000.0);
+    assert_eq!(events[1]["name"], FRAME_EVENT_NAME);
+    assert_eq!(events[1]["args"]["frame"], 0);
+}
+
+#[test]
+fn test_to_csv() {
+    let mut profiler = recording_profiler();
+
+    profiler.record_scope("draw", millis(4));
+    profiler.record_frame(millis(16));
+    profiler.record_scope("input", millis(1));
+    profiler.record_frame(millis(20));
+
+    let csv = profiler.stop_recording().unwrap().to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines, vec![
+        "frame,frame_ms,draw_ms,input_ms",
+        "0,16.000,4.000,0.000",
+        "1,20.000,0.000,1.000",
+    ]);
+}
@@ -0,0 +1,117 @@
+use std::time::Duration;
+
+use super::Profiler;
+
+fn millis(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+fn enabled_profiler(history_size: usize) -> Profiler {
+    let mut profiler = Profiler::new(history_size);
+    profiler.set_enabled(true);
+    profiler
+}
+
+#[test]
+fn test_disabled_profiler_does_not_record() {
+    let mut profiler = Profiler::new(10);
+
+    profiler.record_scope("draw", millis(5));
+    profiler.record_frame(millis(16));
+
+    assert!(profiler.history().is_empty());
+    assert_eq!(profiler.average_frame_duration(), Duration::ZERO);
+}
+
+#[test]
+fn test_scopes_get_added_up_per_frame() {
+    let mut profiler = enabled_profiler(10);
+
+    profiler.record_scope("scene update", millis(2));
+    profiler.record_scope("draw", millis(5));
+    profiler.record_scope("scene update", millis(3));
+    profiler.record_frame(millis(16));
+
+    let frame = profiler.last_frame().unwrap();
+    assert_eq!(frame.duration, millis(16));
+    assert_eq!(frame.scopes.len(), 2);
+    assert_eq!(frame.scopes[0].name, "scene update");
+    assert_eq!(frame.scopes[0].duration, millis(5));
+    assert_eq!(frame.scopes[0].calls, 2);
+    assert_eq!(frame.scope("draw").unwrap().duration, millis(5));
+}
+
+#[test]
+fn test_history_drops_oldest_frames() {
+    let mut profiler = enabled_profiler(3);
+
+    for ms in 1..=5 {
+        profiler.record_frame(millis(ms));
+    }
+
+    let durations: Vec<Duration> = profiler.history().iter().map(|frame| frame.duration).collect();
+    assert_eq!(durations, vec![millis(3), millis(4), millis(5)]);
+}
+
+#[test]
+fn test_averages_and_max() {
+    let mut profiler = enabled_profiler(10);
+
+    profiler.record_scope("draw", millis(4));
+    profiler.record_frame(millis(10));
+    profiler.record_frame(millis(20));
+    profiler.record_scope("draw", millis(8));
+    profiler.record_scope("input", millis(1));
+    profiler.record_frame(millis(30));
+
+    assert_eq!(profiler.average_frame_duration(), millis(20));
+    assert_eq!(profiler.max_frame_duration(), millis(30));
+    assert_eq!(profiler.average_scope_duration("draw"), millis(4));
+    assert_eq!(profiler.max_scope_duration("draw"), millis(8));
+    assert_eq!(profiler.max_scope_duration("unknown"), Duration::ZERO);
+    assert_eq!(profiler.scope_names(), vec!["draw".to_string(), "input".to_string()]);
+}
+
+#[test]
+fn test_nested_scopes() {
+    let mut profiler = enabled_profiler(10);
+
+    profiler.begin_scope("outer");
+    profiler.begin_scope("inner");
+    profiler.end_scope("inner");
+    profiler.end_scope("outer");
+    profiler.record_frame(millis(16));
+
+    let frame = profiler.last_frame().unwrap();
+    assert_eq!(frame.scopes[0].name, "inner");
+    assert_eq!(frame.scopes[1].name, "outer");
+    assert!(frame.scopes[1].duration >= frame.scopes[0].duration);
+}
+
+#[test]
+fn test_ending_scope_out_of_order_is_ignored() {
+    let mut profiler = enabled_profiler(10);
+
+    profiler.begin_scope("outer");
+    profiler.begin_scope("inner");
+    profiler.end_scope("outer");
+    profiler.end_scope("inner");
+    profiler.end_scope("outer");
+    profiler.record_frame(millis(16));
+
+    let frame = profiler.last_frame().unwrap();
+    assert_eq!(frame.scope("outer").unwrap().calls, 1);
+    assert_eq!(frame.scope("inner").unwrap().calls, 1);
+}
+
+#[test]
+fn test_disabling_clears_current_frame() {
+    let mut profiler = enabled_profiler(10);
+
+    profiler.record_scope("draw", millis(4));
+    profiler.set_enabled(false);
+    profiler.set_enabled(true);
+    profiler.record_frame(millis(16));
+
+    assert!(profiler.last_frame().unwrap().scopes.is_empty());
+}
@@ -1,40 +1,36 @@
 use std::f32::consts::{PI, TAU};
 
 use glam::{Vec3, Vec2};
-use rand::{Rng, rngs::ThreadRng};
+use rand::Rng;
 
-use crate::{asset_manager::AssetManager, event::{self, EventSystem}, graphics::{material::Material, scene::Scene, shader::{GlShaderBuilder, PATH_COLORED_FRAG}, ui::Interface, Camera, Cube, Shape, Transform}, input::{Input, Key}, time, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, event::{self, EventSystem}, graphics::{material::Material, scene::Scene, shader::{GlShaderBuilder, PATH_COLORED_FRAG}, ui::Interface, Camera, CameraController, Cube, FlyController, ProjectionMode, Transform}, input::{Input, Key}, time, world::{Entity, Mesh, World}, ResourceId};
+
+/// Rotation in radians that gets added to the transform of an entity every update
+struct Spin(Vec3);
 
 pub struct CoordinateSystem {
-    material_id: ResourceId<Material>,
-    cubes: Vec<Cube>,
-    transforms: Vec<Transform>,
-    rotations: Vec<Vec3>,
-    camera: Camera,
-    rng: ThreadRng,
+    world: World,
+    camera: Entity,
+    target: Entity,
     movement_speed: f32,
-    zoom_speed: f32,
+    camera_controller: FlyController,
 }
 
 impl Scene for CoordinateSystem {
-    fn new(event_system: &mut EventSystem, window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, String> {
+    fn new(event_system: &mut EventSystem, window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
         event_system.send(event::LockCursor{});
         event_system.send(event::HideCursor{});
 
         let shader_id = asset_manager.load_shader(
             Box::new(GlShaderBuilder::new("./assets/shaders/with-camera.vert", PATH_COLORED_FRAG.to_string()))
-        ).unwrap();
-        let material_id = asset_manager.load_material(&shader_id).unwrap();
-
-        let mut cubes = vec![];
-        let mut transforms = vec![];
-        let mut rotations = vec![];
+        )?;
+        let material_id: ResourceId<Material> = asset_manager.load_material(&shader_id)?;
 
+        let mut world = World::new();
         let mut rng = rand::thread_rng();
 
         for _ in 0..15 {
             let cube = Cube::new_colored(asset_manager.get_shader_by_id(&shader_id).unwrap());
-            cubes.push(cube);
 
             let mut transform = Transform::new();
             transform.translate_z(rng.gen_range(10.0..30.0));
@@ -42,104 +38,89 @@ impl Scene for CoordinateSystem {
             transform.translate_y(rng.gen_range(0.0..10.0) - 5.0);
             transform.rotate_x(rng.gen_range(0.0..TAU) - PI);
             transform.rotate_x(rng.gen_range(0.0..TAU) - PI);
-            transforms.push(transform);
 
-            rotations.push(Vec3 { 
+            let spin = Spin(Vec3 { 
                 x: (rng.gen_range(0.0..10.0) - 5.0) / 250.0, 
                 y: (rng.gen_range(0.0..10.0) - 5.0) / 375.0, 
                 z: 0.0,
             });
+
+            let entity = world.spawn();
+            world
+                .with_component(entity, Mesh::new(cube))
+                .with_component(entity, transform)
+                .with_component(entity, material_id.duplicate())
+                .with_component(entity, spin);
         }
 
+        let target = world.query::<Mesh>().next().map(|(entity, _)| entity).unwrap();
+
         let mut camera = Camera::new(window_size.x / window_size.y, 45.0, 0.1, 500.0);
         camera.set_look_sensitivity(3.0);
         camera.translate_z(-40.0);
+        camera.set_ortho_distance(40.0);
 
-        {
-            let shader = asset_manager.get_shader_by_id(&shader_id).unwrap();
-            shader.set_uniform("projection", &camera.projection_for_shader());
-            shader.set_uniform("view", &camera.view_for_shader());
-        }
+        let camera_entity = world.spawn();
+        world.add_component(camera_entity, camera);
+
+        world.add_system(|world: &mut World, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager| {
+            for (_, transform, spin) in world.query2_mut::<Transform, Spin>() {
+                transform.rotate(&spin.0);
+            }
+        });
 
         let result = Self { 
-            material_id,
-            cubes,
-            transforms,
-            rotations,
-            camera,
-            rng,
+            world,
+            camera: camera_entity,
+            target,
             movement_speed: 10.0,
-            zoom_speed: 10.0,
+            camera_controller: FlyController::new().with_movement_speed(10.0).with_look_speed(0.02),
         };
 
         Ok(result)
     }
 
-    fn update(&mut self, _: &mut EventSystem, input: &Input, asset_manager: &mut dyn AssetManager, _: &mut Interface) {
-        for i in 0..self.cubes.len() {
-            self.transforms[i].rotate(&self.rotations[i]);
-        }
+    fn update(&mut self, _: &mut EventSystem, input: &Input, _: &mut dyn AssetManager, _: &mut Interface) {
+        let target_position = self.world.component::<Transform>(self.target).unwrap().position;
+        let camera = self.world.mut_component::<Camera>(self.camera).unwrap();
 
-        // self.poll_axis_movement(input);
-        self.poll_free_movement(input);
-        self.poll_zoom(input, asset_manager);
-        
-        if input.mouse.did_move() {
-            self.camera.rotate(input.mouse.get_moved_x() as f32 / 50.0, input.mouse.get_moved_y() as f32 / 50.0);
-        }
+        self.camera_controller.update(camera, input, time::DELTA);
+        Self::poll_target_movement(camera, input, target_position, self.movement_speed);
 
         if input.keyboard.is_key_down(Key::Space) {
-            self.camera.look_at(self.transforms[0].position);
+            camera.look_at(target_position);
         }
 
-        asset_manager.get_material_shader(&self.material_id).unwrap().set_uniform("view", &self.camera.view_for_shader());
+        if input.keyboard.is_key_down(Key::O) {
+            let mode = match camera.projection_mode() {
+                ProjectionMode::Perspective => ProjectionMode::Orthographic,
+                ProjectionMode::Orthographic => ProjectionMode::Perspective,
+            };
+            camera.transition_projection_mode(mode, 0.5);
+        }
+        camera.update(time::DELTA);
     }
 
-    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager) {
-        let shader = asset_manager.get_material_shader(&self.material_id).unwrap();
+    unsafe fn draw(&self, _asset_manager: &mut dyn AssetManager, _interpolation: f32) {
+        // The world gets drawn by the engine
+    }
 
-        for i in 0..self.cubes.len() {
-            shader.set_uniform("model", &self.transforms[i].for_shader());
-            self.cubes[i].draw(shader);
-        }
+    fn world(&self) -> Option<&World> {
+        Some(&self.world)
+    }
+
+    fn mut_world(&mut self) -> Option<&mut World> {
+        Some(&mut self.world)
     }
 }
 
 impl CoordinateSystem {
-    fn poll_free_movement(&mut self, input: &Input) {
-        if input.keyboard.is_key_held(Key::A) {
-            self.camera.move_left(self.movement_speed * time::DELTA);
-        }
-        if input.keyboard.is_key_held(Key::D) {
-            self.camera.move_right(self.movement_speed * time::DELTA);
-        }
-        if input.keyboard.is_key_held(Key::S) {
-            self.camera.move_back(self.movement_speed * time::DELTA);
-        }
-        if input.keyboard.is_key_held(Key::W) {
-            self.camera.move_forth(self.movement_speed * time::DELTA);
-        }
-        if input.keyboard.is_key_held(Key::Shift) {
-            self.camera.move_up(self.movement_speed * time::DELTA);
-        }
-        if input.keyboard.is_key_held(Key::Cntrl) {
-            self.camera.move_down(self.movement_speed * time::DELTA);
-        }
-
+    fn poll_target_movement(camera: &mut Camera, input: &Input, target_position: Vec3, movement_speed: f32) {
         if input.keyboard.is_key_held(Key::T) {
-            self.camera.move_towards(self.transforms[0].position, self.movement_speed * time::DELTA);
+            camera.move_towards(target_position, movement_speed * time::DELTA);
         }
         if input.keyboard.is_key_held(Key::G) {
-            self.camera.move_away_from(self.transforms[0].position, self.movement_speed * time::DELTA);
-        }
-    }
-
-    fn poll_zoom(&mut self, input: &Input, asset_manager: &mut dyn AssetManager) {
-        let scroll_y = input.mouse.get_scroll_y() as f32 * self.zoom_speed * time::DELTA;
-
-        if scroll_y != 0.0 {
-            self.camera.zoom(scroll_y);
-            asset_manager.get_material_shader(&self.material_id).unwrap().set_uniform("projection", &self.camera.projection_for_shader());
+            camera.move_away_from(target_position, movement_speed * time::DELTA);
         }
     }
 }
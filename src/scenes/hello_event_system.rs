@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{graphics::{scene::Scene, ui::Interface}, event::{WindowResizeEvent, EventSystem, EventReader}, input::Input, asset_manager::AssetManager, log};
+use crate::{error::LazuliError, graphics::{scene::Scene, ui::Interface}, event::{WindowResizeEvent, EventSystem, EventReader}, input::Input, asset_manager::AssetManager, log};
 
 pub struct HelloEventSystem {
     window_resize_listener1: EventReader<WindowResizeEvent>,
@@ -9,7 +9,7 @@ pub struct HelloEventSystem {
 }
 
 impl Scene for HelloEventSystem {
-    fn new(event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, _: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, String> {
+    fn new(event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, _: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
         let listener1 = event_system.register::<WindowResizeEvent>();
         let listener2 = event_system.register::<WindowResizeEvent>();
         
@@ -37,5 +37,5 @@ impl Scene for HelloEventSystem {
         }
     }
 
-    unsafe fn draw(&self, _: &mut dyn AssetManager) {}
+    unsafe fn draw(&self, _: &mut dyn AssetManager, _: f32) {}
 }
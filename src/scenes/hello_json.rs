@@ -3,11 +3,11 @@ use std::{fs, path::Path};
 use glam::Vec2;
 
 use serde::{Deserialize, Serialize};
-use crate::{graphics::{scene::Scene, ui::Interface}, event::EventSystem, input::Input, asset_manager::AssetManager, log};
+use crate::{error::LazuliError, graphics::{scene::Scene, ui::Interface}, event::EventSystem, input::Input, asset_manager::AssetManager, log};
 
 pub struct HelloJson {}
 impl Scene for HelloJson {
-    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, _: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, String>
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, _: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError>
     {
         let data = MyStruct {
             id: 15,
@@ -25,7 +25,7 @@ impl Scene for HelloJson {
     }
 
     fn update(&mut self, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager, _: &mut Interface) {}
-    unsafe fn draw(&self, _: &mut dyn AssetManager) {}
+    unsafe fn draw(&self, _: &mut dyn AssetManager, _: f32) {}
 }
 
 #[derive(Serialize, Deserialize)]
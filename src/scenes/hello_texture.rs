@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, event::EventSystem, graphics::{material::Material, scene::Scene, shader::{GlShaderBuilder, PATH_TEXTURED_FRAG, PATH_TEXTURED_VERT}, ui::Interface, Rectangle, Shape}, input::Input, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, event::EventSystem, graphics::{material::Material, scene::Scene, shader::{GlShaderBuilder, PATH_TEXTURED_FRAG, PATH_TEXTURED_VERT}, ui::Interface, Rectangle, Shape}, input::Input, ResourceId};
 
 pub struct HelloTexture {
     material_id: ResourceId<Material>,
@@ -8,7 +8,7 @@ pub struct HelloTexture {
 }
 
 impl Scene for HelloTexture {
-    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, String> {
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
         let shader_id = asset_manager.load_shader(
             Box::new(GlShaderBuilder::new(PATH_TEXTURED_VERT, PATH_TEXTURED_FRAG))
         )?;
@@ -28,7 +28,7 @@ impl Scene for HelloTexture {
 
     fn update(&mut self, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager, _: &mut Interface) {}
 
-    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager) {
+    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager, _interpolation: f32) {
         self.shape.draw(asset_manager.get_material_shader(&self.material_id).unwrap());
     }
 }
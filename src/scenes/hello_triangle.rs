@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, event::EventSystem, graphics::{scene::Scene, shader::{GlShaderBuilder, ShaderProgram, PATH_MOVING_TRIANGLE_FRAG, PATH_MOVING_TRIANGLE_VERT}, ui::Interface, Shape, Triangle}, input::Input, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, event::EventSystem, graphics::{scene::Scene, shader::{GlShaderBuilder, ShaderProgram, PATH_MOVING_TRIANGLE_FRAG, PATH_MOVING_TRIANGLE_VERT}, ui::Interface, Shape, Triangle}, input::Input, ResourceId};
 
 pub struct HelloTriangle {
     shader_id: ResourceId<Box<dyn ShaderProgram>>,
@@ -8,7 +8,7 @@ pub struct HelloTriangle {
 }
 
 impl Scene for HelloTriangle {
-    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, String> {
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
         let shader_id = asset_manager.load_shader(
             Box::new(GlShaderBuilder::new(PATH_MOVING_TRIANGLE_VERT, PATH_MOVING_TRIANGLE_FRAG))
         )?;
@@ -25,7 +25,7 @@ impl Scene for HelloTriangle {
 
     fn update(&mut self, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager, _: &mut Interface) {}
 
-    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager) {
+    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager, _interpolation: f32) {
         self.triangle.draw(asset_manager.get_shader_by_id(&self.shader_id).unwrap())
     }
 }
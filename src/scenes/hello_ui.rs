@@ -1,6 +1,6 @@
 use glam::Vec2;
 
-use crate::{graphics::{scene::Scene, ui::{shapes::RectangleBuilder, widget::{ButtonBuilder, SliderBuilder, DropdownBuilder, DropdownOption}, Position, AnchorPoint, TextBuilder, Interface, VerticalListBuilder, Padding, UiWidgetId, UiElementId, Width, UiLayoutId}, Color}, event::EventSystem, input::{Input, Key}, asset_manager::AssetManager, log, ResourceId};
+use crate::{error::LazuliError, graphics::{scene::Scene, ui::{shapes::RectangleBuilder, widget::{ButtonBuilder, SliderBuilder, DropdownBuilder, DropdownOption}, Position, AnchorPoint, TextBuilder, Interface, VerticalListBuilder, Padding, UiWidgetId, UiElementId, Width, UiLayoutId}, Color}, event::EventSystem, input::{Input, Key}, asset_manager::AssetManager, log, ResourceId};
 
 pub struct HelloUi {
     width_slider_id: ResourceId<UiWidgetId>,
@@ -15,7 +15,7 @@ pub struct HelloUi {
 }
 
 impl Scene for HelloUi {
-    fn new(_: &mut EventSystem, _: Vec2, _: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Self, String> 
+    fn new(_: &mut EventSystem, _: Vec2, _: f32, asset_manager: &mut dyn AssetManager, interface: &mut Interface) -> Result<Self, LazuliError> 
     {
         let rectangle_id = interface.mut_element_registry().create_rectangle(&RectangleBuilder::new(), asset_manager)?;
         let second_rectangle_id = interface.mut_element_registry().create_rectangle(&RectangleBuilder::new()
@@ -179,6 +179,6 @@ impl Scene for HelloUi {
         }
     }
 
-    unsafe fn draw(&self, _: &mut dyn AssetManager) {
+    unsafe fn draw(&self, _: &mut dyn AssetManager, _: f32) {
     }
 }
@@ -1,16 +1,17 @@
 use glam::Vec2;
 
-use crate::{asset_manager::AssetManager, event::EventSystem, graphics::{material::Material, scene::Scene, shader::{GlShaderBuilder, UniformValue, PATH_MOVING_TRIANGLE_FRAG, PATH_MOVING_TRIANGLE_VERT}, ui::Interface, Shape, Triangle}, input::Input, ResourceId};
+use crate::{error::LazuliError, asset_manager::AssetManager, event::EventSystem, graphics::{material::Material, scene::Scene, shader::{GlShaderBuilder, UniformValue, PATH_MOVING_TRIANGLE_FRAG, PATH_MOVING_TRIANGLE_VERT}, ui::Interface, Shape, Triangle}, input::Input, ResourceId};
 
 pub struct MovingTriangle {
     material_id: ResourceId<Material>,
     triangle: Triangle,
     triangle_offset_x: f32,
+    previous_triangle_offset_x: f32,
     triangle_movement_velocity: f32,
 }
 
 impl Scene for MovingTriangle {
-    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, String> {
+    fn new(_event_system: &mut EventSystem, _window_size: Vec2, _pixel_density: f32, asset_manager: &mut dyn AssetManager, _: &mut Interface) -> Result<Self, LazuliError> {
         let shader_id = asset_manager.load_shader(
             Box::new(GlShaderBuilder::new(PATH_MOVING_TRIANGLE_VERT, PATH_MOVING_TRIANGLE_FRAG))
         )?;
@@ -23,11 +24,13 @@ impl Scene for MovingTriangle {
             material_id,
             triangle,
             triangle_offset_x: 0.0,
+            previous_triangle_offset_x: 0.0,
             triangle_movement_velocity: 0.008,
         })
     }
 
-    fn update(&mut self, _: &mut EventSystem, _: &Input, asset_manager: &mut dyn AssetManager, _: &mut Interface) {
+    fn update(&mut self, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager, _: &mut Interface) {
+        self.previous_triangle_offset_x = self.triangle_offset_x;
         self.triangle_offset_x += self.triangle_movement_velocity;
 
         if self.triangle_offset_x > 0.5 {
@@ -38,13 +41,14 @@ impl Scene for MovingTriangle {
             self.triangle_offset_x = -0.5;
             self.triangle_movement_velocity *= -1.0;
         }
-
-        let shader_id = asset_manager.get_material_by_id(&self.material_id).unwrap().shader_id.duplicate();
-        asset_manager.get_shader_by_id(&shader_id).unwrap().set_uniform("xPos", &UniformValue::from(self.triangle_offset_x));
     }
 
-    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager) {
+    unsafe fn draw(&self, asset_manager: &mut dyn AssetManager, interpolation: f32) {
+        let offset_x = self.previous_triangle_offset_x + (self.triangle_offset_x - self.previous_triangle_offset_x) * interpolation;
+
         let shader_id = asset_manager.get_material_by_id(&self.material_id).unwrap().shader_id.duplicate();
-        self.triangle.draw(asset_manager.get_shader_by_id(&shader_id).unwrap());
+        let shader = asset_manager.get_shader_by_id(&shader_id).unwrap();
+        shader.set_uniform("xPos", &UniformValue::from(offset_x));
+        self.triangle.draw(shader);
     }
 }
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use super::TICK_RATE;
+
+/// Maximum number of simulation steps that get run for a single frame. When the simulation falls behind
+/// more than this, the remaining time gets dropped so that we do not end up in a spiral where every frame
+/// takes longer because it has to catch up even more steps.
+const DEFAULT_MAX_STEPS: u32 = 5;
+
+/// Decouples the rate at which the simulation gets updated from the rate at which frames get rendered.
+///
+/// Every frame, the time that passed since the previous frame gets added to an accumulator. The simulation
+/// then runs as many fixed size steps as fit in the accumulator. The time that is left over is used to
+/// calculate the interpolation alpha, which tells the renderer how far we are in between the previous
+/// and the next simulation step.
+pub struct FixedTimestep {
+    step: Duration,
+    accumulator: Duration,
+    max_steps: u32,
+}
+
+impl FixedTimestep {
+    /// `tick_rate` is the number of simulation steps per second. Panics if it is 0.
+    pub fn new(tick_rate: u32) -> Self {
+        assert!(tick_rate > 0, "FixedTimestep tick rate must be at least 1");
+
+        Self {
+            step: Duration::from_nanos(1_000_000_000 / tick_rate as u64),
+            accumulator: Duration::ZERO,
+            max_steps: DEFAULT_MAX_STEPS,
+        }
+    }
+
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Add the time that passed since the previous frame and get the number of simulation steps that
+    /// need to be run for this frame.
+    pub fn advance(&mut self, frame_time: Duration) -> u32 {
+        self.accumulator += frame_time;
+
+        let steps_needed = (self.accumulator.as_nanos() / self.step.as_nanos()) as u32;
+        let steps = steps_needed.min(self.max_steps);
+        self.accumulator -= self.step * steps;
+
+        if steps_needed > self.max_steps {
+            // We are too far behind to catch up, so drop the time we can not simulate
+            self.accumulator = Duration::from_nanos((self.accumulator.as_nanos() % self.step.as_nanos()) as u64);
+        }
+
+        steps
+    }
+
+    /// How far we are in between the last simulation step and the next one, in the range [0.0, 1.0)
+    pub fn alpha(&self) -> f32 {
+        self.accumulator.as_secs_f32() / self.step.as_secs_f32()
+    }
+
+    /// Duration of a single simulation step, in seconds
+    pub fn delta(&self) -> f32 {
+        self.step.as_secs_f32()
+    }
+
+    pub fn step_duration(&self) -> Duration {
+        self.step
+    }
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(TICK_RATE)
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use super::FixedTimestep;
+
+#[test]
+fn test_advance() {
+    let mut timestep = FixedTimestep::new(100);
+
+    assert_eq!(0, timestep.advance(Duration::from_millis(5)));
+    assert_eq!(1, timestep.advance(Duration::from_millis(5)));
+    assert_eq!(2, timestep.advance(Duration::from_millis(25)));
+    assert_eq!(0, timestep.advance(Duration::from_millis(4)));
+    assert_eq!(1, timestep.advance(Duration::from_millis(1)));
+}
+
+#[test]
+fn test_advance_clamps_to_max_steps() {
+    let mut timestep = FixedTimestep::new(100).with_max_steps(3);
+
+    assert_eq!(3, timestep.advance(Duration::from_millis(1_005)));
+
+    // the time that could not be simulated is dropped, but the remainder of a step is kept
+    assert_eq!(0, timestep.advance(Duration::from_millis(4)));
+    assert_eq!(1, timestep.advance(Duration::from_millis(1)));
+}
+
+#[test]
+fn test_alpha() {
+    let mut timestep = FixedTimestep::new(100);
+
+    assert_eq!(0.0, timestep.alpha());
+
+    timestep.advance(Duration::from_millis(25));
+    assert!((timestep.alpha() - 0.5).abs() < 0.0001);
+
+    timestep.advance(Duration::from_millis(5));
+    assert!(timestep.alpha().abs() < 0.0001);
+}
+
+#[test]
+fn test_same_steps_regardless_of_frame_rate() {
+    let mut slow = FixedTimestep::new(60);
+    let mut fast = FixedTimestep::new(60);
+
+    let mut slow_steps = 0;
+    for _ in 0..20 {
+        slow_steps += slow.advance(Duration::from_millis(50));
+    }
+
+    let mut fast_steps = 0;
+    for _ in 0..500 {
+        fast_steps += fast.advance(Duration::from_millis(2));
+    }
+
+    assert_eq!(60, slow_steps);
+    assert_eq!(slow_steps, fast_steps);
+}
+
+#[test]
+#[should_panic(expected = "tick rate must be at least 1")]
+fn test_zero_tick_rate() {
+    FixedTimestep::new(0);
+}
@@ -1,2 +1,11 @@
 mod time;
 pub use time::*;
+
+mod virtual_clock;
+pub use virtual_clock::VirtualClock;
+
+mod fixed_timestep;
+pub use fixed_timestep::FixedTimestep;
+
+#[cfg(test)]
+mod fixed_timestep_test;
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+/// A clock that only moves forward when it is told to. This makes it possible to run frame based logic
+/// deterministically, without depending on the system time.
+pub struct VirtualClock {
+    elapsed: Duration,
+    frame_count: u64,
+}
+
+impl VirtualClock {
+    pub fn new() -> Self {
+        Self {
+            elapsed: Duration::ZERO,
+            frame_count: 0,
+        }
+    }
+
+    /// Advance the clock by a single frame that took `frame_time`
+    pub fn advance(&mut self, frame_time: Duration) {
+        self.elapsed += frame_time;
+        self.frame_count += 1;
+    }
+
+    /// Time that passed since the clock was created
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    pub fn now_millis(&self) -> u128 {
+        self.elapsed.as_millis()
+    }
+
+    pub fn now_seconds(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+}
@@ -0,0 +1,90 @@
+use std::any::Any;
+
+use super::Entity;
+
+/// Holds all components of a single type, indexed by entity id
+pub struct ComponentStorage<T> {
+    components: Vec<Option<T>>,
+    count: usize,
+}
+
+impl<T> ComponentStorage<T> {
+    pub fn new() -> Self {
+        Self { components: vec![], count: 0 }
+    }
+
+    /// Returns the component that the entity already had, if any
+    pub fn insert(&mut self, entity: Entity, component: T) -> Option<T> {
+        let index = entity.id() as usize;
+
+        if index >= self.components.len() {
+            self.components.resize_with(index + 1, || None);
+        }
+
+        let previous = self.components[index].replace(component);
+        if previous.is_none() {
+            self.count += 1;
+        }
+
+        previous
+    }
+
+    pub fn remove(&mut self, entity: Entity) -> Option<T> {
+        let removed = self.components.get_mut(entity.id() as usize)?.take();
+        if removed.is_some() {
+            self.count -= 1;
+        }
+
+        removed
+    }
+
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        self.components.get(entity.id() as usize)?.as_ref()
+    }
+
+    pub fn get_mut(&mut self, entity: Entity) -> Option<&mut T> {
+        self.components.get_mut(entity.id() as usize)?.as_mut()
+    }
+
+    /// Iterate over the ids of the entities that have this component, together with the component
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &T)> {
+        self.components.iter().enumerate().filter_map(|(id, component)| {
+            component.as_ref().map(|component| (id as u32, component))
+        })
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u32, &mut T)> {
+        self.components.iter_mut().enumerate().filter_map(|(id, component)| {
+            component.as_mut().map(|component| (id as u32, component))
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+/// Makes it possible to keep storages of different component types in a single collection
+pub trait AnyComponentStorage {
+    fn remove_entity(&mut self, entity: Entity);
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: 'static> AnyComponentStorage for ComponentStorage<T> {
+    fn remove_entity(&mut self, entity: Entity) {
+        self.remove(entity);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,23 @@
+/// A handle to an object in a `World`. An entity is nothing more than an id; all of its data lives in components.
+///
+/// Ids get reused after an entity is despawned. The generation makes sure that a handle to a despawned entity
+/// does not accidentally point to a new entity with the same id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Entity {
+    id: u32,
+    generation: u32,
+}
+
+impl Entity {
+    pub(super) fn new(id: u32, generation: u32) -> Self {
+        Self { id, generation }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
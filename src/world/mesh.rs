@@ -0,0 +1,16 @@
+use crate::graphics::Shape;
+
+/// Component with the shape that gets drawn for an entity
+pub struct Mesh {
+    shape: Box<dyn Shape>,
+}
+
+impl Mesh {
+    pub fn new(shape: impl Shape + 'static) -> Self {
+        Self { shape: Box::new(shape) }
+    }
+
+    pub fn shape(&self) -> &dyn Shape {
+        self.shape.as_ref()
+    }
+}
@@ -0,0 +1,17 @@
+mod entity;
+pub use entity::Entity;
+
+mod component_storage;
+pub use component_storage::ComponentStorage;
+
+mod system;
+pub use system::System;
+
+mod mesh;
+pub use mesh::Mesh;
+
+mod world;
+pub use world::World;
+
+#[cfg(test)]
+mod world_test;
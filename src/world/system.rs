@@ -0,0 +1,15 @@
+use crate::{asset_manager::AssetManager, event::EventSystem, input::Input};
+
+use super::World;
+
+/// Logic that runs over the entities of a `World` once per update. Any closure with the same signature as
+/// `System::run` is a system as well.
+pub trait System {
+    fn run(&mut self, world: &mut World, event_system: &mut EventSystem, input: &Input, asset_manager: &mut dyn AssetManager);
+}
+
+impl<F> System for F where F: FnMut(&mut World, &mut EventSystem, &Input, &mut dyn AssetManager) {
+    fn run(&mut self, world: &mut World, event_system: &mut EventSystem, input: &Input, asset_manager: &mut dyn AssetManager) {
+        self(world, event_system, input, asset_manager)
+    }
+}
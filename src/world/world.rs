@@ -0,0 +1,251 @@
+use std::{any::TypeId, collections::HashMap};
+
+use crate::{asset_manager::AssetManager, event::EventSystem, graphics::{material::Material, Camera, Transform}, input::Input, log, ResourceId};
+
+use super::{component_storage::AnyComponentStorage, ComponentStorage, Entity, Mesh, System};
+
+/// Keeps track of entities and their components, and runs systems over them.
+///
+/// Any `'static` type can be used as a component. The engine draws every entity that has a `Transform`, a `Mesh`
+/// and a `ResourceId<Material>`, as seen by the active camera, which is an entity with a `Camera` component.
+///
+/// A scene can hand its world to the engine with `Scene::world` and `Scene::mut_world`. The engine will then run
+/// the systems right after `Scene::update` and draw the world right after `Scene::draw`.
+pub struct World {
+    /// Generation of every entity id, also of the ids that are not in use
+    generations: Vec<u32>,
+    alive: Vec<bool>,
+    free_ids: Vec<u32>,
+    storages: HashMap<TypeId, Box<dyn AnyComponentStorage>>,
+    systems: Vec<Box<dyn System>>,
+    active_camera: Option<Entity>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        Self {
+            generations: vec![],
+            alive: vec![],
+            free_ids: vec![],
+            storages: HashMap::new(),
+            systems: vec![],
+            active_camera: None,
+        }
+    }
+
+    pub fn spawn(&mut self) -> Entity {
+        match self.free_ids.pop() {
+            Some(id) => {
+                self.alive[id as usize] = true;
+                Entity::new(id, self.generations[id as usize])
+            },
+            None => {
+                let id = self.generations.len() as u32;
+                self.generations.push(0);
+                self.alive.push(true);
+                Entity::new(id, 0)
+            },
+        }
+    }
+
+    /// Remove the entity and all of its components. Returns false if the entity was already despawned.
+    pub fn despawn(&mut self, entity: Entity) -> bool {
+        if !self.is_alive(entity) {
+            return false;
+        }
+
+        for storage in self.storages.values_mut() {
+            storage.remove_entity(entity);
+        }
+
+        let index = entity.id() as usize;
+        self.alive[index] = false;
+        self.generations[index] += 1;
+        self.free_ids.push(entity.id());
+
+        if self.active_camera == Some(entity) {
+            self.active_camera = None;
+        }
+
+        true
+    }
+
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        let index = entity.id() as usize;
+        index < self.alive.len() && self.alive[index] && self.generations[index] == entity.generation()
+    }
+
+    pub fn number_of_entities(&self) -> usize {
+        self.alive.len() - self.free_ids.len()
+    }
+
+    /// Add a component to the entity, replacing the component of the same type that it already had
+    pub fn add_component<T: 'static>(&mut self, entity: Entity, component: T) {
+        if !self.is_alive(entity) {
+            log::engine_warn(format!("World can not add component to entity {:?} because it does not exist", entity));
+            return;
+        }
+
+        self.mut_storage_or_insert::<T>().insert(entity, component);
+    }
+
+    pub fn with_component<T: 'static>(&mut self, entity: Entity, component: T) -> &mut Self {
+        self.add_component(entity, component);
+        self
+    }
+
+    pub fn remove_component<T: 'static>(&mut self, entity: Entity) -> Option<T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+
+        self.mut_storage::<T>()?.remove(entity)
+    }
+
+    pub fn component<T: 'static>(&self, entity: Entity) -> Option<&T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+
+        self.storage::<T>()?.get(entity)
+    }
+
+    pub fn mut_component<T: 'static>(&mut self, entity: Entity) -> Option<&mut T> {
+        if !self.is_alive(entity) {
+            return None;
+        }
+
+        self.mut_storage::<T>()?.get_mut(entity)
+    }
+
+    pub fn has_component<T: 'static>(&self, entity: Entity) -> bool {
+        self.component::<T>(entity).is_some()
+    }
+
+    /// All entities that have a component of type `T`
+    pub fn query<T: 'static>(&self) -> impl Iterator<Item = (Entity, &T)> {
+        let generations = &self.generations;
+
+        self.storage::<T>().into_iter().flat_map(move |storage| {
+            storage.iter().map(move |(id, component)| (Entity::new(id, generations[id as usize]), component))
+        })
+    }
+
+    pub fn query_mut<T: 'static>(&mut self) -> impl Iterator<Item = (Entity, &mut T)> {
+        let generations = &self.generations;
+        let storage = self.storages.get_mut(&TypeId::of::<T>())
+            .and_then(|storage| storage.as_any_mut().downcast_mut::<ComponentStorage<T>>());
+
+        storage.into_iter().flat_map(move |storage| {
+            storage.iter_mut().map(move |(id, component)| (Entity::new(id, generations[id as usize]), component))
+        })
+    }
+
+    /// All entities that have both a component of type `A` and a component of type `B`
+    pub fn query2<A: 'static, B: 'static>(&self) -> impl Iterator<Item = (Entity, &A, &B)> {
+        let generations = &self.generations;
+        let storages = self.storage::<A>().zip(self.storage::<B>());
+
+        storages.into_iter().flat_map(move |(storage_a, storage_b)| {
+            storage_a.iter().filter_map(move |(id, a)| {
+                let entity = Entity::new(id, generations[id as usize]);
+                storage_b.get(entity).map(|b| (entity, a, b))
+            })
+        })
+    }
+
+    /// Like `query2`, but the first component can be changed. `A` and `B` must be different types.
+    pub fn query2_mut<A: 'static, B: 'static>(&mut self) -> impl Iterator<Item = (Entity, &mut A, &B)> {
+        assert!(TypeId::of::<A>() != TypeId::of::<B>(), "World::query2_mut needs two different component types");
+
+        let generations = &self.generations;
+        let [storage_a, storage_b] = self.storages.get_disjoint_mut([&TypeId::of::<A>(), &TypeId::of::<B>()]);
+
+        let storage_a = storage_a.and_then(|storage| storage.as_any_mut().downcast_mut::<ComponentStorage<A>>());
+        let storage_b = storage_b.and_then(|storage| storage.as_any().downcast_ref::<ComponentStorage<B>>());
+
+        storage_a.zip(storage_b).into_iter().flat_map(move |(storage_a, storage_b)| {
+            storage_a.iter_mut().filter_map(move |(id, a)| {
+                let entity = Entity::new(id, generations[id as usize]);
+                storage_b.get(entity).map(|b| (entity, a, b))
+            })
+        })
+    }
+
+    /// Systems run in the order in which they were added
+    pub fn add_system(&mut self, system: impl System + 'static) {
+        self.systems.push(Box::new(system));
+    }
+
+    pub fn run_systems(&mut self, event_system: &mut EventSystem, input: &Input, asset_manager: &mut dyn AssetManager) {
+        // Take the systems out of the world so that they can get mutable access to the world
+        let mut systems = std::mem::take(&mut self.systems);
+
+        for system in systems.iter_mut() {
+            system.run(self, event_system, input, asset_manager);
+        }
+
+        // Keep systems that were added by other systems
+        systems.append(&mut self.systems);
+        self.systems = systems;
+    }
+
+    /// Use the camera of the given entity to draw the world. When no camera is set, the first entity with a
+    /// `Camera` component gets used.
+    pub fn set_active_camera(&mut self, entity: Entity) {
+        self.active_camera = Some(entity);
+    }
+
+    pub fn active_camera(&self) -> Option<Entity> {
+        match self.active_camera {
+            Some(entity) if self.has_component::<Camera>(entity) => Some(entity),
+            _ => self.query::<Camera>().next().map(|(entity, _)| entity),
+        }
+    }
+
+    /// Draw every entity with a `Transform`, a `Mesh` and a `ResourceId<Material>`
+    pub unsafe fn draw(&self, asset_manager: &mut dyn AssetManager) {
+        let camera = self.active_camera().and_then(|entity| self.component::<Camera>(entity));
+
+        for (entity, mesh, transform) in self.query2::<Mesh, Transform>() {
+            let material_id = match self.component::<ResourceId<Material>>(entity) {
+                Some(material_id) => material_id,
+                None => continue,
+            };
+
+            if let Err(err) = asset_manager.activate_material(material_id) {
+                log::engine_err(format!("World failed to draw entity {:?}: {}", entity, err));
+                continue;
+            }
+
+            let shader = match asset_manager.get_material_shader(material_id) {
+                Some(shader) => shader,
+                None => continue,
+            };
+
+            if let Some(camera) = camera {
+                shader.set_uniform("projection", &camera.projection_for_shader());
+                shader.set_uniform("view", &camera.view_for_shader());
+            }
+            shader.set_uniform("model", &transform.for_shader());
+
+            mesh.shape().draw(shader);
+        }
+    }
+
+    fn storage<T: 'static>(&self) -> Option<&ComponentStorage<T>> {
+        self.storages.get(&TypeId::of::<T>())?.as_any().downcast_ref::<ComponentStorage<T>>()
+    }
+
+    fn mut_storage<T: 'static>(&mut self) -> Option<&mut ComponentStorage<T>> {
+        self.storages.get_mut(&TypeId::of::<T>())?.as_any_mut().downcast_mut::<ComponentStorage<T>>()
+    }
+
+    fn mut_storage_or_insert<T: 'static>(&mut self) -> &mut ComponentStorage<T> {
+        self.storages.entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(ComponentStorage::<T>::new()))
+            .as_any_mut()
+            .downcast_mut::<ComponentStorage<T>>()
+            .unwrap()
+    }
+}
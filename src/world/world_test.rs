@@ -0,0 +1,138 @@
+use glam::Vec3;
+
+use crate::{asset_manager::{asset_manager_mock::MockAssetManager, AssetManager}, event::EventSystem, graphics::Transform, input::Input};
+
+use super::World;
+
+struct Velocity(Vec3);
+
+#[derive(Debug, PartialEq)]
+struct Name(&'static str);
+
+#[test]
+fn test_spawn_and_despawn() {
+    let mut world = World::new();
+
+    let a = world.spawn();
+    let b = world.spawn();
+    assert_ne!(a, b);
+    assert_eq!(world.number_of_entities(), 2);
+
+    assert!(world.despawn(a));
+    assert!(!world.despawn(a));
+    assert!(!world.is_alive(a));
+    assert!(world.is_alive(b));
+    assert_eq!(world.number_of_entities(), 1);
+}
+
+#[test]
+fn test_reused_id_does_not_match_old_entity() {
+    let mut world = World::new();
+
+    let old = world.spawn();
+    world.add_component(old, Name("old"));
+    world.despawn(old);
+
+    let new = world.spawn();
+    assert_eq!(new.id(), old.id());
+    assert_ne!(new.generation(), old.generation());
+
+    assert!(world.component::<Name>(old).is_none());
+    assert!(world.component::<Name>(new).is_none());
+
+    world.add_component(old, Name("old"));
+    assert!(world.component::<Name>(new).is_none());
+}
+
+#[test]
+fn test_components() {
+    let mut world = World::new();
+    let entity = world.spawn();
+
+    world.add_component(entity, Name("cube"));
+    assert_eq!(world.component::<Name>(entity), Some(&Name("cube")));
+    assert!(!world.has_component::<Velocity>(entity));
+
+    world.add_component(entity, Name("sphere"));
+    assert_eq!(world.component::<Name>(entity), Some(&Name("sphere")));
+
+    world.mut_component::<Name>(entity).unwrap().0 = "cone";
+    assert_eq!(world.remove_component::<Name>(entity), Some(Name("cone")));
+    assert!(!world.has_component::<Name>(entity));
+}
+
+#[test]
+fn test_despawn_removes_components() {
+    let mut world = World::new();
+    let entity = world.spawn();
+    world.add_component(entity, Name("cube"));
+
+    world.despawn(entity);
+
+    assert_eq!(world.query::<Name>().count(), 0);
+}
+
+#[test]
+fn test_query() {
+    let mut world = World::new();
+
+    let a = world.spawn();
+    world.with_component(a, Name("a")).with_component(a, Transform::new());
+    let b = world.spawn();
+    world.add_component(b, Name("b"));
+    let c = world.spawn();
+    world.add_component(c, Transform::new());
+
+    let names: Vec<&str> = world.query::<Name>().map(|(_, name)| name.0).collect();
+    assert_eq!(names, vec!["a", "b"]);
+
+    let both: Vec<_> = world.query2::<Name, Transform>().map(|(entity, _, _)| entity).collect();
+    assert_eq!(both, vec![a]);
+
+    assert_eq!(world.query::<Velocity>().count(), 0);
+    assert_eq!(world.query2::<Name, Velocity>().count(), 0);
+}
+
+#[test]
+fn test_query_mut() {
+    let mut world = World::new();
+
+    for i in 0..3 {
+        let entity = world.spawn();
+        world.add_component(entity, Transform::new());
+        world.add_component(entity, Velocity(Vec3::new(i as f32, 0.0, 0.0)));
+    }
+
+    for (_, transform, velocity) in world.query2_mut::<Transform, Velocity>() {
+        transform.translate(&velocity.0);
+    }
+    for (_, transform) in world.query_mut::<Transform>() {
+        transform.translate_y(1.0);
+    }
+
+    let positions: Vec<Vec3> = world.query::<Transform>().map(|(_, transform)| transform.position).collect();
+    assert_eq!(positions, vec![Vec3::new(0.0, 1.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(2.0, 1.0, 0.0)]);
+}
+
+#[test]
+fn test_run_systems() {
+    let mut world = World::new();
+    let entity = world.spawn();
+    world.add_component(entity, Transform::new());
+    world.add_component(entity, Velocity(Vec3::X));
+
+    world.add_system(|world: &mut World, _: &mut EventSystem, _: &Input, _: &mut dyn AssetManager| {
+        for (_, transform, velocity) in world.query2_mut::<Transform, Velocity>() {
+            transform.translate(&velocity.0);
+        }
+    });
+
+    let mut event_system = EventSystem::new();
+    let input = Input::new();
+    let mut asset_manager = MockAssetManager::new();
+
+    world.run_systems(&mut event_system, &input, &mut asset_manager);
+    world.run_systems(&mut event_system, &input, &mut asset_manager);
+
+    assert_eq!(world.component::<Transform>(entity).unwrap().position, Vec3::new(2.0, 0.0, 0.0));
+}